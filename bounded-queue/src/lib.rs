@@ -142,6 +142,92 @@ impl<T> BoundedQueue<T> {
 
         Some(self.vals[self.last()].as_ref().unwrap())
     }
+
+    /// Iterate over the elements of the queue, starting
+    /// with the oldest one.
+    ///
+    /// # Example
+    /// Elements that were bumped out of the queue are
+    /// not visited.
+    /// ```
+    /// # extern crate bounded_queue;
+    /// # use bounded_queue::*;
+    /// # fn main() {
+    /// let mut queue = BoundedQueue::new(2);
+    ///
+    /// queue.push(1u32);
+    /// queue.push(2);
+    /// queue.push(3);
+    ///
+    /// let vals: Vec<_> = queue.iter().cloned().collect();
+    /// assert!(vals == [2, 3]);
+    ///
+    /// // Newest elements first
+    /// let vals: Vec<_> = queue.iter().rev().cloned().collect();
+    /// assert!(vals == [3, 2]);
+    /// # }
+    /// ```
+    pub fn iter(&self) -> Iter<T> {
+        Iter {
+            queue: self,
+            front: self.front,
+            back: self.back,
+        }
+    }
+}
+
+/// Iterator over the elements of a [`BoundedQueue`].
+///
+/// Created by [`BoundedQueue::iter()`].
+#[derive(Clone, Debug)]
+pub struct Iter<'a, T: 'a> {
+    queue: &'a BoundedQueue<T>,
+    front: usize,
+    back: usize,
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        if self.back == self.front {
+            return None;
+        }
+
+        let idx = self.back % self.queue.capacity();
+        self.back += 1;
+
+        self.queue.vals[idx].as_ref()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.front - self.back;
+        (len, Some(len))
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for Iter<'a, T> {
+    fn next_back(&mut self) -> Option<&'a T> {
+        if self.back == self.front {
+            return None;
+        }
+
+        self.front -= 1;
+        let idx = self.front % self.queue.capacity();
+
+        self.queue.vals[idx].as_ref()
+    }
+}
+
+impl<'a, T> ExactSizeIterator for Iter<'a, T> {}
+
+impl<'a, T> IntoIterator for &'a BoundedQueue<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Iter<'a, T> {
+        self.iter()
+    }
 }
 
 impl<T: Clone> BoundedQueue<T> {
@@ -213,4 +299,27 @@ mod test {
         let mut x = BoundedQueue::<u8>::new(1);
         assert!(x.pop().is_none());
     }
+
+    #[test]
+    pub fn iter_after_pop() {
+        let mut x = BoundedQueue::<u8>::new(3);
+
+        x.push(0);
+        x.push(1);
+        x.push(2);
+        x.pop();
+        x.push(3);
+        x.push(4);
+
+        assert_eq!(x.iter().cloned().collect::<Vec<_>>(), [2, 3, 4]);
+        assert_eq!(x.iter().len(), 3);
+    }
+
+    #[test]
+    pub fn iter_on_empty() {
+        let x = BoundedQueue::<u8>::new(2);
+
+        assert!(x.iter().next().is_none());
+        assert!(x.iter().next_back().is_none());
+    }
 }
@@ -0,0 +1,71 @@
+use shrev::*;
+use specs::*;
+
+use server::*;
+
+use std::time::Instant;
+
+/// The part of the match cycle that the
+/// server is currently in.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum MatchPhase {
+	/// Waiting for enough players to join.
+	/// Players can respawn freely.
+	Lobby,
+	/// Enough players have joined and the
+	/// match is about to start.
+	Countdown,
+	/// A match is being played. Players that
+	/// die stay dead until the next match.
+	Running,
+	/// The match has been won and the lobby
+	/// will open again soon.
+	Ended,
+}
+
+/// The area that players must stay within
+/// during a match.
+#[derive(Copy, Clone, Debug)]
+pub struct SafeZone {
+	pub center: Position,
+	pub radius: Distance,
+	/// Time at which the current match started
+	pub start: Instant,
+}
+
+/// Number of matches that have been started
+/// since the server started.
+#[derive(Copy, Clone, Debug, Default)]
+pub struct Round(pub u32);
+
+#[derive(Copy, Clone, Debug)]
+pub struct GameStartEvent;
+
+#[derive(Copy, Clone, Debug)]
+pub struct GameWinEvent {
+	/// The last player alive, `None` if all
+	/// remaining players died in the same frame.
+	pub winner: Option<Entity>,
+}
+
+pub type OnGameWin = EventChannel<GameWinEvent>;
+pub type OnGameWinReader = ReaderId<GameWinEvent>;
+
+pub type OnGameStart = EventChannel<GameStartEvent>;
+pub type OnGameStartReader = ReaderId<GameStartEvent>;
+
+impl Default for MatchPhase {
+	fn default() -> Self {
+		MatchPhase::Lobby
+	}
+}
+
+impl Default for SafeZone {
+	fn default() -> Self {
+		Self {
+			center: Position::default(),
+			radius: Distance::new(0.0),
+			start: Instant::now(),
+		}
+	}
+}
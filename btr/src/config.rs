@@ -0,0 +1,205 @@
+use server::utils::{load_file, LoadError};
+use server::*;
+
+use std::error::Error;
+use std::fmt;
+use std::path::Path;
+use std::time::Duration;
+
+/// How players get their plane at the start of
+/// each match.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PlaneSelection {
+	/// Players keep whatever plane they picked
+	/// while waiting in the lobby.
+	Player,
+	/// Every player gets a random plane.
+	Random,
+	/// Everyone flies the same plane, which
+	/// changes every match.
+	Rotate,
+}
+
+impl Default for PlaneSelection {
+	fn default() -> Self {
+		PlaneSelection::Player
+	}
+}
+
+/// Size of the safe zone and how it shrinks
+/// over the course of a match.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ZoneConfig {
+	/// Center of the safe zone
+	pub center: Position,
+	/// Radius of the zone when a match starts
+	pub start_radius: Distance,
+	/// The zone never shrinks below this radius
+	pub min_radius: Distance,
+	/// Time after the start of a match before the
+	/// zone starts shrinking.
+	pub shrink_delay: Duration,
+	/// How much the radius of the zone shrinks
+	/// every second.
+	pub shrink_speed: f32,
+	/// Fraction of a plane's health that is lost
+	/// every second while outside the zone.
+	pub damage: f32,
+}
+
+impl Default for ZoneConfig {
+	fn default() -> Self {
+		Self {
+			center: Position::default(),
+			start_radius: Distance::new(8000.0),
+			min_radius: Distance::new(300.0),
+			shrink_delay: Duration::from_secs(60),
+			shrink_speed: 20.0,
+			damage: 0.1,
+		}
+	}
+}
+
+impl ZoneConfig {
+	/// Radius of the safe zone `elapsed` time after
+	/// the start of a match.
+	pub fn radius_at(&self, elapsed: Duration) -> Distance {
+		if elapsed <= self.shrink_delay {
+			return self.start_radius;
+		}
+
+		let shrinking = elapsed - self.shrink_delay;
+		let secs = shrinking.as_secs() as f32 + shrinking.subsec_nanos() as f32 * 1.0e-9;
+		let radius = self.start_radius.inner() - self.shrink_speed * secs;
+
+		Distance::new(radius.max(self.min_radius.inner()))
+	}
+}
+
+/// Tunable parameters for battle royale games.
+///
+/// This is stored as a resource and can be loaded
+/// from a TOML or JSON file using [`BtrConfig::load`].
+/// Any fields that are missing from the file will
+/// use their default values.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(default)]
+pub struct BtrConfig {
+	/// Number of players needed in the lobby
+	/// before the countdown to a match starts.
+	/// A match is won by the last player alive,
+	/// so this must be at least 2.
+	pub min_players: u32,
+	/// Length of the countdown before a match
+	pub countdown: Duration,
+	/// Time between a match being won and the
+	/// lobby opening again.
+	pub game_reset_time: Duration,
+	/// The base score that the winner would get
+	/// if they were the only ones on the server.
+	/// This is multiplied by the number of players
+	/// (up to a max of 10 times).
+	pub game_win_bounty_base: Score,
+
+	pub plane_selection: PlaneSelection,
+	pub zone: ZoneConfig,
+}
+
+impl BtrConfig {
+	/// Load the config from a TOML or JSON file,
+	/// depending on the file extension.
+	pub fn load<P: AsRef<Path>>(path: P) -> Result<Self, BtrConfigError> {
+		let config: BtrConfig = load_file(path)?;
+
+		config.validate()?;
+
+		Ok(config)
+	}
+
+	pub fn validate(&self) -> Result<(), BtrConfigError> {
+		if self.min_players < 2 {
+			return Err(BtrConfigError::NoMinPlayers);
+		}
+
+		if self.zone.min_radius > self.zone.start_radius {
+			return Err(BtrConfigError::ZoneTooSmall);
+		}
+
+		Ok(())
+	}
+}
+
+impl Default for BtrConfig {
+	fn default() -> Self {
+		Self {
+			min_players: 2,
+			countdown: Duration::from_secs(30),
+			game_reset_time: Duration::from_secs(30),
+			game_win_bounty_base: Score(100),
+
+			plane_selection: PlaneSelection::default(),
+			zone: ZoneConfig::default(),
+		}
+	}
+}
+
+#[derive(Debug)]
+pub enum BtrConfigError {
+	Load(LoadError),
+	NoMinPlayers,
+	ZoneTooSmall,
+}
+
+impl fmt::Display for BtrConfigError {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		use self::BtrConfigError::*;
+
+		match self {
+			Load(e) => write!(f, "unable to load BTR config file: {}", e),
+			NoMinPlayers => write!(f, "min_players must be at least 2"),
+			ZoneTooSmall => write!(f, "zone.min_radius must not be larger than zone.start_radius"),
+		}
+	}
+}
+
+impl Error for BtrConfigError {}
+
+impl From<LoadError> for BtrConfigError {
+	fn from(e: LoadError) -> Self {
+		BtrConfigError::Load(e)
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	#[test]
+	fn zone_shrinks_after_delay() {
+		let zone = ZoneConfig {
+			start_radius: Distance::new(1000.0),
+			min_radius: Distance::new(100.0),
+			shrink_delay: Duration::from_secs(10),
+			shrink_speed: 50.0,
+			..Default::default()
+		};
+
+		assert_eq!(zone.radius_at(Duration::from_secs(5)).inner(), 1000.0);
+		assert_eq!(zone.radius_at(Duration::from_secs(12)).inner(), 900.0);
+		assert_eq!(zone.radius_at(Duration::from_secs(60)).inner(), 100.0);
+	}
+
+	#[test]
+	fn needs_two_players() {
+		let mut config = BtrConfig::default();
+		assert!(config.validate().is_ok());
+
+		config.min_players = 1;
+		match config.validate() {
+			Err(BtrConfigError::NoMinPlayers) => (),
+			x => panic!("Unexpected result {:?}", x),
+		}
+	}
+}
@@ -0,0 +1,6 @@
+use server::component::event::TimerEventType;
+
+lazy_static! {
+	pub static ref GAME_START_TIMER: TimerEventType = TimerEventType::register();
+	pub static ref OPEN_LOBBY_TIMER: TimerEventType = TimerEventType::register();
+}
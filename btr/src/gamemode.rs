@@ -0,0 +1,49 @@
+use server::protocol::GameType;
+use server::*;
+
+use specs::Entity;
+
+#[derive(Default, Debug)]
+pub struct BTRGameMode {
+	/// Map that the game is being played on,
+	/// used to pick spawn positions.
+	pub map: Map,
+	/// Whether a match is currently being played.
+	/// Nobody can respawn until it is over.
+	pub match_running: bool,
+}
+
+impl BTRGameMode {
+	pub fn new(map: Map) -> Self {
+		Self {
+			map,
+			..Self::default()
+		}
+	}
+}
+
+impl GameMode for BTRGameMode {
+	fn assign_team(&mut self, player: Entity) -> Team {
+		// Every player is on their own team
+		Team(player.id() as u16)
+	}
+
+	fn spawn_pos(&mut self, _: Entity, team: Team) -> Position {
+		match self.map.random_spawn(team) {
+			Some(pos) => pos,
+			None => panic!("Map {:?} has no spawn zones for BTR", self.map.name),
+		}
+	}
+
+	fn allow_respawn(&self, _: Entity) -> bool {
+		!self.match_running
+	}
+
+	fn gametype(&self) -> GameType {
+		GameType::BTR
+	}
+
+	fn room(&self) -> String {
+		"matrix".to_owned()
+	}
+}
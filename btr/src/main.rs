@@ -0,0 +1,101 @@
+#[macro_use]
+extern crate specs_derive;
+#[macro_use]
+extern crate shred_derive;
+#[macro_use]
+extern crate lazy_static;
+#[macro_use]
+extern crate log;
+#[macro_use]
+extern crate serde;
+
+extern crate airmash_server;
+extern crate env_logger;
+extern crate rand;
+extern crate serde_json;
+extern crate shred;
+extern crate shrev;
+extern crate specs;
+extern crate toml;
+
+use airmash_server as server;
+
+mod component;
+mod config;
+mod consts;
+mod gamemode;
+mod systems;
+
+use std::env;
+
+use config::BtrConfig;
+use gamemode::BTRGameMode;
+use server::types::{BanList, FileAccountStore, FileSessionValidator, Map};
+use server::AirmashServer;
+
+fn main() {
+	env::set_var("RUST_BACKTRACE", "1");
+	env::set_var("RUST_LOG", "airmash_server=info,airmash_server_btr=info");
+
+	env_logger::init();
+
+	let map = match env::var("MAP_FILE") {
+		Ok(path) => match Map::load(&path) {
+			Ok(map) => map,
+			Err(e) => panic!("Failed to load map from {}: {}", path, e),
+		},
+		Err(_) => Map::default(),
+	};
+	// Every player is on their own team so they all
+	// need to be able to use the same spawn zones.
+	if let Err(e) = map.check_spawns(&[]) {
+		panic!("Map {:?} cannot be used for BTR: {}", map.name, e);
+	}
+
+	let mut server = AirmashServer::new("0.0.0.0:3501")
+		.with_engine()
+		.with_map(map.clone())
+		.with_gamemode(BTRGameMode::new(map))
+		.with_alpha_warning();
+
+	let btr_config = match env::var("BTR_CONFIG") {
+		Ok(path) => match BtrConfig::load(&path) {
+			Ok(config) => config,
+			Err(e) => panic!("Failed to load BTR config from {}: {}", path, e),
+		},
+		Err(_) => BtrConfig::default(),
+	};
+	server.world.add_resource(btr_config);
+
+	server.builder = systems::register(&mut server.world, server.builder);
+
+	if let Ok(path) = env::var("CONFIG_FILE") {
+		server = server.with_config_file(path);
+	}
+
+	if let Ok(path) = env::var("ACCOUNTS_FILE") {
+		let store = match FileAccountStore::open(&path) {
+			Ok(store) => store,
+			Err(e) => panic!("Failed to open accounts file {}: {}", path, e),
+		};
+		server = server.with_account_store(store);
+	}
+
+	if let Ok(path) = env::var("SESSIONS_FILE") {
+		server = server.with_session_validator(FileSessionValidator::new(path));
+	}
+
+	if let Ok(path) = env::var("BANS_FILE") {
+		let bans = match BanList::open(&path) {
+			Ok(bans) => bans,
+			Err(e) => panic!("Failed to open bans file {}: {}", path, e),
+		};
+		server = server.with_ban_list(bans);
+	}
+
+	if let Ok(path) = env::var("REPLAY_FILE") {
+		server = server.with_replay_file(path);
+	}
+
+	server.run();
+}
@@ -0,0 +1,68 @@
+use specs::*;
+
+use server::component::flag::IsPlayer;
+use server::types::systemdata::IsAlive;
+use server::*;
+
+use component::*;
+use systems::on_game_start::RespawnAll;
+
+/// End the match once there is at most one
+/// player left alive.
+#[derive(Default)]
+pub struct CheckWin;
+
+#[derive(SystemData)]
+pub struct CheckWinData<'a> {
+	phase: Write<'a, MatchPhase>,
+	win_channel: Write<'a, OnGameWin>,
+
+	entities: Entities<'a>,
+	is_player: ReadStorage<'a, IsPlayer>,
+	is_alive: IsAlive<'a>,
+}
+
+impl<'a> System<'a> for CheckWin {
+	type SystemData = CheckWinData<'a>;
+
+	fn run(&mut self, mut data: Self::SystemData) {
+		if *data.phase != MatchPhase::Running {
+			return;
+		}
+
+		let alive = (
+			&*data.entities,
+			data.is_player.mask() & data.is_alive.mask(),
+		)
+			.join()
+			.map(|(ent, ..)| ent)
+			.take(2)
+			.collect::<Vec<_>>();
+
+		if alive.len() > 1 {
+			return;
+		}
+
+		let winner = alive.first().cloned();
+
+		info!("BTR match won by {:?}", winner);
+
+		*data.phase = MatchPhase::Ended;
+		data.win_channel.single_write(GameWinEvent { winner });
+	}
+}
+
+impl SystemInfo for CheckWin {
+	// Spectators are brought back into the game
+	// in the frame that the match starts, they
+	// need to be counted as alive.
+	type Dependencies = RespawnAll;
+
+	fn name() -> &'static str {
+		concat!(module_path!(), "::", line!())
+	}
+
+	fn new() -> Self {
+		Self::default()
+	}
+}
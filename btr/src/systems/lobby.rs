@@ -0,0 +1,113 @@
+use specs::*;
+
+use server::component::event::TimerEvent;
+use server::component::flag::IsPlayer;
+use server::consts::spectate::SPECTATOR_TEAM;
+use server::consts::timer::DELAYED_MESSAGE;
+use server::protocol::server::ServerMessage;
+use server::protocol::ServerMessageType;
+use server::types::FutureDispatcher;
+use server::*;
+
+use component::*;
+use config::BtrConfig;
+use consts::GAME_START_TIMER;
+
+use std::time::Duration;
+
+const MESSAGE_30_SECONDS: &'static str = "Match starting in 30 seconds - pick your plane";
+const MESSAGE_10_SECONDS: &'static str = "Match starting in 10 seconds";
+const MESSAGE_5_SECONDS: &'static str = "Match starting in 5 seconds";
+const MESSAGE_4_SECONDS: &'static str = "Match starting in 4 seconds";
+const MESSAGE_3_SECONDS: &'static str = "Match starting in 3 seconds";
+const MESSAGE_2_SECONDS: &'static str = "Match starting in 2 seconds";
+const MESSAGE_1_SECONDS: &'static str = "Match starting in a second";
+const MESSAGE_0_SECONDS: &'static str = "Match starting! Stay inside the safe zone";
+
+const MESSAGES: [(u32, u64, &'static str); 8] = [
+	(7, 30, MESSAGE_30_SECONDS),
+	(7, 10, MESSAGE_10_SECONDS),
+	(2, 5, MESSAGE_5_SECONDS),
+	(2, 4, MESSAGE_4_SECONDS),
+	(2, 3, MESSAGE_3_SECONDS),
+	(2, 2, MESSAGE_2_SECONDS),
+	(2, 1, MESSAGE_1_SECONDS),
+	(3, 0, MESSAGE_0_SECONDS),
+];
+
+/// Start the countdown to the next match once
+/// enough players are waiting in the lobby.
+#[derive(Default)]
+pub struct CheckLobby;
+
+#[derive(SystemData)]
+pub struct CheckLobbyData<'a> {
+	phase: Write<'a, MatchPhase>,
+	future: ReadExpect<'a, FutureDispatcher>,
+	config: Read<'a, BtrConfig>,
+
+	is_player: ReadStorage<'a, IsPlayer>,
+	team: ReadStorage<'a, Team>,
+}
+
+impl<'a> System<'a> for CheckLobby {
+	type SystemData = CheckLobbyData<'a>;
+
+	fn run(&mut self, mut data: Self::SystemData) {
+		if *data.phase != MatchPhase::Lobby {
+			return;
+		}
+
+		// Spectators without a team aren't waiting to play
+		let players = (&data.is_player, &data.team)
+			.join()
+			.filter(|(_, team)| **team != SPECTATOR_TEAM)
+			.count() as u32;
+		if players < data.config.min_players {
+			return;
+		}
+
+		*data.phase = MatchPhase::Countdown;
+
+		let countdown = data.config.countdown;
+
+		data.future
+			.run_delayed(countdown, move |inst| TimerEvent {
+				ty: *GAME_START_TIMER,
+				instant: inst,
+				data: None,
+			});
+
+		for (duration, delay, msg) in MESSAGES.iter() {
+			let delay = Duration::from_secs(*delay);
+			// Skip messages for times longer than the countdown
+			if delay > countdown {
+				continue;
+			}
+
+			data.future.run_delayed(countdown - delay, move |inst| {
+				Some(TimerEvent {
+					ty: *DELAYED_MESSAGE,
+					instant: inst,
+					data: Some(Box::new(ServerMessage {
+						ty: ServerMessageType::TimeToGameStart,
+						duration: *duration * 1000,
+						text: msg.to_string(),
+					})),
+				})
+			});
+		}
+	}
+}
+
+impl SystemInfo for CheckLobby {
+	type Dependencies = ();
+
+	fn name() -> &'static str {
+		concat!(module_path!(), "::", line!())
+	}
+
+	fn new() -> Self {
+		Self::default()
+	}
+}
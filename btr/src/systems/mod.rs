@@ -0,0 +1,18 @@
+mod check_win;
+mod lobby;
+mod register;
+mod respawn_all;
+mod spectate_late_join;
+mod spectate_on_death;
+
+pub mod on_game_start;
+pub mod on_game_win;
+pub mod timer;
+pub mod zone;
+
+pub use self::register::register;
+
+pub use self::check_win::CheckWin;
+pub use self::lobby::CheckLobby;
+pub use self::spectate_late_join::SpectateLateJoin;
+pub use self::spectate_on_death::SpectateOnDeath;
@@ -0,0 +1,90 @@
+use specs::*;
+
+use server::component::flag::IsPlayer;
+use server::protocol::server::PlayerType;
+use server::*;
+
+use component::*;
+use config::{BtrConfig, PlaneSelection};
+
+use rand;
+
+use super::StartMatch;
+
+const PLANES: [Plane; 5] = [
+	Plane::Predator,
+	Plane::Goliath,
+	Plane::Mohawk,
+	Plane::Tornado,
+	Plane::Prowler,
+];
+
+/// Pick the plane that each player will fly
+/// this match, according to
+/// [`BtrConfig::plane_selection`].
+#[derive(Default)]
+pub struct AssignPlanes {
+	reader: Option<OnGameStartReader>,
+}
+
+#[derive(SystemData)]
+pub struct AssignPlanesData<'a> {
+	channel: Read<'a, OnGameStart>,
+	config: Read<'a, BtrConfig>,
+	round: Read<'a, Round>,
+	conns: Read<'a, Connections>,
+
+	entities: Entities<'a>,
+	is_player: ReadStorage<'a, IsPlayer>,
+	plane: WriteStorage<'a, Plane>,
+}
+
+impl<'a> System<'a> for AssignPlanes {
+	type SystemData = AssignPlanesData<'a>;
+
+	fn setup(&mut self, res: &mut Resources) {
+		Self::SystemData::setup(res);
+
+		self.reader = Some(res.fetch_mut::<OnGameStart>().register_reader());
+	}
+
+	fn run(&mut self, mut data: Self::SystemData) {
+		for _ in data.channel.read(self.reader.as_mut().unwrap()) {
+			let selection = data.config.plane_selection;
+			let rotated = PLANES[data.round.0 as usize % PLANES.len()];
+
+			if selection == PlaneSelection::Player {
+				continue;
+			}
+
+			let conns = &data.conns;
+
+			(&*data.entities, &mut data.plane, data.is_player.mask())
+				.join()
+				.for_each(|(ent, plane, ..)| {
+					*plane = match selection {
+						PlaneSelection::Random => PLANES[rand::random::<usize>() % PLANES.len()],
+						_ => rotated,
+					};
+
+					conns.send_to_all(PlayerType {
+						id: ent.into(),
+						ty: *plane,
+					});
+				});
+		}
+	}
+}
+
+impl SystemInfo for AssignPlanes {
+	// Needs the round number for this match
+	type Dependencies = StartMatch;
+
+	fn name() -> &'static str {
+		concat!(module_path!(), "::", line!())
+	}
+
+	fn new() -> Self {
+		Self::default()
+	}
+}
@@ -0,0 +1,7 @@
+mod assign_planes;
+mod respawn_all;
+mod start_match;
+
+pub use self::assign_planes::AssignPlanes;
+pub use self::respawn_all::RespawnAll;
+pub use self::start_match::StartMatch;
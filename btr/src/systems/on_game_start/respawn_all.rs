@@ -0,0 +1,61 @@
+use specs::*;
+
+use server::systems::handlers::game::on_join::AllJoinHandlers;
+use server::*;
+
+use component::*;
+use systems::respawn_all::RespawnAllData;
+use systems::timer::GameStart;
+
+use super::AssignPlanes;
+
+/// Bring every player back into the game,
+/// including those that are spectating, at
+/// the start of a match.
+#[derive(Default)]
+pub struct RespawnAll {
+	reader: Option<OnGameStartReader>,
+}
+
+#[derive(SystemData)]
+pub struct RespawnAllSystemData<'a> {
+	channel: Read<'a, OnGameStart>,
+	respawn: RespawnAllData<'a>,
+}
+
+impl<'a> System<'a> for RespawnAll {
+	type SystemData = RespawnAllSystemData<'a>;
+
+	fn setup(&mut self, res: &mut Resources) {
+		Self::SystemData::setup(res);
+
+		self.reader = Some(res.fetch_mut::<OnGameStart>().register_reader());
+	}
+
+	fn run(&mut self, mut data: Self::SystemData) {
+		for _ in data.channel.read(self.reader.as_mut().unwrap()) {
+			data.respawn.respawn_all();
+		}
+	}
+}
+
+impl SystemInfo for RespawnAll {
+	type Dependencies = (
+		// Planes need to be picked before
+		// players respawn in them.
+		AssignPlanes,
+		// PlayerRespawn accesses position by entity,
+		// there's a race condition in ordering here
+		// if a player joins exactly as a game is starting.
+		AllJoinHandlers,
+		GameStart,
+	);
+
+	fn name() -> &'static str {
+		concat!(module_path!(), "::", line!())
+	}
+
+	fn new() -> Self {
+		Self::default()
+	}
+}
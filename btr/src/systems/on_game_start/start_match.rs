@@ -0,0 +1,65 @@
+use specs::*;
+
+use server::component::time::ThisFrame;
+use server::*;
+
+use component::*;
+use config::BtrConfig;
+use gamemode::BTRGameMode;
+use systems::timer::GameStart;
+
+/// Switch into the running phase and reset
+/// the safe zone when a match starts.
+#[derive(Default)]
+pub struct StartMatch {
+	reader: Option<OnGameStartReader>,
+}
+
+#[derive(SystemData)]
+pub struct StartMatchData<'a> {
+	channel: Read<'a, OnGameStart>,
+	phase: Write<'a, MatchPhase>,
+	zone: Write<'a, SafeZone>,
+	round: Write<'a, Round>,
+	gamemode: GameModeWriter<'a, BTRGameMode>,
+	config: Read<'a, BtrConfig>,
+	this_frame: Read<'a, ThisFrame>,
+}
+
+impl<'a> System<'a> for StartMatch {
+	type SystemData = StartMatchData<'a>;
+
+	fn setup(&mut self, res: &mut Resources) {
+		Self::SystemData::setup(res);
+
+		self.reader = Some(res.fetch_mut::<OnGameStart>().register_reader());
+	}
+
+	fn run(&mut self, mut data: Self::SystemData) {
+		for _ in data.channel.read(self.reader.as_mut().unwrap()) {
+			*data.phase = MatchPhase::Running;
+			data.gamemode.match_running = true;
+			data.round.0 += 1;
+
+			*data.zone = SafeZone {
+				center: data.config.zone.center,
+				radius: data.config.zone.start_radius,
+				start: data.this_frame.0,
+			};
+
+			info!("Starting BTR match {}", data.round.0);
+		}
+	}
+}
+
+impl SystemInfo for StartMatch {
+	type Dependencies = GameStart;
+
+	fn name() -> &'static str {
+		concat!(module_path!(), "::", line!())
+	}
+
+	fn new() -> Self {
+		Self::default()
+	}
+}
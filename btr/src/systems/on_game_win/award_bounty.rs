@@ -0,0 +1,94 @@
+use specs::*;
+
+use server::component::channel::*;
+use server::component::counter::*;
+use server::component::event::*;
+use server::component::time::*;
+use server::consts::timer::SCORE_BOARD;
+use server::types::Upgrades;
+use server::*;
+
+use server::protocol::server::ScoreUpdate;
+
+use component::*;
+use config::BtrConfig;
+use systems::CheckWin;
+
+/// Award bounty to the winner of the match
+#[derive(Default)]
+pub struct AwardBounty {
+	reader: Option<OnGameWinReader>,
+}
+
+#[derive(SystemData)]
+pub struct AwardBountyData<'a> {
+	channel: Read<'a, OnGameWin>,
+	players_game: Read<'a, PlayersGame>,
+	config: Read<'a, BtrConfig>,
+	timer_channel: Write<'a, OnTimerEvent>,
+	this_frame: Read<'a, ThisFrame>,
+	conns: Read<'a, Connections>,
+
+	score: WriteStorage<'a, Score>,
+	earnings: WriteStorage<'a, Earnings>,
+	kills: ReadStorage<'a, TotalKills>,
+	deaths: ReadStorage<'a, TotalDeaths>,
+	upgrades: ReadStorage<'a, Upgrades>,
+}
+
+impl<'a> System<'a> for AwardBounty {
+	type SystemData = AwardBountyData<'a>;
+
+	fn setup(&mut self, res: &mut Resources) {
+		Self::SystemData::setup(res);
+
+		self.reader = Some(res.fetch_mut::<OnGameWin>().register_reader());
+	}
+
+	fn run(&mut self, mut data: Self::SystemData) {
+		for evt in data.channel.read(self.reader.as_mut().unwrap()) {
+			let player = match evt.winner {
+				Some(player) => player,
+				None => continue,
+			};
+			let bounty = data.players_game.0.min(10) * data.config.game_win_bounty_base.0;
+
+			let (score, earnings) = match (data.score.get_mut(player), data.earnings.get_mut(player)) {
+				(Some(score), Some(earnings)) => (score, earnings),
+				_ => continue,
+			};
+
+			score.0 += bounty;
+			(earnings.0).0 += bounty;
+
+			let packet = ScoreUpdate {
+				id: player.into(),
+				score: *score,
+				earnings: earnings.0,
+				total_deaths: data.deaths.get(player).map(|x| x.0).unwrap_or(0),
+				total_kills: data.kills.get(player).map(|x| x.0).unwrap_or(0),
+				upgrades: data.upgrades.get(player).map(|x| x.unused).unwrap_or(0),
+			};
+
+			data.conns.send_to_all(packet);
+
+			data.timer_channel.single_write(TimerEvent {
+				ty: *SCORE_BOARD,
+				instant: data.this_frame.0,
+				data: None,
+			});
+		}
+	}
+}
+
+impl SystemInfo for AwardBounty {
+	type Dependencies = CheckWin;
+
+	fn name() -> &'static str {
+		concat!(module_path!(), "::", line!())
+	}
+
+	fn new() -> Self {
+		Self::default()
+	}
+}
@@ -0,0 +1,86 @@
+use server::*;
+use specs::*;
+
+use component::*;
+use config::BtrConfig;
+use systems::CheckWin;
+
+use serde_json;
+
+use server::component::counter::{PlayersGame, TotalKills};
+use server::protocol::server::{ServerCustom, ServerMessage};
+use server::protocol::{ServerCustomType, ServerMessageType};
+use server::types::FlagCode;
+
+#[derive(Default)]
+pub struct DisplayWin {
+	reader: Option<OnGameWinReader>,
+}
+
+#[derive(SystemData)]
+pub struct DisplayWinData<'a> {
+	channel: Read<'a, OnGameWin>,
+	conns: Read<'a, Connections>,
+	players_game: Read<'a, PlayersGame>,
+	config: Read<'a, BtrConfig>,
+
+	name: ReadStorage<'a, Name>,
+	flag: ReadStorage<'a, FlagCode>,
+	kills: ReadStorage<'a, TotalKills>,
+}
+
+impl<'a> System<'a> for DisplayWin {
+	type SystemData = DisplayWinData<'a>;
+
+	fn setup(&mut self, res: &mut Resources) {
+		Self::SystemData::setup(res);
+
+		self.reader = Some(res.fetch_mut::<OnGameWin>().register_reader());
+	}
+
+	fn run(&mut self, data: Self::SystemData) {
+		for evt in data.channel.read(self.reader.as_mut().unwrap()) {
+			let winner = match evt.winner {
+				Some(winner) => winner,
+				None => {
+					data.conns.send_to_all(ServerMessage {
+						ty: ServerMessageType::Banner,
+						duration: 5000,
+						text: "Nobody survived the match".to_owned(),
+					});
+					continue;
+				}
+			};
+
+			let name = data.name.get(winner).map(|x| &*x.0).unwrap_or("");
+			let flag = data.flag.get(winner).cloned().unwrap_or(FlagCode::UnitedNations);
+			let kills = data.kills.get(winner).map(|x| x.0).unwrap_or(0);
+
+			let text = format!(
+				"{{\"p\":{},\"f\":{},\"b\":{},\"k\":{},\"t\":{}}}",
+				serde_json::to_string(name).unwrap(),
+				flag as u16,
+				data.players_game.0.min(10) * data.config.game_win_bounty_base.0,
+				kills,
+				data.config.game_reset_time.as_secs(),
+			);
+
+			data.conns.send_to_all(ServerCustom {
+				ty: ServerCustomType::BTRWin,
+				data: text,
+			});
+		}
+	}
+}
+
+impl SystemInfo for DisplayWin {
+	type Dependencies = CheckWin;
+
+	fn name() -> &'static str {
+		concat!(module_path!(), "::", line!())
+	}
+
+	fn new() -> Self {
+		Self::default()
+	}
+}
@@ -0,0 +1,7 @@
+mod award_bounty;
+mod display_win;
+mod setup_restart;
+
+pub use self::award_bounty::AwardBounty;
+pub use self::display_win::DisplayWin;
+pub use self::setup_restart::SetupRestart;
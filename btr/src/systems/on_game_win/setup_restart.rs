@@ -0,0 +1,57 @@
+use specs::*;
+
+use server::component::event::TimerEvent;
+use server::types::FutureDispatcher;
+use server::*;
+
+use component::*;
+use config::BtrConfig;
+use consts::OPEN_LOBBY_TIMER;
+use systems::CheckWin;
+
+/// Open the lobby again a while after the
+/// match has been won.
+#[derive(Default)]
+pub struct SetupRestart {
+	reader: Option<OnGameWinReader>,
+}
+
+#[derive(SystemData)]
+pub struct SetupRestartData<'a> {
+	channel: Read<'a, OnGameWin>,
+	future: ReadExpect<'a, FutureDispatcher>,
+	config: Read<'a, BtrConfig>,
+}
+
+impl<'a> System<'a> for SetupRestart {
+	type SystemData = SetupRestartData<'a>;
+
+	fn setup(&mut self, res: &mut Resources) {
+		Self::SystemData::setup(res);
+
+		self.reader = Some(res.fetch_mut::<OnGameWin>().register_reader());
+	}
+
+	fn run(&mut self, data: Self::SystemData) {
+		for _ in data.channel.read(self.reader.as_mut().unwrap()) {
+			data.future
+				.run_delayed(data.config.game_reset_time, move |inst| TimerEvent {
+					ty: *OPEN_LOBBY_TIMER,
+					instant: inst,
+					data: None,
+				});
+		}
+	}
+}
+
+impl SystemInfo for SetupRestart {
+	type Dependencies = CheckWin;
+
+	fn name() -> &'static str {
+		concat!(module_path!(), "::", line!())
+	}
+
+	fn new() -> Self {
+		Self::default()
+	}
+}
@@ -0,0 +1,30 @@
+use server::Builder;
+use specs::*;
+
+use super::*;
+use component::*;
+
+pub fn register<'a, 'b>(world: &mut World, disp: Builder<'a, 'b>) -> Builder<'a, 'b> {
+	world.add_resource(MatchPhase::default());
+	world.add_resource(SafeZone::default());
+	world.add_resource(Round::default());
+
+	disp.with::<CheckLobby>()
+		.with::<SpectateLateJoin>()
+		// Safe zone
+		.with::<zone::ShrinkZone>()
+		.with::<zone::ZoneDamage>()
+		.with_handler::<SpectateOnDeath>()
+		.with::<CheckWin>()
+		// On Game Win events
+		.with::<on_game_win::DisplayWin>()
+		.with::<on_game_win::AwardBounty>()
+		.with::<on_game_win::SetupRestart>()
+		// Timer events
+		.with::<timer::GameStart>()
+		.with::<timer::OpenLobby>()
+		// Game Start events
+		.with::<on_game_start::StartMatch>()
+		.with::<on_game_start::AssignPlanes>()
+		.with::<on_game_start::RespawnAll>()
+}
@@ -0,0 +1,44 @@
+use specs::*;
+
+use server::component::channel::*;
+use server::component::event::*;
+use server::component::flag::*;
+use server::consts::spectate::SPECTATOR_TEAM;
+use server::Team;
+
+/// Everything needed to bring every player
+/// back into the game at once, whether they
+/// are alive, dead or spectating.
+#[derive(SystemData)]
+pub struct RespawnAllData<'a> {
+	channel: Write<'a, OnPlayerRespawn>,
+
+	entities: Entities<'a>,
+	is_player: ReadStorage<'a, IsPlayer>,
+	team: ReadStorage<'a, Team>,
+	is_spec: WriteStorage<'a, IsSpectating>,
+	is_dead: WriteStorage<'a, IsDead>,
+}
+
+impl<'a> RespawnAllData<'a> {
+	pub fn respawn_all(&mut self) {
+		use self::PlayerRespawnPrevStatus::*;
+
+		// Spectators without a team stay in spec
+		let players = (&*self.entities, &self.team, self.is_player.mask())
+			.join()
+			.filter(|(_, team, ..)| **team != SPECTATOR_TEAM)
+			.map(|(ent, ..)| ent)
+			.collect::<Vec<_>>();
+
+		for player in players {
+			let was_spec = self.is_spec.remove(player).is_some();
+			let was_dead = self.is_dead.remove(player).is_some();
+
+			self.channel.single_write(PlayerRespawn {
+				player,
+				prev_status: if was_spec || was_dead { Dead } else { Alive },
+			});
+		}
+	}
+}
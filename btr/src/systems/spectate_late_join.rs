@@ -0,0 +1,83 @@
+use specs::*;
+
+use server::component::channel::*;
+use server::component::event::PlayerSpectate;
+use server::component::flag::IsPlayer;
+use server::consts::spectate::SPECTATOR_TEAM;
+use server::systems::handlers::game::on_join::AllJoinHandlers;
+use server::types::systemdata::IsAlive;
+use server::*;
+
+use component::*;
+
+/// Players that join while a match is being
+/// played have to spectate until it is over.
+#[derive(Default)]
+pub struct SpectateLateJoin {
+	reader: Option<OnPlayerJoinReader>,
+}
+
+#[derive(SystemData)]
+pub struct SpectateLateJoinData<'a> {
+	channel: Read<'a, OnPlayerJoin>,
+	spec_channel: Write<'a, OnPlayerSpectate>,
+	phase: Read<'a, MatchPhase>,
+
+	entities: Entities<'a>,
+	is_player: ReadStorage<'a, IsPlayer>,
+	is_alive: IsAlive<'a>,
+}
+
+impl<'a> System<'a> for SpectateLateJoin {
+	type SystemData = SpectateLateJoinData<'a>;
+
+	fn setup(&mut self, res: &mut Resources) {
+		Self::SystemData::setup(res);
+
+		self.reader = Some(res.fetch_mut::<OnPlayerJoin>().register_reader());
+	}
+
+	fn run(&mut self, mut data: Self::SystemData) {
+		for evt in data.channel.read(self.reader.as_mut().unwrap()) {
+			// These players are put in spec by the server
+			if evt.team == SPECTATOR_TEAM {
+				continue;
+			}
+
+			match *data.phase {
+				MatchPhase::Running | MatchPhase::Ended => (),
+				_ => continue,
+			}
+
+			let target = (
+				&*data.entities,
+				data.is_player.mask() & data.is_alive.mask(),
+			)
+				.join()
+				.map(|(ent, ..)| ent)
+				.find(|&ent| ent != evt.id);
+
+			// This goes through the same path as the
+			// spectate command, which takes care of
+			// removing the player's plane.
+			data.spec_channel.single_write(PlayerSpectate {
+				player: evt.id,
+				target,
+				is_dead: false,
+				is_spec: false,
+			});
+		}
+	}
+}
+
+impl SystemInfo for SpectateLateJoin {
+	type Dependencies = AllJoinHandlers;
+
+	fn name() -> &'static str {
+		concat!(module_path!(), "::", line!())
+	}
+
+	fn new() -> Self {
+		Self::default()
+	}
+}
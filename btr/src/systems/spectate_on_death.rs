@@ -0,0 +1,81 @@
+use specs::*;
+
+use server::component::channel::*;
+use server::component::event::*;
+use server::component::flag::IsPlayer;
+use server::systems::handlers::game::on_player_killed::KnownEventSources;
+use server::types::systemdata::IsAlive;
+use server::utils::{EventHandler, EventHandlerTypeProvider};
+use server::*;
+
+use component::*;
+use systems::zone::ZoneDamage;
+
+/// Put players into spec when they die during
+/// a match, since they won't be respawning.
+///
+/// They start off watching their killer if
+/// there was one.
+#[derive(Default)]
+pub struct SpectateOnDeath;
+
+#[derive(SystemData)]
+pub struct SpectateOnDeathData<'a> {
+	phase: Read<'a, MatchPhase>,
+	channel: Write<'a, OnPlayerSpectate>,
+
+	entities: Entities<'a>,
+	is_player: ReadStorage<'a, IsPlayer>,
+	is_alive: IsAlive<'a>,
+}
+
+impl EventHandlerTypeProvider for SpectateOnDeath {
+	type Event = PlayerKilled;
+}
+
+impl<'a> EventHandler<'a> for SpectateOnDeath {
+	type SystemData = SpectateOnDeathData<'a>;
+
+	fn on_event(&mut self, evt: &PlayerKilled, data: &mut Self::SystemData) {
+		match *data.phase {
+			MatchPhase::Running | MatchPhase::Ended => (),
+			_ => return,
+		}
+
+		if !data.entities.is_alive(evt.player) {
+			return;
+		}
+
+		let killer = Some(evt.killer)
+			.filter(|&killer| killer != evt.player)
+			.filter(|&killer| data.entities.is_alive(killer) && data.is_alive.get(killer));
+
+		let target = killer.or_else(|| {
+			(&*data.entities, data.is_player.mask() & data.is_alive.mask())
+				.join()
+				.map(|(ent, ..)| ent)
+				.find(|&ent| ent != evt.player)
+		});
+
+		data.channel.single_write(PlayerSpectate {
+			player: evt.player,
+			target,
+			// The player's plane has already been
+			// removed by the kill.
+			is_dead: true,
+			is_spec: false,
+		});
+	}
+}
+
+impl SystemInfo for SpectateOnDeath {
+	type Dependencies = (KnownEventSources, ZoneDamage);
+
+	fn name() -> &'static str {
+		concat!(module_path!(), "::", line!())
+	}
+
+	fn new() -> Self {
+		Self::default()
+	}
+}
@@ -0,0 +1,5 @@
+mod game_start;
+mod open_lobby;
+
+pub use self::game_start::GameStart;
+pub use self::open_lobby::OpenLobby;
@@ -0,0 +1,63 @@
+use specs::*;
+
+use server::component::channel::*;
+use server::systems::handlers::game::on_join::AllJoinHandlers;
+use server::*;
+
+use component::*;
+use consts::*;
+use gamemode::BTRGameMode;
+use systems::respawn_all::RespawnAllData;
+
+/// Go back to the lobby once the [`OPEN_LOBBY_TIMER`]
+/// fires after a match, bringing everyone that
+/// died during the match back to life.
+#[derive(Default)]
+pub struct OpenLobby {
+	reader: Option<OnTimerEventReader>,
+}
+
+#[derive(SystemData)]
+pub struct OpenLobbyData<'a> {
+	channel: Read<'a, OnTimerEvent>,
+	phase: Write<'a, MatchPhase>,
+	gamemode: GameModeWriter<'a, BTRGameMode>,
+	respawn: RespawnAllData<'a>,
+}
+
+impl<'a> System<'a> for OpenLobby {
+	type SystemData = OpenLobbyData<'a>;
+
+	fn setup(&mut self, res: &mut Resources) {
+		Self::SystemData::setup(res);
+
+		self.reader = Some(res.fetch_mut::<OnTimerEvent>().register_reader());
+	}
+
+	fn run(&mut self, mut data: Self::SystemData) {
+		for evt in data.channel.read(self.reader.as_mut().unwrap()) {
+			if evt.ty != *OPEN_LOBBY_TIMER {
+				continue;
+			}
+
+			*data.phase = MatchPhase::Lobby;
+			data.gamemode.match_running = false;
+			data.respawn.respawn_all();
+		}
+	}
+}
+
+impl SystemInfo for OpenLobby {
+	// PlayerRespawn accesses position by entity,
+	// so players that join this frame need to be
+	// set up first.
+	type Dependencies = AllJoinHandlers;
+
+	fn name() -> &'static str {
+		concat!(module_path!(), "::", line!())
+	}
+
+	fn new() -> Self {
+		Self::default()
+	}
+}
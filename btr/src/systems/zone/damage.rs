@@ -0,0 +1,100 @@
+use specs::*;
+
+use server::component::channel::OnPlayerKilled;
+use server::component::event::PlayerKilled;
+use server::component::flag::IsPlayer;
+use server::component::time::{LastFrame, ThisFrame};
+use server::systems::{HealthRegenSystem, PositionUpdate};
+use server::types::systemdata::IsAlive;
+use server::*;
+
+use component::*;
+use config::BtrConfig;
+
+use super::ShrinkZone;
+
+/// Damage every plane that is outside of the
+/// safe zone, killing them once they run out
+/// of health.
+#[derive(Default)]
+pub struct ZoneDamage;
+
+#[derive(SystemData)]
+pub struct ZoneDamageData<'a> {
+	phase: Read<'a, MatchPhase>,
+	zone: Read<'a, SafeZone>,
+	config: Read<'a, BtrConfig>,
+	kill_channel: Write<'a, OnPlayerKilled>,
+	this_frame: Read<'a, ThisFrame>,
+	last_frame: Read<'a, LastFrame>,
+
+	entities: Entities<'a>,
+	pos: ReadStorage<'a, Position>,
+	health: WriteStorage<'a, Health>,
+	is_player: ReadStorage<'a, IsPlayer>,
+	is_alive: IsAlive<'a>,
+}
+
+impl<'a> System<'a> for ZoneDamage {
+	type SystemData = ZoneDamageData<'a>;
+
+	fn run(&mut self, mut data: Self::SystemData) {
+		if *data.phase != MatchPhase::Running {
+			return;
+		}
+
+		let delta = data.this_frame.0 - data.last_frame.0;
+		let secs = delta.as_secs() as f32 + delta.subsec_nanos() as f32 * 1.0e-9;
+		// The zone ignores shields and upgrades,
+		// nothing can save a plane outside of it.
+		let damage = Health::new(data.config.zone.damage * secs);
+
+		let center = data.zone.center;
+		let radius = data.zone.radius;
+		let mut killed = vec![];
+
+		for (ent, &pos, health, ..) in (
+			&*data.entities,
+			&data.pos,
+			&mut data.health,
+			data.is_player.mask() & data.is_alive.mask(),
+		)
+			.join()
+		{
+			if (pos - center).length2() <= radius * radius {
+				continue;
+			}
+
+			// Players that were killed by a missile this
+			// frame will already be out of health.
+			if health.inner() <= 0.0 {
+				continue;
+			}
+
+			*health -= damage;
+
+			if health.inner() <= 0.0 {
+				killed.push(PlayerKilled {
+					missile: ent,
+					player: ent,
+					killer: ent,
+					pos,
+				});
+			}
+		}
+
+		data.kill_channel.iter_write(killed.into_iter());
+	}
+}
+
+impl SystemInfo for ZoneDamage {
+	type Dependencies = (ShrinkZone, PositionUpdate, HealthRegenSystem);
+
+	fn name() -> &'static str {
+		concat!(module_path!(), "::", line!())
+	}
+
+	fn new() -> Self {
+		Self::default()
+	}
+}
@@ -0,0 +1,5 @@
+mod damage;
+mod shrink;
+
+pub use self::damage::ZoneDamage;
+pub use self::shrink::ShrinkZone;
@@ -0,0 +1,45 @@
+use specs::*;
+
+use server::component::time::ThisFrame;
+use server::*;
+
+use component::*;
+use config::BtrConfig;
+
+/// Shrink the safe zone over the course
+/// of a match.
+#[derive(Default)]
+pub struct ShrinkZone;
+
+#[derive(SystemData)]
+pub struct ShrinkZoneData<'a> {
+	phase: Read<'a, MatchPhase>,
+	zone: Write<'a, SafeZone>,
+	config: Read<'a, BtrConfig>,
+	this_frame: Read<'a, ThisFrame>,
+}
+
+impl<'a> System<'a> for ShrinkZone {
+	type SystemData = ShrinkZoneData<'a>;
+
+	fn run(&mut self, mut data: Self::SystemData) {
+		if *data.phase != MatchPhase::Running {
+			return;
+		}
+
+		let elapsed = data.this_frame.0 - data.zone.start;
+		data.zone.radius = data.config.zone.radius_at(elapsed);
+	}
+}
+
+impl SystemInfo for ShrinkZone {
+	type Dependencies = ();
+
+	fn name() -> &'static str {
+		concat!(module_path!(), "::", line!())
+	}
+
+	fn new() -> Self {
+		Self::default()
+	}
+}
@@ -1,95 +1,208 @@
 use server::protocol::PlaneType;
+use server::utils::{load_file, LoadError};
 use server::*;
 
 use fnv::FnvHashMap;
+use shuffle::ShuffleType;
+
+use std::error::Error;
+use std::fmt;
+use std::path::Path;
 use std::time::Duration;
 
 lazy_static! {
-	pub static ref FLAG_RADIUS: FnvHashMap<Plane, Distance> = {
+	pub static ref FLAG_NO_REGRAB_TIME: Duration = Duration::from_secs(5);
+
+	pub static ref FLAG_MESSAGE_TEAM: FnvHashMap<Team, &'static str> = {
 		let mut map = FnvHashMap::default();
 
-		// These are just random guesses
-		// TODO: rev-eng these from official server
-		map.insert(
-			PlaneType::Predator,
-			Distance::new(100.0)
-		);
+		map.insert(Team(1), "blueflag");
+		map.insert(Team(2), "redflag");
 
-		map.insert(
-			PlaneType::Goliath,
-			Distance::new(100.0)
-		);
+		map
+	};
+}
 
-		map.insert(
-			PlaneType::Tornado,
-			Distance::new(100.0)
-		);
+pub const BLUE_TEAM: Team = Team(1);
+pub const RED_TEAM: Team = Team(2);
 
-		map.insert(
-			PlaneType::Prowler,
-			Distance::new(100.0)
-		);
+/// Tunable parameters for CTF games.
+///
+/// This is stored as a resource and can be loaded
+/// from a TOML or JSON file using [`CtfConfig::load`].
+/// Any fields that are missing from the file will
+/// use their default values.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CtfConfig {
+	/// Distance that a plane must be within to
+	/// pick up or return a flag.
+	pub flag_radius: FnvHashMap<Plane, Distance>,
+	/// Distance that the player must be within to cap
+	pub cap_radius: Distance,
 
-		map.insert(
+	/// Position of the blue flag when at home. This
+	/// is taken from the map, see [`CtfConfig::apply_map`].
+	#[serde(skip)]
+	pub blue_flag_pos: Position,
+	/// Position of the red flag when at home. This
+	/// is taken from the map, see [`CtfConfig::apply_map`].
+	#[serde(skip)]
+	pub red_flag_pos: Position,
+
+	/// Time between winning a game and a new game starting
+	pub game_reset_time: Duration,
+	/// Number of captures a team needs to win a game
+	pub caps_to_win: u8,
+
+	/// The base score that a player would get if they were
+	/// the only ones on the server and they capped. This
+	/// value will be multiplied by the number of players
+	/// in the server (up to a max of 10 times).
+	pub flag_cap_bounty_base: Score,
+	/// The base score that a winning player would get
+	/// if they were the only ones on the server.
+	pub game_win_bounty_base: Score,
+
+	/// Shuffle used to reassign teams between games
+	pub shuffle: ShuffleType,
+	/// Put players that connect from the same IP address
+	/// in a party, which the balanced shuffle keeps on
+	/// the same team. This can't tell friends apart from
+	/// strangers behind the same NAT (e.g. at a school or
+	/// on a mobile network), so it can be turned off.
+	pub parties_by_addr: bool,
+}
+
+impl CtfConfig {
+	/// Position of a team's flag when it is at home.
+	pub fn flag_home_pos(&self, team: Team) -> Position {
+		if team == BLUE_TEAM {
+			self.blue_flag_pos
+		} else {
+			self.red_flag_pos
+		}
+	}
+
+	/// Position that a team's flag needs to be
+	/// brought to in order to be captured. Flags
+	/// get returned at the opposite base.
+	pub fn flag_return_pos(&self, team: Team) -> Position {
+		if team == BLUE_TEAM {
+			self.red_flag_pos
+		} else {
+			self.blue_flag_pos
+		}
+	}
+
+	/// Place the flags at the bases of `map`. Both
+	/// teams must have a base on the map.
+	pub fn apply_map(&mut self, map: &Map) -> Result<(), CtfConfigError> {
+		let blue = map
+			.base(BLUE_TEAM)
+			.ok_or(CtfConfigError::MissingBase(BLUE_TEAM))?;
+		let red = map
+			.base(RED_TEAM)
+			.ok_or(CtfConfigError::MissingBase(RED_TEAM))?;
+
+		self.blue_flag_pos = blue.flag;
+		self.red_flag_pos = red.flag;
+
+		Ok(())
+	}
+
+	/// Load the config from a TOML or JSON file,
+	/// depending on the file extension.
+	pub fn load<P: AsRef<Path>>(path: P) -> Result<Self, CtfConfigError> {
+		let config: CtfConfig = load_file(path)?;
+
+		config.validate()?;
+
+		Ok(config)
+	}
+
+	pub fn validate(&self) -> Result<(), CtfConfigError> {
+		const PLANES: [PlaneType; 5] = [
+			PlaneType::Predator,
+			PlaneType::Goliath,
 			PlaneType::Mohawk,
-			Distance::new(100.0)
-		);
+			PlaneType::Tornado,
+			PlaneType::Prowler,
+		];
 
-		map
-	};
-	// TODO: Actually determine this
-	/// Distance that the player must be within to cap
-	pub static ref CAP_RADIUS: Distance = Distance::new(100.0);
+		for plane in PLANES.iter() {
+			if !self.flag_radius.contains_key(plane) {
+				return Err(CtfConfigError::MissingFlagRadius(*plane));
+			}
+		}
 
-	pub static ref FLAG_HOME_POS: FnvHashMap<Team, Position> = {
-		let mut map = FnvHashMap::default();
+		if self.caps_to_win == 0 {
+			return Err(CtfConfigError::NoCapsToWin);
+		}
 
-		// Blue team
-		map.insert(Team(1), Position::new(
-			Distance::new(-9670.0),
-			Distance::new(-1470.0))
-		);
-		// Red team
-		map.insert(Team(2), Position::new(
-			Distance::new(8600.0),
-			Distance::new(-940.0))
-		);
+		Ok(())
+	}
+}
 
-		map
-	};
-	pub static ref FLAG_RETURN_POS: FnvHashMap<Team, Position> = {
-		let mut map = FnvHashMap::default();
+impl Default for CtfConfig {
+	fn default() -> Self {
+		let mut flag_radius = FnvHashMap::default();
 
-		// Flags get returned at the opposite base
-		map.insert(Team(2), FLAG_HOME_POS[&Team(1)]);
-		map.insert(Team(1), FLAG_HOME_POS[&Team(2)]);
+		// These are just random guesses
+		// TODO: rev-eng these from official server
+		flag_radius.insert(PlaneType::Predator, Distance::new(100.0));
+		flag_radius.insert(PlaneType::Goliath, Distance::new(100.0));
+		flag_radius.insert(PlaneType::Tornado, Distance::new(100.0));
+		flag_radius.insert(PlaneType::Prowler, Distance::new(100.0));
+		flag_radius.insert(PlaneType::Mohawk, Distance::new(100.0));
 
-		map
-	};
+		let map = Map::default();
 
-	pub static ref FLAG_NO_REGRAB_TIME: Duration = Duration::from_secs(5);
+		Self {
+			flag_radius,
+			// TODO: Actually determine this
+			cap_radius: Distance::new(100.0),
 
-	pub static ref FLAG_MESSAGE_TEAM: FnvHashMap<Team, &'static str> = {
-		let mut map = FnvHashMap::default();
+			blue_flag_pos: map.base(BLUE_TEAM).unwrap().flag,
+			red_flag_pos: map.base(RED_TEAM).unwrap().flag,
 
-		map.insert(Team(1), "blueflag");
-		map.insert(Team(2), "redflag");
+			game_reset_time: Duration::from_secs(60),
+			caps_to_win: 3,
 
-		map
-	};
+			flag_cap_bounty_base: Score(100),
+			game_win_bounty_base: Score(100),
 
-	/// Time between winning a game and a new game starting
-	pub static ref GAME_RESET_TIME: Duration = Duration::from_secs(60);
+			shuffle: ShuffleType::default(),
+			parties_by_addr: true,
+		}
+	}
 }
 
-pub const BLUE_TEAM: Team = Team(1);
-pub const RED_TEAM: Team = Team(2);
+#[derive(Debug)]
+pub enum CtfConfigError {
+	Load(LoadError),
+	MissingFlagRadius(Plane),
+	NoCapsToWin,
+	MissingBase(Team),
+}
+
+impl fmt::Display for CtfConfigError {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		use self::CtfConfigError::*;
+
+		match self {
+			Load(e) => write!(f, "unable to load CTF config file: {}", e),
+			MissingFlagRadius(plane) => write!(f, "no flag radius for plane {:?}", plane),
+			NoCapsToWin => write!(f, "caps_to_win must be at least 1"),
+			MissingBase(team) => write!(f, "the map has no base for team {}", team.0),
+		}
+	}
+}
+
+impl Error for CtfConfigError {}
 
-/// The base score that a player would get if they were
-/// the only ones on the server and they capped. This
-/// value will be multiplied by the number of players
-/// in the server (up to a max of 10 times).
-pub const FLAG_CAP_BOUNTY_BASE: Score = Score(100);
-/// The base score that a winning player would get
-/// if they were the only ones on the server.
-pub const GAME_WIN_BOUNTY_BASE: Score = Score(100);
+impl From<LoadError> for CtfConfigError {
+	fn from(e: LoadError) -> Self {
+		CtfConfigError::Load(e)
+	}
+}
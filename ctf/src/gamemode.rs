@@ -1,30 +1,37 @@
-use server::protocol::GameType;
+use server::protocol::{GameType, PowerupType};
+use server::types::PowerupSpawn;
 use server::*;
 
 use rand;
 use specs::Entity;
 
 use std::cmp::Ordering;
+use std::time::Duration;
 
 pub const RED_TEAM: Team = Team(2);
 pub const BLUE_TEAM: Team = Team(1);
 
-lazy_static! {
-	static ref BLUE_TEAM_RESPAWN: Position =
-		Position::new(Distance::new(-8878.0), Distance::new(-2971.0));
-	static ref RED_TEAM_RESPAWN: Position =
-		Position::new(Distance::new(7818.0), Distance::new(-2930.0));
-}
+/// Distance between each flag and the shield at
+/// its base. The shield is on the side of the flag
+/// away from the middle of the map, so attackers
+/// don't pick it up whenever they grab the flag.
+const SHIELD_OFFSET: f32 = 400.0;
 
 #[derive(Default, Debug)]
 pub struct CTFGameMode {
 	pub redteam: u16,
 	pub blueteam: u16,
+	/// Map that the game is being played on,
+	/// used to find the team respawn points.
+	pub map: Map,
 }
 
 impl CTFGameMode {
-	pub fn new() -> Self {
-		Self::default()
+	pub fn new(map: Map) -> Self {
+		Self {
+			map,
+			..Self::default()
+		}
 	}
 }
 
@@ -59,14 +66,39 @@ impl GameMode for CTFGameMode {
 	}
 
 	fn spawn_pos(&mut self, _: Entity, team: Team) -> Position {
-		if team == BLUE_TEAM {
-			*BLUE_TEAM_RESPAWN
-		} else if team == RED_TEAM {
-			*RED_TEAM_RESPAWN
-		} else {
+		if team != BLUE_TEAM && team != RED_TEAM {
 			// No need for this yet
 			unimplemented!();
 		}
+
+		match self.map.random_spawn(team) {
+			Some(pos) => pos,
+			None => panic!("Map {:?} has no spawn zone for team {}", self.map.name, team.0),
+		}
+	}
+
+	fn upgrade_drop_chance(&self) -> f32 {
+		0.5
+	}
+
+	fn powerup_spawns(&self) -> Vec<PowerupSpawn> {
+		// Every base has a shield for its defenders
+		self.map
+			.bases
+			.iter()
+			.map(|base| {
+				let bound = self.map.bounds.x.inner();
+				let flag = base.flag.x.inner();
+				let x = flag + SHIELD_OFFSET * flag.signum();
+
+				PowerupSpawn {
+					pos: Position::new(Distance::new(x.max(-bound).min(bound)), base.flag.y),
+					ty: PowerupType::Shield,
+					respawn: Duration::from_secs(90),
+					jitter: Duration::from_secs(15),
+				}
+			})
+			.collect()
 	}
 
 	fn gametype(&self) -> GameType {
@@ -6,15 +6,19 @@ extern crate shred_derive;
 extern crate lazy_static;
 #[macro_use]
 extern crate log;
+#[macro_use]
+extern crate serde;
 
 extern crate airmash_server;
 extern crate env_logger;
 extern crate fnv;
 extern crate htmlescape;
 extern crate rand;
+extern crate serde_json;
 extern crate shred;
 extern crate shrev;
 extern crate specs;
+extern crate toml;
 
 use airmash_server as server;
 
@@ -27,7 +31,9 @@ mod systems;
 
 use std::env;
 
+use config::CtfConfig;
 use gamemode::{CTFGameMode, BLUE_TEAM, RED_TEAM};
+use server::types::{BanList, FileAccountStore, FileSessionValidator, Map};
 use server::AirmashServer;
 
 fn main() {
@@ -36,13 +42,66 @@ fn main() {
 
 	env_logger::init();
 
+	let map = match env::var("MAP_FILE") {
+		Ok(path) => match Map::load(&path) {
+			Ok(map) => map,
+			Err(e) => panic!("Failed to load map from {}: {}", path, e),
+		},
+		Err(_) => Map::default(),
+	};
+	if let Err(e) = map.check_spawns(&[BLUE_TEAM, RED_TEAM]) {
+		panic!("Map {:?} cannot be used for CTF: {}", map.name, e);
+	}
+
 	let mut server = AirmashServer::new("0.0.0.0:3501")
 		.with_engine()
-		.with_gamemode(CTFGameMode::new())
+		.with_map(map.clone())
+		.with_gamemode(CTFGameMode::new(map.clone()))
 		.with_alpha_warning();
 
+	let mut ctf_config = match env::var("CTF_CONFIG") {
+		Ok(path) => match CtfConfig::load(&path) {
+			Ok(config) => config,
+			Err(e) => panic!("Failed to load CTF config from {}: {}", path, e),
+		},
+		Err(_) => CtfConfig::default(),
+	};
+	if let Err(e) = ctf_config.apply_map(&map) {
+		panic!("Map {:?} cannot be used for CTF: {}", map.name, e);
+	}
+	let shuffle = shuffle::get_shuffle(ctf_config.shuffle);
+	server.world.add_resource(ctf_config);
+
 	server.builder = systems::register(&mut server.world, server.builder);
-	server.world.add_resource(shuffle::get_shuffle());
+	server.world.add_resource(shuffle);
+
+	if let Ok(path) = env::var("CONFIG_FILE") {
+		server = server.with_config_file(path);
+	}
+
+	if let Ok(path) = env::var("ACCOUNTS_FILE") {
+		let store = match FileAccountStore::open(&path) {
+			Ok(store) => store,
+			Err(e) => panic!("Failed to open accounts file {}: {}", path, e),
+		};
+		server = server.with_account_store(store);
+	}
+
+	if let Ok(path) = env::var("SESSIONS_FILE") {
+		server = server.with_session_validator(FileSessionValidator::new(path));
+	}
+
+	if let Ok(path) = env::var("BANS_FILE") {
+		let bans = match BanList::open(&path) {
+			Ok(bans) => bans,
+			Err(e) => panic!("Failed to open bans file {}: {}", path, e),
+		};
+		server = server.with_ban_list(bans);
+	}
+
+	if let Ok(path) = env::var("REPLAY_FILE") {
+		server = server.with_replay_file(path);
+	}
 
 	server.run();
 }
@@ -0,0 +1,308 @@
+use super::*;
+
+use config::{BLUE_TEAM, RED_TEAM};
+use server::Team;
+use specs::Entity;
+
+use fnv::FnvHashMap;
+
+/// Weight of a single capture, in score units
+const CAPTURE_WEIGHT: f32 = 200.0;
+/// Weight of kill-death difference, in score units
+const KD_WEIGHT: f32 = 10.0;
+
+/// Shuffle that tries to make both teams as evenly
+/// matched as possible.
+///
+/// Every player is given a rating based on their
+/// score, captures and kills/deaths. Parties (groups
+/// of players that joined together) are kept on the
+/// same team where possible. Groups are then assigned
+/// greedily, strongest first, to the weaker team while
+/// keeping team sizes within one of each other. After
+/// that, single players are swapped between the teams
+/// as long as doing so reduces the rating difference.
+pub struct BalancedShuffle;
+
+fn rating(info: &PlayerShuffleInfo) -> f32 {
+	let kd = info.kills as f32 - info.deaths as f32;
+
+	(info.score.0 as f32 + info.captures as f32 * CAPTURE_WEIGHT + kd * KD_WEIGHT).max(0.0)
+}
+
+struct Group {
+	players: Vec<(Entity, Team, f32)>,
+	rating: f32,
+}
+
+#[derive(Default)]
+struct TeamState {
+	players: Vec<(Entity, Team, f32)>,
+	rating: f32,
+}
+
+impl TeamState {
+	fn add(&mut self, player: (Entity, Team, f32)) {
+		self.rating += player.2;
+		self.players.push(player);
+	}
+}
+
+impl ShuffleProvider for BalancedShuffle {
+	fn shuffle(&self, infos: Vec<PlayerShuffleInfo>) -> Vec<TeamChangeEntry> {
+		let infos = infos
+			.into_iter()
+			.filter(|info| info.team == RED_TEAM || info.team == BLUE_TEAM)
+			.collect::<Vec<_>>();
+
+		let total = infos.len();
+		// Maximum number of players on a single team
+		let max_size = (total + 1) / 2;
+
+		let mut parties: FnvHashMap<u32, Group> = FnvHashMap::default();
+		let mut groups = vec![];
+
+		for info in infos.iter() {
+			let player = (info.player, info.team, rating(info));
+
+			let group = match info.party {
+				Some(party) => parties.entry(party).or_insert_with(|| Group {
+					players: vec![],
+					rating: 0.0,
+				}),
+				None => {
+					groups.push(Group {
+						players: vec![player],
+						rating: player.2,
+					});
+					continue;
+				}
+			};
+
+			group.rating += player.2;
+			group.players.push(player);
+		}
+
+		for (_, group) in parties {
+			// Parties too big to fit on one team get split up
+			if group.players.len() > max_size {
+				groups.extend(group.players.into_iter().map(|player| Group {
+					players: vec![player],
+					rating: player.2,
+				}));
+			} else {
+				groups.push(group);
+			}
+		}
+
+		// Place larger groups first, then stronger ones
+		groups.sort_by(|a, b| {
+			b.players
+				.len()
+				.cmp(&a.players.len())
+				.then(b.rating.partial_cmp(&a.rating).unwrap())
+		});
+
+		let mut teams = [TeamState::default(), TeamState::default()];
+
+		for group in groups {
+			let weaker = if teams[0].rating <= teams[1].rating {
+				0
+			} else {
+				1
+			};
+
+			let size = group.players.len();
+			let fits = |team: &TeamState| team.players.len() + size <= max_size;
+
+			let idx = if fits(&teams[weaker]) {
+				weaker
+			} else if fits(&teams[1 - weaker]) {
+				1 - weaker
+			} else {
+				// Neither team has space for the whole
+				// group so it has to be split up.
+				for player in group.players {
+					let idx = if teams[0].players.len() <= teams[1].players.len() {
+						0
+					} else {
+						1
+					};
+					teams[idx].add(player);
+				}
+				continue;
+			};
+
+			for player in group.players {
+				teams[idx].add(player);
+			}
+		}
+
+		improve_by_swaps(&mut teams, &infos);
+
+		// Pick whichever assignment of colours to teams
+		// results in the fewest players changing team.
+		let kept = |state: &TeamState, team: Team| {
+			state.players.iter().filter(|(_, old, _)| *old == team).count()
+		};
+		let unchanged = kept(&teams[0], BLUE_TEAM) + kept(&teams[1], RED_TEAM);
+
+		let colours = if unchanged * 2 >= total {
+			[BLUE_TEAM, RED_TEAM]
+		} else {
+			[RED_TEAM, BLUE_TEAM]
+		};
+
+		teams
+			.iter()
+			.zip(colours.iter())
+			.flat_map(|(state, &new_team)| {
+				state
+					.players
+					.iter()
+					.filter(move |(_, old, _)| *old != new_team)
+					.map(move |&(player, ..)| TeamChangeEntry { player, new_team })
+			})
+			.collect()
+	}
+}
+
+/// Swap single (non-party) players between teams
+/// while that reduces the rating difference.
+fn improve_by_swaps(teams: &mut [TeamState; 2], infos: &[PlayerShuffleInfo]) {
+	let in_party = infos
+		.iter()
+		.map(|info| (info.player, info.party.is_some()))
+		.collect::<FnvHashMap<_, _>>();
+
+	loop {
+		let diff = teams[0].rating - teams[1].rating;
+		let mut best: Option<(usize, usize, f32)> = None;
+
+		for (i, a) in teams[0].players.iter().enumerate() {
+			if in_party[&a.0] {
+				continue;
+			}
+
+			for (j, b) in teams[1].players.iter().enumerate() {
+				if in_party[&b.0] {
+					continue;
+				}
+
+				let new_diff = (diff - 2.0 * (a.2 - b.2)).abs();
+				let current = best.map(|x| x.2).unwrap_or(diff.abs());
+
+				if new_diff < current {
+					best = Some((i, j, new_diff));
+				}
+			}
+		}
+
+		let (i, j, _) = match best {
+			Some(x) => x,
+			None => break,
+		};
+
+		let a = teams[0].players[i];
+		let b = teams[1].players[j];
+
+		teams[0].players[i] = b;
+		teams[1].players[j] = a;
+		teams[0].rating += b.2 - a.2;
+		teams[1].rating += a.2 - b.2;
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	use server::Score;
+	use specs::{Builder, World};
+
+	fn info(world: &mut World, team: Team, score: u32, party: Option<u32>) -> PlayerShuffleInfo {
+		PlayerShuffleInfo {
+			player: world.create_entity().build(),
+			score: Score(score),
+			team,
+			captures: 0,
+			kills: 0,
+			deaths: 0,
+			party,
+		}
+	}
+
+	fn final_teams(
+		infos: &[(Entity, Team, u32)],
+		changes: Vec<TeamChangeEntry>,
+	) -> (Vec<u32>, Vec<u32>) {
+		let mut blue = vec![];
+		let mut red = vec![];
+
+		for &(player, team, score) in infos {
+			let team = changes
+				.iter()
+				.find(|x| x.player == player)
+				.map(|x| x.new_team)
+				.unwrap_or(team);
+
+			if team == BLUE_TEAM {
+				blue.push(score);
+			} else {
+				red.push(score);
+			}
+		}
+
+		(blue, red)
+	}
+
+	#[test]
+	fn balances_stacked_teams() {
+		let mut world = World::new();
+		let infos = vec![
+			info(&mut world, BLUE_TEAM, 1000, None),
+			info(&mut world, BLUE_TEAM, 900, None),
+			info(&mut world, RED_TEAM, 100, None),
+			info(&mut world, RED_TEAM, 50, None),
+		];
+		let summary = infos
+			.iter()
+			.map(|x| (x.player, x.team, x.score.0))
+			.collect::<Vec<_>>();
+
+		let (blue, red) = final_teams(&summary, BalancedShuffle.shuffle(infos));
+
+		assert_eq!(blue.len(), 2);
+		assert_eq!(red.len(), 2);
+
+		let diff = blue.iter().sum::<u32>() as i64 - red.iter().sum::<u32>() as i64;
+		assert_eq!(diff.abs(), 50);
+	}
+
+	#[test]
+	fn keeps_parties_together() {
+		let mut world = World::new();
+		let infos = vec![
+			info(&mut world, BLUE_TEAM, 500, Some(1)),
+			info(&mut world, RED_TEAM, 500, Some(1)),
+			info(&mut world, BLUE_TEAM, 400, None),
+			info(&mut world, RED_TEAM, 400, None),
+		];
+		let party = [infos[0].player, infos[1].player];
+		let summary = infos
+			.iter()
+			.map(|x| (x.player, x.team, x.score.0))
+			.collect::<Vec<_>>();
+
+		let changes = BalancedShuffle.shuffle(infos);
+		let team_of = |player: Entity| {
+			changes
+				.iter()
+				.find(|x| x.player == player)
+				.map(|x| x.new_team)
+				.unwrap_or_else(|| summary.iter().find(|x| x.0 == player).unwrap().1)
+		};
+
+		assert_eq!(team_of(party[0]), team_of(party[1]));
+	}
+}
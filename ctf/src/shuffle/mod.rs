@@ -1,10 +1,12 @@
 mod alternating_shuffle;
+mod balanced_shuffle;
 mod even_shuffle;
 mod no_shuffle;
 mod random_shuffle;
 mod structs;
 
 pub use self::alternating_shuffle::AlternatingShuffle;
+pub use self::balanced_shuffle::BalancedShuffle;
 pub use self::even_shuffle::EvenShuffle;
 pub use self::no_shuffle::NoShuffle;
 pub use self::random_shuffle::RandomShuffle;
@@ -15,6 +17,31 @@ pub trait ShuffleProvider {
 	fn shuffle(&self, infos: Vec<PlayerShuffleInfo>) -> Vec<TeamChangeEntry>;
 }
 
-pub fn get_shuffle() -> Box<ShuffleProvider + Sync + Send> {
-	Box::new(AlternatingShuffle)
+/// The shuffle to use at the end of each game,
+/// selected using [`CtfConfig::shuffle`][0].
+///
+/// [0]: ::config::CtfConfig
+#[derive(Copy, Clone, Debug, Serialize, Deserialize)]
+pub enum ShuffleType {
+	Alternating,
+	Balanced,
+	Even,
+	Random,
+	None,
+}
+
+impl Default for ShuffleType {
+	fn default() -> Self {
+		ShuffleType::Alternating
+	}
+}
+
+pub fn get_shuffle(ty: ShuffleType) -> Box<ShuffleProvider + Sync + Send> {
+	match ty {
+		ShuffleType::Alternating => Box::new(AlternatingShuffle),
+		ShuffleType::Balanced => Box::new(BalancedShuffle),
+		ShuffleType::Even => Box::new(EvenShuffle),
+		ShuffleType::Random => Box::new(RandomShuffle),
+		ShuffleType::None => Box::new(NoShuffle),
+	}
 }
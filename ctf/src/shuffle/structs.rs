@@ -9,6 +9,9 @@ pub struct PlayerShuffleInfo {
 	pub captures: u32,
 	pub kills: u32,
 	pub deaths: u32,
+	/// Players with the same party joined together
+	/// and should be kept on the same team.
+	pub party: Option<u32>,
 }
 
 pub struct TeamChangeEntry {
@@ -0,0 +1,82 @@
+use server::component::flag::IsBot;
+use server::systems::bots::BotGoal;
+use server::*;
+use specs::*;
+
+use component::*;
+use config::{CtfConfig, BLUE_TEAM, RED_TEAM};
+use systems::PosUpdateSystem;
+
+/// Send bots after the flags.
+///
+/// Bots carrying the enemy flag head back to base
+/// to capture it, bots whose flag has been taken
+/// go after it and all other bots go for the
+/// enemy flag.
+#[derive(Default)]
+pub struct SetBotGoals;
+
+#[derive(SystemData)]
+pub struct SetBotGoalsData<'a> {
+	entities: Entities<'a>,
+	flags: ReadExpect<'a, Flags>,
+	config: Read<'a, CtfConfig>,
+
+	pos: ReadStorage<'a, Position>,
+	team: ReadStorage<'a, Team>,
+	carrier: ReadStorage<'a, FlagCarrier>,
+	is_bot: ReadStorage<'a, IsBot>,
+	goals: WriteStorage<'a, BotGoal>,
+}
+
+impl<'a> System<'a> for SetBotGoals {
+	type SystemData = SetBotGoalsData<'a>;
+
+	fn run(&mut self, mut data: Self::SystemData) {
+		let flags = *data.flags;
+		let flag_of = |team: Team| if team == BLUE_TEAM { flags.blue } else { flags.red };
+
+		let bots = (&*data.entities, &data.team, &data.is_bot)
+			.join()
+			.map(|(ent, team, _)| (ent, *team))
+			.collect::<Vec<_>>();
+
+		for (bot, team) in bots {
+			let enemy_team = if team == BLUE_TEAM { RED_TEAM } else { BLUE_TEAM };
+			let own_flag = flag_of(team);
+			let enemy_flag = flag_of(enemy_team);
+
+			let (own_pos, enemy_pos) = match (data.pos.get(own_flag), data.pos.get(enemy_flag)) {
+				(Some(own), Some(enemy)) => (*own, *enemy),
+				_ => continue,
+			};
+
+			let carrying = data.carrier.get(enemy_flag).and_then(|c| c.0) == Some(bot);
+			let own_flag_taken = (own_pos - data.config.flag_home_pos(team))
+				.length2()
+				.inner() > 0.01;
+
+			let goal = if carrying {
+				data.config.flag_return_pos(enemy_team)
+			} else if own_flag_taken {
+				own_pos
+			} else {
+				enemy_pos
+			};
+
+			data.goals.insert(bot, BotGoal(goal)).unwrap();
+		}
+	}
+}
+
+impl SystemInfo for SetBotGoals {
+	type Dependencies = PosUpdateSystem;
+
+	fn name() -> &'static str {
+		concat!(module_path!(), "::", line!())
+	}
+
+	fn new() -> Self {
+		Self::default()
+	}
+}
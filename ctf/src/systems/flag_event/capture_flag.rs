@@ -1,7 +1,7 @@
 use server::*;
 use specs::*;
 
-use config as ctfconfig;
+use config::{self as ctfconfig, CtfConfig};
 
 use component::*;
 
@@ -19,6 +19,7 @@ pub struct CaptureFlagData<'a> {
 	pub carrier: WriteStorage<'a, FlagCarrier>,
 
 	pub scores: Read<'a, GameScores>,
+	pub config: Read<'a, CtfConfig>,
 	pub channel: Write<'a, OnFlag>,
 	pub conns: Read<'a, Connections>,
 }
@@ -30,6 +31,7 @@ impl<'a> System<'a> for CaptureFlag {
 		let mut channel = data.channel;
 		let conns = data.conns;
 		let scores = *data.scores;
+		let config = data.config;
 
 		(
 			&mut data.pos,
@@ -41,14 +43,14 @@ impl<'a> System<'a> for CaptureFlag {
 			.join()
 			.filter(|(pos, team, carrier, _, _)| {
 				// Filter out all flags that aren't within cap radius
-				(ctfconfig::FLAG_RETURN_POS[&team] - **pos).length2()
-					< *ctfconfig::CAP_RADIUS * *ctfconfig::CAP_RADIUS
+				(config.flag_return_pos(**team) - **pos).length2()
+					< config.cap_radius * config.cap_radius
 					&& carrier.0.is_some()
 			})
 			.for_each(|(pos, team, carrier, _, ent)| {
 				let captor = carrier.0.unwrap();
 
-				*pos = ctfconfig::FLAG_HOME_POS[team];
+				*pos = config.flag_home_pos(*team);
 				*carrier = FlagCarrier(None);
 
 				let blueinc;
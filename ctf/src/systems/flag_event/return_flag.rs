@@ -1,7 +1,7 @@
 use server::*;
 use specs::*;
 
-use config as ctfconfig;
+use config::CtfConfig;
 
 use component::*;
 
@@ -23,6 +23,7 @@ pub struct ReturnFlagData<'a> {
 	carrier: ReadStorage<'a, FlagCarrier>,
 	keystate: ReadStorage<'a, KeyState>,
 	is_alive: IsAlive<'a>,
+	config: Read<'a, CtfConfig>,
 
 	channel: Write<'a, OnFlag>,
 }
@@ -40,6 +41,7 @@ impl<'a> System<'a> for ReturnFlag {
 		let carrier = data.carrier;
 		let keystate = data.keystate;
 		let is_alive = data.is_alive;
+		let config = data.config;
 		let mut channel = data.channel;
 
 		let returned = {
@@ -48,7 +50,7 @@ impl<'a> System<'a> for ReturnFlag {
 					.join()
 					.filter(|(_, _, _, carrier, ..)| carrier.0.is_none())
 					.filter(|(_, pos, team, ..)| {
-						(ctfconfig::FLAG_HOME_POS[&team] - **pos).length2().inner() > 0.01
+						(config.flag_home_pos(**team) - **pos).length2().inner() > 0.01
 					})
 					.map(|(ent, pos, team, ..)| (ent, *pos, *team))
 					.collect::<Vec<_>>()
@@ -74,7 +76,7 @@ impl<'a> System<'a> for ReturnFlag {
 						.filter(|(_, _, _, player_team, ..)| **player_team == *flag_team)
 						.filter(|(_, _, _, _, keystate, ..)| !keystate.stealthed)
 						.filter_map(|(player, player_pos, plane, ..)| {
-							let radius = ctfconfig::FLAG_RADIUS[&plane];
+							let radius = config.flag_radius[&plane];
 							let dist2 = (*player_pos - *flag_pos).length2();
 
 							if dist2 < radius * radius {
@@ -1,3 +1,4 @@
+mod bot_goals;
 mod drop;
 mod drop_on_despawn;
 mod drop_on_stealth;
@@ -6,6 +7,7 @@ mod pickupflag;
 mod pos_update;
 mod register;
 mod score_detailed;
+mod update_status;
 
 pub mod flag_event;
 pub mod on_flag;
@@ -17,6 +19,7 @@ pub mod timer;
 
 pub use self::register::register;
 
+pub use self::bot_goals::SetBotGoals;
 pub use self::drop::DropSystem;
 pub use self::drop_on_despawn::DropOnDespawn;
 pub use self::drop_on_stealth::DropOnStealth;
@@ -24,3 +27,4 @@ pub use self::flagspeed::FlagSpeedSystem;
 pub use self::pickupflag::PickupFlagSystem;
 pub use self::pos_update::PosUpdateSystem;
 pub use self::score_detailed::ScoreDetailed;
+pub use self::update_status::UpdateStatus;
@@ -1,7 +1,7 @@
 use specs::*;
 
 use component::*;
-use config::{BLUE_TEAM, RED_TEAM};
+use config::{CtfConfig, BLUE_TEAM, RED_TEAM};
 use server::*;
 
 use super::SendFlagMessage;
@@ -16,6 +16,7 @@ pub struct CheckWinData<'a> {
 	flag_channel: Read<'a, OnFlag>,
 	win_channel: Write<'a, OnGameWin>,
 	scores: Read<'a, GameScores>,
+	config: Read<'a, CtfConfig>,
 }
 
 impl<'a> System<'a> for CheckWin {
@@ -36,12 +37,14 @@ impl<'a> System<'a> for CheckWin {
 				_ => continue,
 			}
 
+			let caps_to_win = data.config.caps_to_win;
+
 			// Check to see if the game is over yet
-			if data.scores.redteam < 3 && data.scores.blueteam < 3 {
+			if data.scores.redteam < caps_to_win && data.scores.blueteam < caps_to_win {
 				continue;
 			}
 
-			let winning_team = if data.scores.redteam >= 3 {
+			let winning_team = if data.scores.redteam >= caps_to_win {
 				RED_TEAM
 			} else {
 				BLUE_TEAM
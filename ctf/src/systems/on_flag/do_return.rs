@@ -1,7 +1,7 @@
 use server::*;
 use specs::*;
 
-use config::{BLUE_TEAM, FLAG_HOME_POS, RED_TEAM};
+use config::{CtfConfig, BLUE_TEAM, RED_TEAM};
 
 use component::*;
 
@@ -16,6 +16,7 @@ pub struct DoReturn;
 pub struct DoReturnData<'a> {
 	pos: WriteStorage<'a, Position>,
 	flags: ReadExpect<'a, Flags>,
+	config: Read<'a, CtfConfig>,
 
 	scores: Read<'a, GameScores>,
 	conns: Read<'a, Connections>,
@@ -48,7 +49,7 @@ impl<'a> EventHandler<'a> for DoReturn {
 			team = BLUE_TEAM;
 		}
 
-		let pos = (*FLAG_HOME_POS)[&team];
+		let pos = data.config.flag_home_pos(team);
 		*flag_pos = pos;
 
 		data.carriers.get_mut(evt.flag).unwrap().0 = None;
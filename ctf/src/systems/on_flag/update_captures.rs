@@ -2,6 +2,7 @@ use specs::*;
 
 use component::*;
 
+use server::types::Account;
 use server::*;
 
 pub struct UpdateCaptures {
@@ -15,6 +16,7 @@ pub struct UpdateCapturesData<'a> {
 
 	pub entities: Entities<'a>,
 	pub captures: WriteStorage<'a, Captures>,
+	pub account: WriteStorage<'a, Account>,
 }
 
 impl UpdateCaptures {
@@ -46,6 +48,12 @@ impl<'a> System<'a> for UpdateCaptures {
 			}
 
 			data.captures.get_mut(player).unwrap().0 += 1;
+
+			// Keep the account up to date so that the
+			// captures are saved when the player leaves.
+			if let Some(account) = data.account.get_mut(player) {
+				account.stats.captures += 1;
+			}
 		}
 	}
 }
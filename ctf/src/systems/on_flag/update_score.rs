@@ -2,7 +2,7 @@ use server::*;
 use specs::*;
 
 use component::*;
-use config as ctfconfig;
+use config::CtfConfig;
 
 use server::component::counter::*;
 use server::protocol::server::ScoreUpdate;
@@ -17,6 +17,7 @@ pub struct UpdateScoreData<'a> {
 	pub channel: Read<'a, OnFlag>,
 	pub conns: Read<'a, Connections>,
 	pub players_game: Read<'a, PlayersGame>,
+	pub config: Read<'a, CtfConfig>,
 
 	pub scores: WriteStorage<'a, Score>,
 	pub earnings: WriteStorage<'a, Earnings>,
@@ -49,7 +50,7 @@ impl<'a> System<'a> for UpdateScore {
 
 			let player = evt.player.unwrap();
 			let players_game = data.players_game.0;
-			let score_increase = ctfconfig::FLAG_CAP_BOUNTY_BASE.0 * players_game.min(10);
+			let score_increase = data.config.flag_cap_bounty_base.0 * players_game.min(10);
 
 			let ref mut earnings = data.earnings.get_mut(player).unwrap().0;
 			let score = data.scores.get_mut(player).unwrap();
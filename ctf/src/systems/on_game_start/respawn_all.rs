@@ -3,6 +3,7 @@ use specs::*;
 use server::component::channel::*;
 use server::component::event::*;
 use server::component::flag::*;
+use server::consts::spectate::SPECTATOR_TEAM;
 use server::systems::handlers::game::on_join::AllJoinHandlers;
 use server::types::systemdata::IsAlive;
 use server::*;
@@ -26,6 +27,7 @@ pub struct RespawnAllData<'a> {
 
 	entities: Entities<'a>,
 	is_player: ReadStorage<'a, IsPlayer>,
+	team: ReadStorage<'a, Team>,
 	is_alive: IsAlive<'a>,
 }
 
@@ -42,8 +44,10 @@ impl<'a> System<'a> for RespawnAll {
 		use self::PlayerRespawnPrevStatus::*;
 
 		for _ in data.channel.read(self.reader.as_mut().unwrap()) {
-			let players = (&*data.entities, data.is_player.mask())
+			// Spectators without a team stay in spec
+			let players = (&*data.entities, &data.team, data.is_player.mask())
 				.join()
+				.filter(|(_, team, ..)| **team != SPECTATOR_TEAM)
 				.map(|(ent, ..)| (ent, data.is_alive.get(ent)))
 				.map(|(ent, is_alive)| PlayerRespawn {
 					player: ent,
@@ -1,6 +1,7 @@
 use specs::*;
 
 use server::component::flag::*;
+use server::consts::spectate::SPECTATOR_TEAM;
 use server::*;
 
 use component::*;
@@ -27,6 +28,7 @@ pub struct RespawnAllUnspecData<'a> {
 
 	entities: Entities<'a>,
 	is_player: ReadStorage<'a, IsPlayer>,
+	team: ReadStorage<'a, Team>,
 	is_spec: WriteStorage<'a, IsSpectating>,
 }
 
@@ -43,8 +45,10 @@ impl<'a> System<'a> for RespawnAllUnspec {
 		let mut is_spec = data.is_spec;
 
 		for _ in data.channel.read(self.reader.as_mut().unwrap()) {
-			(&*data.entities, data.is_player.mask())
+			(&*data.entities, &data.team, data.is_player.mask())
 				.join()
+				// Spectators without a team stay in spec
+				.filter(|(_, team, ..)| **team != SPECTATOR_TEAM)
 				.for_each(|(ent, ..)| {
 					// Remove the spectating key if present,
 					// otherwise leave it
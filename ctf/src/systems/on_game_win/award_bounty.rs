@@ -12,7 +12,7 @@ use server::*;
 use server::protocol::server::ScoreUpdate;
 
 use component::*;
-use config::GAME_WIN_BOUNTY_BASE;
+use config::CtfConfig;
 use systems::on_flag::CheckWin;
 
 /// Award bounty to all members of the winning team
@@ -25,6 +25,7 @@ pub struct AwardBounty {
 pub struct AwardBountyData<'a> {
 	channel: Read<'a, OnGameWin>,
 	players_game: Read<'a, PlayersGame>,
+	config: Read<'a, CtfConfig>,
 	timer_channel: Write<'a, OnTimerEvent>,
 	this_frame: Read<'a, ThisFrame>,
 	conns: Read<'a, Connections>,
@@ -53,7 +54,7 @@ impl<'a> System<'a> for AwardBounty {
 
 		for evt in data.channel.read(self.reader.as_mut().unwrap()) {
 			let team = evt.winning_team;
-			let bounty = data.players_game.0.min(10) * GAME_WIN_BOUNTY_BASE.0;
+			let bounty = data.players_game.0.min(10) * data.config.game_win_bounty_base.0;
 
 			(
 				&data.team,
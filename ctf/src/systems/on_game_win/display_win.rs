@@ -2,7 +2,7 @@ use server::*;
 use specs::*;
 
 use component::*;
-use config::*;
+use config::CtfConfig;
 use systems::on_flag::CheckWin;
 
 use server::component::counter::PlayersGame;
@@ -19,6 +19,7 @@ pub struct DisplayWinData<'a> {
 	channel: Read<'a, OnGameWin>,
 	conns: Read<'a, Connections>,
 	players_game: Read<'a, PlayersGame>,
+	config: Read<'a, CtfConfig>,
 }
 
 impl<'a> System<'a> for DisplayWin {
@@ -37,7 +38,7 @@ impl<'a> System<'a> for DisplayWin {
 			let text = format!(
 				"{{\"w\":{},\"b\":{},\"t\":{}}}",
 				evt.winning_team.0,
-				data.players_game.0.min(10) * GAME_WIN_BOUNTY_BASE.0,
+				data.players_game.0.min(10) * data.config.game_win_bounty_base.0,
 				13, // seconds
 			);
 
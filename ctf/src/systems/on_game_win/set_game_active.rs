@@ -5,16 +5,12 @@ use server::types::FutureDispatcher;
 use server::*;
 
 use component::*;
-use config::*;
+use config::CtfConfig;
 use consts::*;
 use systems::on_flag::CheckWin;
 
 use std::time::Duration;
 
-lazy_static! {
-	static ref TIMER_DURATION: Duration = *GAME_RESET_TIME + Duration::from_millis(100);
-}
-
 /// Change GameActive state to false.
 ///
 /// This is required to change game
@@ -30,6 +26,7 @@ pub struct SetGameActiveData<'a> {
 	channel: Read<'a, OnGameWin>,
 	game_active: Write<'a, GameActive>,
 	dispatcher: ReadExpect<'a, FutureDispatcher>,
+	config: Read<'a, CtfConfig>,
 }
 
 impl<'a> System<'a> for SetGameActive {
@@ -45,8 +42,10 @@ impl<'a> System<'a> for SetGameActive {
 		for _ in data.channel.read(self.reader.as_mut().unwrap()) {
 			data.game_active.0 = false;
 
+			let duration = data.config.game_reset_time + Duration::from_millis(100);
+
 			data.dispatcher
-				.run_delayed(*TIMER_DURATION, |inst| TimerEvent {
+				.run_delayed(duration, |inst| TimerEvent {
 					ty: *SET_GAME_ACTIVE,
 					instant: inst,
 					data: None,
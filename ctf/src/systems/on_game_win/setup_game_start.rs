@@ -5,8 +5,8 @@ use server::types::FutureDispatcher;
 use server::*;
 
 use component::*;
+use config::CtfConfig;
 use consts::GAME_START_TIMER;
-use std::time::Duration;
 use systems::on_flag::CheckWin;
 
 #[derive(Default)]
@@ -18,6 +18,7 @@ pub struct SetupGameStart {
 pub struct SetupGameStartData<'a> {
 	channel: Read<'a, OnGameWin>,
 	future: ReadExpect<'a, FutureDispatcher>,
+	config: Read<'a, CtfConfig>,
 }
 
 impl<'a> System<'a> for SetupGameStart {
@@ -32,7 +33,7 @@ impl<'a> System<'a> for SetupGameStart {
 	fn run(&mut self, data: Self::SystemData) {
 		for _ in data.channel.read(self.reader.as_mut().unwrap()) {
 			data.future
-				.run_delayed(Duration::from_secs(60), move |inst| TimerEvent {
+				.run_delayed(data.config.game_reset_time, move |inst| TimerEvent {
 					ty: *GAME_START_TIMER,
 					instant: inst,
 					data: None,
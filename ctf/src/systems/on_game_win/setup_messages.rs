@@ -8,7 +8,7 @@ use server::types::FutureDispatcher;
 use server::*;
 
 use component::*;
-use config::*;
+use config::CtfConfig;
 use std::time::Duration;
 use systems::on_flag::CheckWin;
 
@@ -43,6 +43,7 @@ pub struct SetupMessages {
 pub struct SetupMessagesData<'a> {
 	channel: Read<'a, OnGameWin>,
 	future: ReadExpect<'a, FutureDispatcher>,
+	config: Read<'a, CtfConfig>,
 }
 
 impl<'a> System<'a> for SetupMessages {
@@ -55,10 +56,18 @@ impl<'a> System<'a> for SetupMessages {
 	}
 
 	fn run(&mut self, data: Self::SystemData) {
+		let reset_time = data.config.game_reset_time;
+
 		for _ in data.channel.read(self.reader.as_mut().unwrap()) {
 			for (duration, delay, msg) in MESSAGES.iter() {
+				let delay = Duration::from_secs(*delay);
+				// Skip messages for times longer than the reset time
+				if delay > reset_time {
+					continue;
+				}
+
 				data.future.run_delayed(
-					*GAME_RESET_TIME - Duration::from_secs(*delay),
+					reset_time - delay,
 					move |inst| {
 						Some(TimerEvent {
 							ty: *DELAYED_MESSAGE,
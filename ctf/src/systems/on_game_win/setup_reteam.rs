@@ -5,6 +5,7 @@ use server::types::FutureDispatcher;
 use server::*;
 
 use component::*;
+use config::CtfConfig;
 use consts::RETEAM_TIMER;
 use std::time::Duration;
 use systems::on_flag::CheckWin;
@@ -18,6 +19,7 @@ pub struct SetupReteam {
 pub struct SetupReteamData<'a> {
 	channel: Read<'a, OnGameWin>,
 	future: ReadExpect<'a, FutureDispatcher>,
+	config: Read<'a, CtfConfig>,
 }
 
 impl<'a> System<'a> for SetupReteam {
@@ -30,9 +32,13 @@ impl<'a> System<'a> for SetupReteam {
 	}
 
 	fn run(&mut self, data: Self::SystemData) {
+		// Teams are shuffled 5 seconds before the game starts
+		let reset_time = data.config.game_reset_time;
+		let delay = reset_time - Duration::from_secs(5).min(reset_time);
+
 		for _ in data.channel.read(self.reader.as_mut().unwrap()) {
 			data.future
-				.run_delayed(Duration::from_secs(55), move |inst| TimerEvent {
+				.run_delayed(delay, move |inst| TimerEvent {
 					ty: *RETEAM_TIMER,
 					instant: inst,
 					data: None,
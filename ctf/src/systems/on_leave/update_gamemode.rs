@@ -2,6 +2,7 @@ use specs::*;
 
 use server::component::channel::*;
 use server::component::event::PlayerLeave;
+use server::consts::spectate::SPECTATOR_TEAM;
 use server::systems::handlers::packet::OnCloseHandler;
 use server::*;
 
@@ -38,6 +39,8 @@ impl<'a> System<'a> for UpdateGameModeOnPlayerLeave {
 				data.gamemode.redteam -= std::cmp::min(data.gamemode.redteam, 1);
 			} else if *team == BLUE_TEAM {
 				data.gamemode.blueteam -= std::cmp::min(data.gamemode.blueteam, 1);
+			} else if *team == SPECTATOR_TEAM {
+				// Spectators never took up a team slot
 			} else {
 				unimplemented!();
 			}
@@ -6,7 +6,7 @@ use server::types::systemdata::*;
 use server::*;
 
 use component::*;
-use config as ctfconfig;
+use config::{self as ctfconfig, CtfConfig};
 use systems::on_join::SendFlagPosition;
 
 use std::cmp::Ordering;
@@ -16,6 +16,7 @@ pub struct PickupFlagSystem;
 #[derive(SystemData)]
 pub struct PickupFlagSystemData<'a> {
 	pub config: Read<'a, Config>,
+	pub ctf_config: Read<'a, CtfConfig>,
 	pub entities: Entities<'a>,
 	pub channel: Write<'a, OnFlag>,
 	pub thisframe: Read<'a, ThisFrame>,
@@ -83,7 +84,7 @@ impl<'a> System<'a> for PickupFlagSystem {
 				})
 				.filter(|(_, _, _, _, _, _, ref keystate)| keystate.stealthed != true)
 				.filter_map(|(p_ent, p_pos, _, _, p_plane, ..)| {
-					let rad = ctfconfig::FLAG_RADIUS[&p_plane];
+					let rad = data.ctf_config.flag_radius[&p_plane];
 					let dst = (*p_pos - f_pos).length2();
 
 					// Filter out distances that are too large
@@ -7,7 +7,7 @@ use specs::*;
 use std::time::Instant;
 
 use super::*;
-use config;
+use config::{self, CtfConfig};
 
 pub fn register<'a, 'b>(world: &mut World, disp: Builder<'a, 'b>) -> Builder<'a, 'b> {
 	world.register::<Team>();
@@ -16,6 +16,14 @@ pub fn register<'a, 'b>(world: &mut World, disp: Builder<'a, 'b>) -> Builder<'a,
 	world.register::<FlagCarrier>();
 	world.register::<LastDrop>();
 
+	let (blue_pos, red_pos) = {
+		let config = world.read_resource::<CtfConfig>();
+		(
+			config.flag_home_pos(config::BLUE_TEAM),
+			config.flag_home_pos(config::RED_TEAM),
+		)
+	};
+
 	let lastdrop = LastDrop {
 		player: None,
 		time: Instant::now(),
@@ -24,7 +32,7 @@ pub fn register<'a, 'b>(world: &mut World, disp: Builder<'a, 'b>) -> Builder<'a,
 	let blue = world
 		.create_entity()
 		.with(config::BLUE_TEAM)
-		.with(config::FLAG_HOME_POS[&config::BLUE_TEAM])
+		.with(blue_pos)
 		.with(IsFlag {})
 		.with(FlagCarrier(None))
 		.with(lastdrop)
@@ -32,7 +40,7 @@ pub fn register<'a, 'b>(world: &mut World, disp: Builder<'a, 'b>) -> Builder<'a,
 	let red = world
 		.create_entity()
 		.with(config::RED_TEAM)
-		.with(config::FLAG_HOME_POS[&config::RED_TEAM])
+		.with(red_pos)
 		.with(IsFlag {})
 		.with(FlagCarrier(None))
 		.with(lastdrop)
@@ -43,6 +51,7 @@ pub fn register<'a, 'b>(world: &mut World, disp: Builder<'a, 'b>) -> Builder<'a,
 	disp.with_handler::<DropOnDespawn>()
 		.with_handler::<DropOnStealth>()
 		.with::<ScoreDetailed>()
+		.with::<UpdateStatus>()
 		// On Leave Events
 		.with::<on_leave::UpdateGameMode>()
 		.with::<on_leave::Drop>()
@@ -54,6 +63,7 @@ pub fn register<'a, 'b>(world: &mut World, disp: Builder<'a, 'b>) -> Builder<'a,
 		.with::<DropSystem>()
 		.with::<PosUpdateSystem>()
 		.with::<FlagSpeedSystem>()
+		.with::<SetBotGoals>()
 		// On Flag Events
 		.with::<on_flag::SendFlagMessage>()
 		.with::<on_flag::PickupMessage>()
@@ -3,7 +3,8 @@ use specs::*;
 use server::component::channel::*;
 use server::component::counter::*;
 use server::component::flag::*;
-use server::types::GameModeWriter;
+use server::consts::spectate::SPECTATOR_TEAM;
+use server::types::{AssociatedConnection, GameModeWriter};
 use server::*;
 
 use server::protocol::server::{PlayerReteam, PlayerReteamPlayer};
@@ -14,6 +15,9 @@ use consts::*;
 use gamemode::CTFGameMode;
 use shuffle::*;
 
+use fnv::FnvHashMap;
+use std::net::IpAddr;
+
 #[derive(Default)]
 pub struct Shuffle {
 	reader: Option<OnTimerEventReader>,
@@ -26,6 +30,7 @@ pub struct ShuffleData<'a> {
 	conns: Read<'a, Connections>,
 	entities: Entities<'a>,
 	gamemode: GameModeWriter<'a, CTFGameMode>,
+	config: Read<'a, CtfConfig>,
 
 	is_player: ReadStorage<'a, IsPlayer>,
 	captures: ReadStorage<'a, Captures>,
@@ -33,6 +38,19 @@ pub struct ShuffleData<'a> {
 	team: WriteStorage<'a, Team>,
 	kills: ReadStorage<'a, TotalKills>,
 	deaths: ReadStorage<'a, TotalDeaths>,
+	associated: ReadStorage<'a, AssociatedConnection>,
+}
+
+impl Shuffle {
+	/// Players connecting from the same address are
+	/// assumed to be playing together, so they get
+	/// put in the same party. See
+	/// [`CtfConfig::parties_by_addr`].
+	fn player_addr<'a>(data: &ShuffleData<'a>, player: Entity) -> Option<IpAddr> {
+		let conn = data.associated.get(player)?;
+
+		data.conns.conns.get(&conn.0).map(|c| c.info.addr)
+	}
 }
 
 impl<'a> System<'a> for Shuffle {
@@ -50,6 +68,27 @@ impl<'a> System<'a> for Shuffle {
 				continue;
 			}
 
+			let mut addrs: FnvHashMap<IpAddr, Vec<Entity>> = FnvHashMap::default();
+			if data.config.parties_by_addr {
+				for (ent, ..) in (&*data.entities, data.is_player.mask()).join() {
+					if let Some(addr) = Self::player_addr(&data, ent) {
+						addrs.entry(addr).or_insert_with(Vec::new).push(ent);
+					}
+				}
+			}
+
+			let parties = addrs
+				.into_iter()
+				.map(|(_, players)| players)
+				.filter(|players| players.len() > 1)
+				.enumerate()
+				.flat_map(|(party, players)| {
+					players
+						.into_iter()
+						.map(move |player| (player, party as u32))
+				})
+				.collect::<FnvHashMap<Entity, u32>>();
+
 			let player_info = (
 				&*data.entities,
 				&data.team,
@@ -60,6 +99,8 @@ impl<'a> System<'a> for Shuffle {
 				data.is_player.mask(),
 			)
 				.join()
+				// Spectators aren't on a team to be shuffled
+				.filter(|(_, team, ..)| **team != SPECTATOR_TEAM)
 				.map(
 					|(ent, team, score, captures, kills, deaths, ..)| PlayerShuffleInfo {
 						player: ent,
@@ -68,6 +109,7 @@ impl<'a> System<'a> for Shuffle {
 						captures: captures.0,
 						kills: kills.0,
 						deaths: deaths.0,
+						party: parties.get(&ent).cloned(),
 					},
 				)
 				.collect::<Vec<_>>();
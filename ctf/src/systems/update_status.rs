@@ -0,0 +1,87 @@
+use server::component::collection::GameModeStatus;
+use server::*;
+use specs::*;
+
+use component::*;
+use config::{self, CtfConfig};
+
+/// Flag is sitting at its base
+const FLAG_HOME: f64 = 0.0;
+/// Flag is being carried by a player
+const FLAG_CARRIED: f64 = 1.0;
+/// Flag was dropped somewhere on the map
+const FLAG_DROPPED: f64 = 2.0;
+
+/// Publish the score and the state of both flags
+/// on the server status page.
+#[derive(Default)]
+pub struct UpdateStatus;
+
+#[derive(SystemData)]
+pub struct UpdateStatusData<'a> {
+	status: Write<'a, GameModeStatus>,
+	scores: Read<'a, GameScores>,
+	active: Read<'a, GameActive>,
+	flags: ReadExpect<'a, Flags>,
+	config: Read<'a, CtfConfig>,
+
+	pos: ReadStorage<'a, Position>,
+	carrier: ReadStorage<'a, FlagCarrier>,
+}
+
+impl<'a> System<'a> for UpdateStatus {
+	type SystemData = UpdateStatusData<'a>;
+
+	fn run(&mut self, mut data: Self::SystemData) {
+		let values = &mut data.status.0;
+
+		values.insert("ctf_blue_score", data.scores.blueteam as f64);
+		values.insert("ctf_red_score", data.scores.redteam as f64);
+		values.insert("ctf_game_active", if data.active.0 { 1.0 } else { 0.0 });
+
+		for &(state_name, carrier_name, flag, team) in [
+			(
+				"ctf_blue_flag_state",
+				"ctf_blue_flag_carrier",
+				data.flags.blue,
+				config::BLUE_TEAM,
+			),
+			(
+				"ctf_red_flag_state",
+				"ctf_red_flag_carrier",
+				data.flags.red,
+				config::RED_TEAM,
+			),
+		]
+			.iter()
+		{
+			let carrier = data.carrier.get(flag).and_then(|c| c.0);
+			let at_home = data
+				.pos
+				.get(flag)
+				.map(|&pos| (data.config.flag_home_pos(team) - pos).length2().inner() < 0.01)
+				.unwrap_or(false);
+
+			let state = match carrier {
+				Some(_) => FLAG_CARRIED,
+				None if at_home => FLAG_HOME,
+				None => FLAG_DROPPED,
+			};
+
+			values.insert(state_name, state);
+			values.insert(carrier_name, carrier.map(|c| c.id() as f64).unwrap_or(0.0));
+		}
+	}
+}
+
+impl SystemInfo for UpdateStatus {
+	type Dependencies = ();
+
+	fn name() -> &'static str {
+		concat!(module_path!(), "::", line!())
+	}
+
+	fn new() -> Self {
+		Self::default()
+	}
+}
@@ -5,8 +5,6 @@ extern crate rand;
 extern crate shred;
 extern crate specs;
 #[macro_use]
-extern crate lazy_static;
-#[macro_use]
 extern crate specs_derive;
 #[macro_use]
 extern crate shred_derive;
@@ -19,6 +17,7 @@ use std::env;
 
 use gamemode::EmptyGameMode;
 
+use airmash_server::types::{BanList, FileAccountStore, FileSessionValidator};
 use airmash_server::*;
 
 fn main() {
@@ -26,11 +25,53 @@ fn main() {
     env::set_var("RUST_LOG", "info");
     env_logger::init();
 
+    let map = match env::var("MAP_FILE") {
+        Ok(path) => match Map::load(&path) {
+            Ok(map) => map,
+            Err(e) => panic!("Failed to load map from {}: {}", path, e),
+        },
+        Err(_) => Map::default(),
+    };
+    // Every player is on their own team so they all
+    // need to be able to use the same spawn zones.
+    if let Err(e) = map.check_spawns(&[]) {
+        panic!("Map {:?} cannot be used for FFA: {}", map.name, e);
+    }
+
     let mut server = AirmashServer::new("0.0.0.0:3501")
         .with_engine()
-        .with_gamemode(EmptyGameMode);
+        .with_map(map.clone())
+        .with_gamemode(EmptyGameMode::new(map));
 
     server.builder = systems::register(server.builder);
 
+    if let Ok(path) = env::var("CONFIG_FILE") {
+        server = server.with_config_file(path);
+    }
+
+    if let Ok(path) = env::var("ACCOUNTS_FILE") {
+        let store = match FileAccountStore::open(&path) {
+            Ok(store) => store,
+            Err(e) => panic!("Failed to open accounts file {}: {}", path, e),
+        };
+        server = server.with_account_store(store);
+    }
+
+    if let Ok(path) = env::var("SESSIONS_FILE") {
+        server = server.with_session_validator(FileSessionValidator::new(path));
+    }
+
+    if let Ok(path) = env::var("BANS_FILE") {
+        let bans = match BanList::open(&path) {
+            Ok(bans) => bans,
+            Err(e) => panic!("Failed to open bans file {}: {}", path, e),
+        };
+        server = server.with_ban_list(bans);
+    }
+
+    if let Ok(path) = env::var("REPLAY_FILE") {
+        server = server.with_replay_file(path);
+    }
+
     server.run();
 }
@@ -0,0 +1,156 @@
+use protocol::client::{Chat, Key, Login, Pong};
+use protocol::{ClientPacket, KeyCode, Protocol, ProtocolSerializationExt, ServerPacket};
+use protocol_v5::ProtocolV5;
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+use ws::util::Token;
+use ws::{CloseCode, Handler, Handshake, Message, Result as WsResult, Sender};
+
+/// Time between two steps of the client script.
+const TICK_MS: u64 = 100;
+const TICK: Token = Token(1);
+
+/// Traffic counters shared between all clients.
+#[derive(Default)]
+pub struct ClientStats {
+	pub connected: AtomicUsize,
+	pub bytes_sent: AtomicUsize,
+	pub bytes_recv: AtomicUsize,
+}
+
+/// A client that logs in and then flies, fires
+/// and chats on a fixed script.
+///
+/// Clients are offset from each other by their id
+/// so that they don't all press the same keys on
+/// the same frame.
+pub struct Client {
+	out: Sender,
+	id: usize,
+	seq: u32,
+	tick: u64,
+	logged_in: bool,
+	stats: Arc<ClientStats>,
+}
+
+impl Client {
+	pub fn new(out: Sender, id: usize, stats: Arc<ClientStats>) -> Self {
+		Self {
+			out,
+			id,
+			seq: 0,
+			tick: 0,
+			logged_in: false,
+			stats,
+		}
+	}
+
+	fn send<P: Into<ClientPacket>>(&self, packet: P) -> WsResult<()> {
+		let data = ProtocolV5 {}
+			.serialize_client(&packet.into())
+			.unwrap()
+			.next()
+			.unwrap();
+
+		self.stats
+			.bytes_sent
+			.fetch_add(data.len(), Ordering::Relaxed);
+		self.out.send(Message::Binary(data))
+	}
+
+	fn key(&mut self, key: KeyCode, state: bool) -> WsResult<()> {
+		self.seq += 1;
+
+		let seq = self.seq;
+		self.send(Key { seq, key, state })
+	}
+
+	fn step(&mut self) -> WsResult<()> {
+		let t = self.tick + self.id as u64 * 7;
+		self.tick += 1;
+
+		if self.tick == 1 {
+			self.key(KeyCode::Up, true)?;
+		}
+
+		// Weave left and right, switching every 2 seconds
+		if t % 20 == 0 {
+			let left = (t / 20) % 2 == 0;
+			self.key(KeyCode::Left, left)?;
+			self.key(KeyCode::Right, !left)?;
+		}
+
+		// Fire for 1 second out of every 5
+		match t % 50 {
+			0 => self.key(KeyCode::Fire, true)?,
+			10 => self.key(KeyCode::Fire, false)?,
+			_ => (),
+		}
+
+		if t % 200 == 0 {
+			self.send(Chat {
+				text: format!("load test client {}", self.id),
+			})?;
+		}
+
+		Ok(())
+	}
+}
+
+impl Handler for Client {
+	fn on_open(&mut self, _: Handshake) -> WsResult<()> {
+		self.stats.connected.fetch_add(1, Ordering::Relaxed);
+
+		self.send(Login {
+			protocol: 5,
+			name: format!("loadtest-{}", self.id),
+			session: "none".to_owned(),
+			horizon_x: 3000,
+			horizon_y: 3000,
+			flag: "UN".to_owned(),
+		})
+	}
+
+	fn on_message(&mut self, msg: Message) -> WsResult<()> {
+		let data = msg.into_data();
+		self.stats
+			.bytes_recv
+			.fetch_add(data.len(), Ordering::Relaxed);
+
+		let protocol = ProtocolV5 {};
+		let packet: ServerPacket = match protocol.deserialize(&data) {
+			Ok(packet) => packet,
+			Err(_) => return Ok(()),
+		};
+
+		match packet {
+			ServerPacket::Login(_) => {
+				if !self.logged_in {
+					self.logged_in = true;
+					self.out.timeout(TICK_MS, TICK)?;
+				}
+			}
+			ServerPacket::Ping(ping) => self.send(Pong { num: ping.num })?,
+			_ => (),
+		}
+
+		Ok(())
+	}
+
+	fn on_timeout(&mut self, token: Token) -> WsResult<()> {
+		if token != TICK {
+			return Ok(());
+		}
+
+		self.step()?;
+		self.out.timeout(TICK_MS, TICK)
+	}
+
+	fn on_close(&mut self, code: CloseCode, reason: &str) {
+		self.stats.connected.fetch_sub(1, Ordering::Relaxed);
+
+		warn!("Client {} disconnected: {:?} {}", self.id, code, reason);
+	}
+}
@@ -0,0 +1,94 @@
+use specs::*;
+
+use airmash_server::component::channel::*;
+use airmash_server::systems::collision::{
+	MissileTerrainCollisionSystem, PlaneCollisionSystem, PlayerMissileCollisionSystem,
+};
+use airmash_server::SystemInfo;
+
+use std::any::Any;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+/// Collision counters shared with the report thread.
+#[derive(Default)]
+pub struct CollisionCounts {
+	pub player_missile: AtomicUsize,
+	pub player_terrain: AtomicUsize,
+	pub missile_terrain: AtomicUsize,
+}
+
+/// Counts the collision events generated by the
+/// server each frame.
+pub struct CountCollisions {
+	counts: Arc<CollisionCounts>,
+	player_missile: Option<OnPlayerMissileCollisionReader>,
+	player_terrain: Option<OnPlayerTerrainCollisionReader>,
+	missile_terrain: Option<OnMissileTerrainCollisionReader>,
+}
+
+impl<'a> System<'a> for CountCollisions {
+	type SystemData = (
+		Read<'a, OnPlayerMissileCollision>,
+		Read<'a, OnPlayerTerrainCollision>,
+		Read<'a, OnMissileTerrainCollision>,
+	);
+
+	fn setup(&mut self, res: &mut Resources) {
+		Self::SystemData::setup(res);
+
+		self.player_missile = Some(res.fetch_mut::<OnPlayerMissileCollision>().register_reader());
+		self.player_terrain = Some(res.fetch_mut::<OnPlayerTerrainCollision>().register_reader());
+		self.missile_terrain = Some(res.fetch_mut::<OnMissileTerrainCollision>().register_reader());
+	}
+
+	fn run(&mut self, (player_missile, player_terrain, missile_terrain): Self::SystemData) {
+		let count = player_missile
+			.read(self.player_missile.as_mut().unwrap())
+			.count();
+		self.counts
+			.player_missile
+			.fetch_add(count, Ordering::Relaxed);
+
+		let count = player_terrain
+			.read(self.player_terrain.as_mut().unwrap())
+			.count();
+		self.counts
+			.player_terrain
+			.fetch_add(count, Ordering::Relaxed);
+
+		let count = missile_terrain
+			.read(self.missile_terrain.as_mut().unwrap())
+			.count();
+		self.counts
+			.missile_terrain
+			.fetch_add(count, Ordering::Relaxed);
+	}
+}
+
+impl SystemInfo for CountCollisions {
+	type Dependencies = (
+		PlaneCollisionSystem,
+		PlayerMissileCollisionSystem,
+		MissileTerrainCollisionSystem,
+	);
+
+	fn name() -> &'static str {
+		concat!(module_path!(), "::", line!())
+	}
+
+	fn new() -> Self {
+		Self::new_args(Box::new(Arc::new(CollisionCounts::default())))
+	}
+
+	fn new_args(mut a: Box<Any>) -> Self {
+		let counts = a.downcast_mut::<Arc<CollisionCounts>>().unwrap().clone();
+
+		Self {
+			counts,
+			player_missile: None,
+			player_terrain: None,
+			missile_terrain: None,
+		}
+	}
+}
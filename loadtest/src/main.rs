@@ -0,0 +1,218 @@
+//! Measure how many players the server can handle.
+//!
+//! Usage: `airmash-loadtest [clients] [seconds] [addr]`
+//!
+//! Starts an FFA server and connects a number of
+//! synthetic clients to it over websockets. The
+//! clients log in, fly, fire and chat on a fixed
+//! script. Every few seconds the frame time
+//! percentiles, the traffic per client and the
+//! number of collisions are printed, followed by
+//! a summary of the whole run at the end. A frame has
+//! to finish within 16.67 ms for the server to
+//! keep up with its 60 Hz game loop.
+
+#[macro_use]
+extern crate log;
+
+extern crate airmash_protocol as protocol;
+extern crate airmash_protocol_v5 as protocol_v5;
+extern crate airmash_server;
+extern crate env_logger;
+extern crate specs;
+extern crate ws;
+
+mod client;
+mod collisions;
+
+use std::env;
+use std::process;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use specs::Entity;
+
+use airmash_server::protocol::GameType;
+use airmash_server::status::{frame_time_stats, total_frame_time_stats, FrameTimeStats};
+use airmash_server::*;
+
+use client::{Client, ClientStats};
+use collisions::{CollisionCounts, CountCollisions};
+
+/// Time between two reports.
+const REPORT_INTERVAL: u64 = 5;
+/// Time between two clients connecting, so that
+/// logins don't all land on the same frame.
+const CONNECT_DELAY: u64 = 50;
+
+struct LoadTestGameMode {
+	map: Map,
+}
+
+impl GameMode for LoadTestGameMode {
+	fn assign_team(&mut self, player: Entity) -> Team {
+		Team(player.id() as u16)
+	}
+	fn spawn_pos(&mut self, _: Entity, team: Team) -> Position {
+		self.map.random_spawn(team).unwrap_or_default()
+	}
+	fn gametype(&self) -> GameType {
+		GameType::FFA
+	}
+	fn room(&self) -> String {
+		"loadtest".to_owned()
+	}
+}
+
+#[derive(Copy, Clone, Default)]
+struct Totals {
+	sent: usize,
+	recv: usize,
+	player_missile: usize,
+	player_terrain: usize,
+	missile_terrain: usize,
+}
+
+impl Totals {
+	fn fetch(clients: &ClientStats, counts: &CollisionCounts) -> Self {
+		Self {
+			sent: clients.bytes_sent.load(Ordering::Relaxed),
+			recv: clients.bytes_recv.load(Ordering::Relaxed),
+			player_missile: counts.player_missile.load(Ordering::Relaxed),
+			player_terrain: counts.player_terrain.load(Ordering::Relaxed),
+			missile_terrain: counts.missile_terrain.load(Ordering::Relaxed),
+		}
+	}
+}
+
+fn secs(dur: Duration) -> f64 {
+	dur.as_secs() as f64 + dur.subsec_nanos() as f64 * 1e-9
+}
+
+fn report(
+	title: &str,
+	stats: FrameTimeStats,
+	clients: &ClientStats,
+	start: Totals,
+	end: Totals,
+	elapsed: Duration,
+) {
+	let connected = clients.connected.load(Ordering::Relaxed);
+	let per_client = secs(elapsed) * connected.max(1) as f64;
+
+	println!("{}", title);
+	println!(
+		"  frame time (ms): p50 {:.2} p90 {:.2} p99 {:.2} max {:.2}",
+		stats.p50, stats.p90, stats.p99, stats.max
+	);
+	println!("  connected clients: {}", connected);
+	println!(
+		"  bytes per client per second: {:.0} received, {:.0} sent",
+		(end.recv - start.recv) as f64 / per_client,
+		(end.sent - start.sent) as f64 / per_client
+	);
+	println!(
+		"  collisions: {} player-missile, {} player-terrain, {} missile-terrain",
+		end.player_missile - start.player_missile,
+		end.player_terrain - start.player_terrain,
+		end.missile_terrain - start.missile_terrain
+	);
+}
+
+fn main() {
+	env::set_var("RUST_LOG", "warn");
+	env_logger::init();
+
+	let mut args = env::args().skip(1);
+
+	let usage = "Usage: airmash-loadtest [clients] [seconds] [addr]";
+	let count = match args.next().map(|x| x.parse::<usize>()) {
+		Some(Ok(count)) => count,
+		Some(Err(_)) => {
+			eprintln!("{}", usage);
+			process::exit(1);
+		}
+		None => 50,
+	};
+	let duration = match args.next().map(|x| x.parse::<u64>()) {
+		Some(Ok(secs)) => Duration::from_secs(secs),
+		Some(Err(_)) => {
+			eprintln!("{}", usage);
+			process::exit(1);
+		}
+		None => Duration::from_secs(60),
+	};
+	let addr = args.next().unwrap_or_else(|| "127.0.0.1:3501".to_owned());
+
+	let map = Map::default();
+	let counts = Arc::new(CollisionCounts::default());
+	let stats = Arc::new(ClientStats::default());
+
+	let mut server = AirmashServer::new(addr.clone())
+		.with_engine()
+		.with_map(map.clone())
+		.with_gamemode(LoadTestGameMode { map });
+	server.builder = server
+		.builder
+		.with_args::<CountCollisions, _>(counts.clone());
+
+	for id in 0..count {
+		let url = format!("ws://{}", addr);
+		let stats = stats.clone();
+
+		thread::spawn(move || {
+			thread::sleep(Duration::from_millis(500 + CONNECT_DELAY * id as u64));
+
+			if let Err(e) = ws::connect(url, |out| Client::new(out, id, stats.clone())) {
+				error!("Client {} failed: {}", id, e);
+			}
+		});
+	}
+
+	thread::spawn(move || {
+		let begin = Instant::now();
+		let first = Totals::fetch(&stats, &counts);
+		let mut last = first;
+		let mut last_time = begin;
+
+		println!("Running {} clients against {} for {}s", count, addr, duration.as_secs());
+
+		while begin.elapsed() < duration {
+			thread::sleep(Duration::from_secs(REPORT_INTERVAL));
+
+			let now = Instant::now();
+			let totals = Totals::fetch(&stats, &counts);
+			let title = format!("[{}s]", (now - begin).as_secs());
+
+			report(
+				&title,
+				frame_time_stats(),
+				&stats,
+				last,
+				totals,
+				now - last_time,
+			);
+
+			last = totals;
+			last_time = now;
+		}
+
+		let totals = Totals::fetch(&stats, &counts);
+		// The summary covers every frame of the run, not
+		// just the last few seconds.
+		report(
+			"Summary",
+			total_frame_time_stats(),
+			&stats,
+			first,
+			totals,
+			begin.elapsed(),
+		);
+
+		process::exit(0);
+	});
+
+	server.run();
+}
@@ -0,0 +1,164 @@
+use protocol::client::Command;
+use protocol::server::{CommandReply, GameSpectate, Login, LoginPlayer, PlayerKill};
+use protocol::{
+	ClientPacket, CommandReplyType, FlagCode, Level, PlaneType, Player, PlayerStatus, Position,
+	Protocol, ProtocolSerializationExt, Rotation, ServerPacket, Upgrades,
+};
+use protocol_v5::ProtocolV5;
+
+use std::path::PathBuf;
+
+use ws::{CloseCode, Handler, Message, Result as WsResult, Sender};
+
+use airmash_server::consts::spectate::SPECTATOR_TEAM;
+use airmash_server::replay::ReplayReader;
+
+use playback::{self, PlaybackControl};
+
+/// Id given to the spectating client. Real players
+/// never get this id since the server refuses to
+/// create entities with ids above `0xFFFF`.
+const SPECTATOR_ID: u16 = 0xFFFF;
+
+pub struct ReplayHandler {
+	out: Sender,
+	path: PathBuf,
+	control: PlaybackControl,
+	started: bool,
+}
+
+impl ReplayHandler {
+	pub fn new(out: Sender, path: PathBuf, speed: f32) -> Self {
+		Self {
+			out,
+			path,
+			control: PlaybackControl::new(speed),
+			started: false,
+		}
+	}
+
+	fn send<P: Into<ServerPacket>>(&self, packet: P) -> WsResult<()> {
+		let data = ProtocolV5 {}
+			.serialize_server(&packet.into())
+			.unwrap()
+			.next()
+			.unwrap();
+
+		self.out.send(Message::Binary(data))
+	}
+
+	fn reply(&self, text: String) -> WsResult<()> {
+		self.send(CommandReply {
+			ty: CommandReplyType::ShowInConsole,
+			text,
+		})
+	}
+
+	fn on_login(&mut self) -> WsResult<()> {
+		if self.started {
+			return Ok(());
+		}
+		self.started = true;
+
+		let reader = match ReplayReader::open(&self.path) {
+			Ok(reader) => reader,
+			Err(e) => {
+				error!("Unable to open replay {}: {}", self.path.display(), e);
+				return self.out.close(CloseCode::Error);
+			}
+		};
+		let header = reader.header().clone();
+
+		// The client needs a plane of its own, it gets
+		// despawned right away with the same packet that
+		// the server uses when a player goes into spec.
+		let id = Player(SPECTATOR_ID);
+
+		self.send(Login {
+			success: true,
+			id,
+			team: SPECTATOR_TEAM,
+			clock: 0,
+			token: "none".to_owned(),
+			ty: header.gametype,
+			room: header.room,
+			players: vec![LoginPlayer {
+				id,
+				status: PlayerStatus::Dead,
+				level: Level(0),
+				name: "replay".to_owned(),
+				ty: PlaneType::Predator,
+				team: SPECTATOR_TEAM,
+				pos: Position::default(),
+				rot: Rotation::new(0.0),
+				flag: FlagCode::UnitedNations,
+				upgrades: Upgrades {
+					speed: 0,
+					shield: false,
+					inferno: false,
+				},
+			}],
+		})?;
+		self.send(PlayerKill {
+			id,
+			killer: None,
+			pos: Position::default(),
+		})?;
+
+		info!("Starting replay for {:?}", self.out.connection_id());
+
+		playback::spawn(
+			self.out.clone(),
+			self.path.clone(),
+			reader,
+			self.control.clone(),
+		);
+
+		Ok(())
+	}
+
+	fn on_command(&mut self, cmd: Command) -> WsResult<()> {
+		match &*cmd.com {
+			"speed" => match cmd.data.trim().parse::<f32>() {
+				Ok(speed) if speed > 0.0 => {
+					self.control.set_speed(speed);
+					self.reply(format!("Playback speed set to {}x", speed))
+				}
+				_ => self.reply(format!(
+					"Invalid speed {:?}, current speed is {}x",
+					cmd.data,
+					self.control.speed()
+				)),
+			},
+			"spectate" => match cmd.data.trim().parse::<u16>() {
+				Ok(id) => self.send(GameSpectate { id: Player(id) }),
+				Err(_) => self.reply("Spectate requires a player id".to_owned()),
+			},
+			_ => Ok(()),
+		}
+	}
+}
+
+impl Handler for ReplayHandler {
+	fn on_message(&mut self, msg: Message) -> WsResult<()> {
+		let protocol = ProtocolV5 {};
+		let packet: ClientPacket = match protocol.deserialize(&msg.into_data()) {
+			Ok(packet) => packet,
+			Err(_) => return Ok(()),
+		};
+
+		match packet {
+			ClientPacket::Login(_) => self.on_login(),
+			ClientPacket::Command(cmd) => self.on_command(cmd),
+			_ => Ok(()),
+		}
+	}
+
+	fn on_close(&mut self, _: CloseCode, _: &str) {
+		self.control.stop();
+	}
+
+	fn on_shutdown(&mut self) {
+		self.control.stop();
+	}
+}
@@ -0,0 +1,56 @@
+//! Serve a recorded game to airmash clients.
+//!
+//! Usage: `airmash-replay <replay-file> [speed] [addr]`
+//!
+//! Every client that connects is logged in as a
+//! spectator and gets sent the recording from the
+//! start. While watching, clients can use these
+//! commands:
+//! - `speed <factor>`: change the playback speed
+//! - `spectate <id>`: follow a player
+
+#[macro_use]
+extern crate log;
+
+extern crate airmash_protocol as protocol;
+extern crate airmash_protocol_v5 as protocol_v5;
+extern crate airmash_server;
+extern crate env_logger;
+extern crate ws;
+
+mod handler;
+mod playback;
+
+use std::env;
+use std::path::PathBuf;
+use std::process;
+
+use handler::ReplayHandler;
+
+fn main() {
+	env::set_var("RUST_LOG", "info");
+	env_logger::init();
+
+	let mut args = env::args().skip(1);
+
+	let path = match args.next() {
+		Some(path) => PathBuf::from(path),
+		None => {
+			eprintln!("Usage: airmash-replay <replay-file> [speed] [addr]");
+			process::exit(1);
+		}
+	};
+	let speed = match args.next().map(|x| x.parse::<f32>()) {
+		Some(Ok(speed)) if speed > 0.0 => speed,
+		Some(_) => {
+			eprintln!("Speed must be a positive number");
+			process::exit(1);
+		}
+		None => 1.0,
+	};
+	let addr = args.next().unwrap_or_else(|| "0.0.0.0:3501".to_owned());
+
+	info!("Serving replay {} on {}", path.display(), addr);
+
+	ws::listen(&*addr, |out| ReplayHandler::new(out, path.clone(), speed)).unwrap();
+}
@@ -0,0 +1,113 @@
+use airmash_server::replay::ReplayReader;
+
+use std::cmp;
+use std::fs::File;
+use std::io::BufReader;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
+
+use ws::{Message, Sender};
+
+/// Longest time to sleep for at once, so that
+/// speed changes and disconnects are noticed
+/// quickly.
+const MAX_SLEEP: Duration = Duration::from_millis(50);
+
+fn to_secs(dur: Duration) -> f64 {
+	dur.as_secs() as f64 + dur.subsec_nanos() as f64 * 1e-9
+}
+
+fn from_secs(secs: f64) -> Duration {
+	let secs = secs.max(0.0);
+	Duration::new(secs.trunc() as u64, (secs.fract() * 1e9) as u32)
+}
+
+/// Shared state between a connection and the
+/// thread that is playing the replay to it.
+#[derive(Clone)]
+pub struct PlaybackControl {
+	pub speed: Arc<Mutex<f32>>,
+	pub stopped: Arc<AtomicBool>,
+}
+
+impl PlaybackControl {
+	pub fn new(speed: f32) -> Self {
+		Self {
+			speed: Arc::new(Mutex::new(speed)),
+			stopped: Arc::new(AtomicBool::new(false)),
+		}
+	}
+
+	pub fn speed(&self) -> f32 {
+		*self.speed.lock().unwrap()
+	}
+
+	pub fn set_speed(&self, speed: f32) {
+		*self.speed.lock().unwrap() = speed;
+	}
+
+	pub fn stop(&self) {
+		self.stopped.store(true, Ordering::Relaxed);
+	}
+
+	fn is_stopped(&self) -> bool {
+		self.stopped.load(Ordering::Relaxed)
+	}
+}
+
+/// Send the recording at `path` to `out`, keeping
+/// the original timing scaled by the current speed.
+pub fn spawn(
+	out: Sender,
+	path: PathBuf,
+	mut reader: ReplayReader<BufReader<File>>,
+	control: PlaybackControl,
+) -> JoinHandle<()> {
+	thread::spawn(move || {
+		// Current position within the recording
+		let mut position = 0.0;
+		let mut last = Instant::now();
+
+		loop {
+			let frame = match reader.next_frame() {
+				Ok(Some(frame)) => frame,
+				Ok(None) => break,
+				Err(e) => {
+					error!("Error while reading replay {}: {}", path.display(), e);
+					break;
+				}
+			};
+			let target = to_secs(frame.time);
+
+			loop {
+				if control.is_stopped() {
+					return;
+				}
+
+				let now = Instant::now();
+				let speed = control.speed() as f64;
+				position += to_secs(now - last) * speed;
+				last = now;
+
+				if position >= target {
+					break;
+				}
+
+				thread::sleep(cmp::min(from_secs((target - position) / speed), MAX_SLEEP));
+			}
+
+			if out.send(Message::Binary(frame.data)).is_err() {
+				return;
+			}
+		}
+
+		info!(
+			"Finished playing {} to {:?}",
+			path.display(),
+			out.connection_id()
+		);
+	})
+}
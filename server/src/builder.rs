@@ -1,5 +1,7 @@
 use std::fmt::Debug;
 use std::net::ToSocketAddrs;
+use std::path::Path;
+use std::sync::atomic::Ordering;
 use std::sync::mpsc::{channel, Receiver, Sender};
 use std::thread;
 use std::time::{Duration, Instant};
@@ -10,14 +12,20 @@ use specs::*;
 use futures;
 
 use dispatch::Builder;
+use replay::{ReplayHeader, ReplayRecorder};
 use server;
+use status;
 use systems;
 use timeloop::timeloop;
 use timers;
 
 use types::connection::Message;
 use types::event::ConnectionEvent;
-use types::{Connections, FutureDispatcher, GameMode};
+use consts::RELOAD_CONFIG;
+use types::{
+	AccountStore, Accounts, BanList, Config, ConfigPath, Connections, FutureDispatcher, GameMode,
+	Map, SessionValidator, Sessions,
+};
 
 use component::event::TimerEvent;
 use component::time::{LastFrame, StartTime, ThisFrame};
@@ -193,6 +201,112 @@ where
 		self
 	}
 
+	/// Load the server config from a TOML or JSON file.
+	///
+	/// The file will be reloaded between frames
+	/// whenever [`RELOAD_CONFIG`][0] is set (e.g. by
+	/// the `reload-config` admin command).
+	///
+	/// # Panics
+	/// Panics if the config file cannot be read or
+	/// fails validation.
+	///
+	/// [0]: ::consts::RELOAD_CONFIG
+	pub fn with_config_file<P: AsRef<Path>>(mut self, path: P) -> Self {
+		let path = path.as_ref();
+
+		let config = match Config::load(path) {
+			Ok(config) => config,
+			Err(e) => panic!("Failed to load config from {}: {}", path.display(), e),
+		};
+
+		info!("Loaded config from {}", path.display());
+
+		self.world.add_resource(config);
+		self.world.add_resource(ConfigPath(Some(path.to_owned())));
+		self
+	}
+
+	/// Play on `map` instead of the default map. The
+	/// terrain collision grid is built from the map
+	/// when the systems are set up.
+	///
+	/// Game modes don't have access to the world when
+	/// they are created, so they should be given their
+	/// own copy of the map as well.
+	pub fn with_map(mut self, map: Map) -> Self {
+		info!("Using map {:?}", map.name);
+
+		self.world.add_resource(map);
+		self
+	}
+
+	/// Use `store` to persist player accounts. By
+	/// default accounts are only kept in memory.
+	pub fn with_account_store<S>(mut self, store: S) -> Self
+	where
+		S: AccountStore + 'static,
+	{
+		self.world.add_resource(Accounts(Box::new(store)));
+		self
+	}
+
+	/// Use `validator` to check the session tokens that
+	/// players log in with. By default every token is
+	/// rejected, so nobody gets an account.
+	pub fn with_session_validator<V>(mut self, validator: V) -> Self
+	where
+		V: SessionValidator + 'static,
+	{
+		self.world.add_resource(Sessions(Box::new(validator)));
+		self
+	}
+
+	/// Use `bans` as the list of banned IP addresses.
+	/// By default bans are not persisted.
+	pub fn with_ban_list(mut self, bans: BanList) -> Self {
+		self.world.add_resource(bans);
+		self
+	}
+
+	/// Record all packets that are sent to every player
+	/// into a replay file at `path`. The file can be
+	/// played back with the `airmash-replay` server.
+	///
+	/// The game type and room of the replay are taken
+	/// from the game mode, so this must be called after
+	/// [`with_gamemode`][0].
+	///
+	/// # Panics
+	/// Panics if the replay file cannot be created or
+	/// if no game mode has been set.
+	///
+	/// [0]: #method.with_gamemode
+	pub fn with_replay_file<P: AsRef<Path>>(mut self, path: P) -> Self {
+		use types::gamemode::{GameModeInternal, GameModeWrapper};
+
+		let path = path.as_ref();
+		let header = {
+			let gamemode = self.world.read_resource::<GameModeInternal>();
+			let gamemode = gamemode.0.as_gamemode_ref();
+
+			ReplayHeader {
+				gametype: gamemode.gametype(),
+				room: gamemode.room(),
+			}
+		};
+
+		let recorder = match ReplayRecorder::create(path, &header) {
+			Ok(recorder) => recorder,
+			Err(e) => panic!("Failed to create replay file {}: {}", path.display(), e),
+		};
+
+		info!("Recording replay to {}", path.display());
+
+		self.world.add_resource(recorder);
+		self
+	}
+
 	pub fn with_alpha_warning(self) -> Self {
 		use systems::notify::*;
 
@@ -242,6 +356,10 @@ where
 					return;
 				}
 
+				if RELOAD_CONFIG.swap(false, Ordering::Relaxed) {
+					reload_config(&mut world);
+				}
+
 				world.add_resource(ThisFrame(now));
 				dispatcher.dispatch_seq(&mut world.res);
 				dispatcher.dispatch_thread_local(&mut world.res);
@@ -249,6 +367,8 @@ where
 				world.add_resource(LastFrame(now));
 
 				let duration = Instant::now() - now;
+				status::record_frame_time(duration);
+
 				if duration > Duration::from_millis(120) {
 					// Adjust this down once it becomes a more rare event
 					warn!(
@@ -269,3 +389,29 @@ where
 		server_thread.join().unwrap();
 	}
 }
+
+/// Replace the config resource with a freshly
+/// loaded copy from the config file. The old
+/// config is kept if the new one fails to load.
+fn reload_config(world: &mut World) {
+	let path = match world.res.try_fetch::<ConfigPath>() {
+		Some(path) => path.0.clone(),
+		None => None,
+	};
+
+	let path = match path {
+		Some(path) => path,
+		None => {
+			warn!("Config reload requested but the server has no config file");
+			return;
+		}
+	};
+
+	match Config::load(&path) {
+		Ok(config) => {
+			world.add_resource(config);
+			info!("Reloaded config from {}", path.display());
+		}
+		Err(e) => error!("Failed to reload config from {}: {}", path.display(), e),
+	}
+}
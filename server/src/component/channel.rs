@@ -39,21 +39,23 @@ pub type OnMissileFire = EventChannel<MissileFire>;
 pub type OnPlayerRepel = EventChannel<PlayerRepel>;
 pub type OnPlayerMuted = EventChannel<PlayerMute>;
 pub type OnPlayerThrottled = EventChannel<PlayerThrottle>;
+pub type OnPlayerOffense = EventChannel<PlayerOffense>;
 pub type OnPlayerHit = EventChannel<PlayerHit>;
 pub type OnPowerupExpired = EventChannel<PowerupExpired>;
 pub type OnPlayerPowerup = EventChannel<PlayerPowerup>;
 pub type OnPlayerDespawn = EventChannel<PlayerDespawn>;
+pub type OnMobDespawn = EventChannel<MobDespawnEvent>;
 
 // Upgrade Events
 pub type OnUpgradeSpawn = EventChannel<UpgradeSpawnEvent>;
 pub type OnUpgradePickup = EventChannel<UpgradePickupEvent>;
-pub type OnUpgradeDespawn = EventChannel<UpgradeDespawnEvent>;
 
 // Collision events
 pub type OnPlayerTerrainCollision = EventChannel<PlayerTerrainCollision>;
 pub type OnPlayerMissileCollision = EventChannel<PlayerMissileCollision>;
 pub type OnPlayerPowerupCollision = EventChannel<PlayerPowerupCollision>;
 pub type OnMissileTerrainCollision = EventChannel<MissileTerrainCollision>;
+pub type OnMissileMissileCollision = EventChannel<MissileMissileCollision>;
 pub type OnPlayerUpgradeCollision = EventChannel<PlayerUpgradeCollision>;
 
 // Internal events
@@ -93,6 +95,7 @@ pub type OnMissileFireReader = ReaderId<MissileFire>;
 pub type OnPlayerRepelReader = ReaderId<PlayerRepel>;
 pub type OnPlayerMutedReader = ReaderId<PlayerMute>;
 pub type OnPlayerThrottledReader = ReaderId<PlayerThrottle>;
+pub type OnPlayerOffenseReader = ReaderId<PlayerOffense>;
 pub type OnPlayerHitReader = ReaderId<PlayerHit>;
 pub type OnPowerupExpiredReader = ReaderId<PowerupExpired>;
 pub type OnPlayerPowerupReader = ReaderId<PlayerPowerup>;
@@ -106,6 +109,7 @@ pub type OnPlayerMissileCollisionReader = ReaderId<PlayerMissileCollision>;
 pub type OnPlayerTerrainCollisionReader = ReaderId<PlayerTerrainCollision>;
 pub type OnPlayerPowerupCollisionReader = ReaderId<PlayerPowerupCollision>;
 pub type OnMissileTerrainCollisionReader = ReaderId<MissileTerrainCollision>;
+pub type OnMissileMissileCollisionReader = ReaderId<MissileMissileCollision>;
 pub type OnPlayerUpgradeCollisionReader = ReaderId<PlayerUpgradeCollision>;
 
 // Internal events
@@ -1,7 +1,25 @@
 use special_map::BidirRemovableMap;
 use specs::Entity;
 
-use fnv::FnvBuildHasher;
+use fnv::{FnvBuildHasher, FnvHashMap, FnvHashSet};
+
+use std::collections::BTreeMap;
 
 #[derive(Debug, Default)]
 pub struct PlayerNames(pub BidirRemovableMap<String, Entity, FnvBuildHasher>);
+
+/// Outstanding votemutes, keyed by the player
+/// being voted against. Each entry holds the
+/// set of players that have voted for the mute.
+#[derive(Debug, Default)]
+pub struct VoteMutes(pub FnvHashMap<Entity, FnvHashSet<Entity>>);
+
+/// Game mode specific values to show on the
+/// status page (e.g. the score in CTF). Game modes
+/// are responsible for keeping these up to date.
+///
+/// Keys should be valid prometheus metric names.
+/// They are static so that updating the values every
+/// frame doesn't allocate.
+#[derive(Debug, Default)]
+pub struct GameModeStatus(pub BTreeMap<&'static str, f64>);
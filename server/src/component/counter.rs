@@ -10,6 +10,11 @@ pub struct TotalKills(pub u32);
 #[derive(Clone, Debug, Copy, Component, Default)]
 pub struct TotalDeaths(pub u32);
 
+/// Number of enemy missiles that a player has
+/// shot down since joining.
+#[derive(Clone, Debug, Copy, Component, Default)]
+pub struct Interceptions(pub u32);
+
 #[derive(Clone, Debug, Copy, Component, Default)]
 pub struct Earnings(pub Score);
 
@@ -42,9 +42,17 @@ pub struct PlayerJoin {
 	pub flag: FlagCode,
 	pub session: Session,
 	pub conn: ConnectionId,
+	/// The account that the player is logged in as,
+	/// `None` if they didn't provide a session token.
+	pub account: Option<Account>,
 }
 #[derive(Copy, Clone, Debug)]
 pub struct PlayerLeave(pub Entity);
+/// A player was killed.
+///
+/// If the player died to something other than a
+/// missile then both `missile` and `killer` will
+/// be the player themselves.
 #[derive(Copy, Clone, Debug)]
 pub struct PlayerKilled {
 	pub missile: Entity,
@@ -86,6 +94,8 @@ pub struct PlayerMissileCollision(pub Collision);
 #[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
 pub struct MissileTerrainCollision(pub Collision);
 #[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+pub struct MissileMissileCollision(pub Collision);
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
 pub struct PlayerPowerupCollision(pub Collision);
 #[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
 pub struct PlayerUpgradeCollision(pub Collision);
@@ -122,6 +132,12 @@ pub struct PlayerThrottle {
 	pub player: Entity,
 }
 
+#[derive(Copy, Clone, Debug)]
+pub struct PlayerOffense {
+	pub player: Entity,
+	pub offense: Offense,
+}
+
 #[derive(Clone, Debug)]
 pub enum ChatEventType {
 	Public,
@@ -150,9 +166,12 @@ pub struct UpgradeSpawnEvent {
 	pub pos: Position,
 }
 
+/// An upgrade or powerup crate that should be
+/// removed from the game.
 #[derive(Copy, Clone, Debug)]
-pub struct UpgradeDespawnEvent {
-	pub upgrade: Entity,
+pub struct MobDespawnEvent {
+	pub mob: Entity,
+	pub ty: Mob,
 }
 
 #[derive(Copy, Clone, Debug)]
@@ -36,6 +36,18 @@ pub struct IsChatMuted;
 #[storage(NullStorage)]
 pub struct ForcePlayerUpdate;
 
+/// The player was kicked and should be removed
+/// as soon as their connection closes.
+#[derive(Copy, Clone, Debug, Default, Component)]
+#[storage(NullStorage)]
+pub struct IsKicked;
+
+/// The player is a bot that is controlled
+/// by the server and has no connection.
+#[derive(Copy, Clone, Debug, Default, Component)]
+#[storage(NullStorage)]
+pub struct IsBot;
+
 #[derive(Copy, Clone, Debug, Default)]
 pub struct IsBoosting;
 
@@ -0,0 +1,53 @@
+use specs::*;
+use types::{Config, Distance};
+
+/// The distance that a player can see in each
+/// direction from the centre of their screen.
+///
+/// This is set by the client using the `Horizon`
+/// packet and is clamped to the minimums and
+/// maximums within [`Config`][0].
+///
+/// [0]: ::types::Config
+#[derive(Copy, Clone, Debug, Component)]
+pub struct Horizon {
+	pub x: Distance,
+	pub y: Distance,
+}
+
+impl Horizon {
+	/// The horizon for a client that asked for `x` by
+	/// `y`, clamped to the limits in `config`.
+	pub fn clamped(x: f32, y: f32, config: &Config) -> Self {
+		let clamp = |val: f32, min: Distance, max: Distance| {
+			Distance::new(val.max(min.inner()).min(max.inner()))
+		};
+
+		Self {
+			x: clamp(x, config.min_horizon_x, config.max_horizon_x),
+			y: clamp(y, config.min_horizon_y, config.max_horizon_y),
+		}
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	#[test]
+	fn clamped_to_config() {
+		let config = Config::default();
+
+		let small = Horizon::clamped(0.0, 0.0, &config);
+		assert_eq!(small.x, config.min_horizon_x);
+		assert_eq!(small.y, config.min_horizon_y);
+
+		let large = Horizon::clamped(65535.0, 65535.0, &config);
+		assert_eq!(large.x, config.max_horizon_x);
+		assert_eq!(large.y, config.max_horizon_y);
+
+		let normal = Horizon::clamped(1500.0, 1000.0, &config);
+		assert_eq!(normal.x, Distance::new(1500.0));
+		assert_eq!(normal.y, Distance::new(1000.0));
+	}
+}
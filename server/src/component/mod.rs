@@ -6,7 +6,9 @@ pub mod collision;
 pub mod counter;
 pub mod event;
 pub mod flag;
+pub mod horizon;
 pub mod missile;
+pub mod prediction;
 pub mod ratelimit;
 pub mod reference;
 pub mod time;
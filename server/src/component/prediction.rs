@@ -0,0 +1,24 @@
+//! State used to keep the movement prediction
+//! done by clients in line with the server.
+
+use specs::*;
+use types::{Position, Rotation, Velocity};
+
+/// Sequence number of the last `Key` packet
+/// that was applied for a player.
+#[derive(Clone, Debug, Copy, Component, Default)]
+pub struct LastKeySeq(pub u32);
+
+/// Where the client thinks that a plane is.
+///
+/// Clients keep simulating a plane from the last
+/// `PlayerUpdate` they received for it. This is the
+/// same simulation done on the server, without any
+/// of the collisions or other effects that the
+/// client doesn't know about.
+#[derive(Clone, Debug, Copy, Component, Default)]
+pub struct ClientPrediction {
+	pub pos: Position,
+	pub rot: Rotation,
+	pub vel: Velocity,
+}
@@ -12,3 +12,6 @@ pub struct ChatThrottleLimiter(pub RateLimiter);
 
 #[derive(Clone, Debug, Component)]
 pub struct ChatMuteLimiter(pub RateLimiter);
+
+#[derive(Clone, Debug, Component)]
+pub struct VoteMuteLimiter(pub RateLimiter);
@@ -25,6 +25,9 @@ pub struct SpectateStartTime(pub Instant);
 #[derive(Clone, Debug, Copy, Component)]
 pub struct LastKeyTime(pub Instant);
 
+#[derive(Clone, Debug, Copy, Component)]
+pub struct LastChatTime(pub Instant);
+
 #[derive(Clone, Debug, Copy, Component)]
 pub struct JoinTime(pub Instant);
 
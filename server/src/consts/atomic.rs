@@ -2,4 +2,15 @@ use std::sync::atomic::{AtomicBool, AtomicUsize, ATOMIC_BOOL_INIT, ATOMIC_USIZE_
 
 pub static SHUTDOWN: AtomicBool = ATOMIC_BOOL_INIT;
 
+/// Set to reload the config file between frames
+pub static RELOAD_CONFIG: AtomicBool = ATOMIC_BOOL_INIT;
+
 pub static NUM_PLAYERS: AtomicUsize = ATOMIC_USIZE_INIT;
+
+/// Total number of packets received from clients
+pub static PACKETS_RECEIVED: AtomicUsize = ATOMIC_USIZE_INIT;
+/// Total number of packets sent to clients
+pub static PACKETS_SENT: AtomicUsize = ATOMIC_USIZE_INIT;
+/// Total number of missiles that were shot down
+/// by other missiles
+pub static MISSILE_INTERCEPTIONS: AtomicUsize = ATOMIC_USIZE_INIT;
@@ -3,8 +3,13 @@ mod terrain;
 
 pub mod config;
 pub mod missile;
+pub mod powerup;
+pub mod spectate;
 pub mod timer;
+pub mod upgrade;
 
 pub use self::atomic::NUM_PLAYERS;
+pub use self::atomic::{MISSILE_INTERCEPTIONS, PACKETS_RECEIVED, PACKETS_SENT};
+pub use self::atomic::RELOAD_CONFIG;
 pub use self::atomic::SHUTDOWN;
 pub use self::terrain::TERRAIN;
@@ -0,0 +1,6 @@
+use types::Team;
+
+/// Team given to powerup crates. Collisions are only
+/// checked between different teams, so this needs
+/// to be a team that no player will ever be on.
+pub const POWERUP_TEAM: Team = Team(0xFFFE);
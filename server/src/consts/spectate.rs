@@ -0,0 +1,6 @@
+use types::Team;
+
+/// Team given to players that join as spectators.
+/// These players don't count towards any team until
+/// they use the `join` command.
+pub const SPECTATOR_TEAM: Team = Team(0xFFFD);
@@ -12,4 +12,6 @@ lazy_static! {
 	pub static ref DELAYED_MESSAGE: TimerEventType = TimerEventType::register();
 	pub static ref CLEAR_DEAD_FLAG: TimerEventType = TimerEventType::register();
 	pub static ref DELETE_ENTITY: TimerEventType = TimerEventType::register();
+	pub static ref BACKUP_GRACE_EXPIRED: TimerEventType = TimerEventType::register();
+	pub static ref SAVE_ACCOUNTS: TimerEventType = TimerEventType::register();
 }
@@ -0,0 +1,99 @@
+use specs::*;
+use types::*;
+
+use protocol::ServerPacket;
+
+use component::channel::*;
+use component::flag::IsPlayer;
+
+/// Associates backup connections with the player
+/// whose backup token they present.
+///
+/// If the player currently has no primary connection
+/// (i.e. it closed and the player is within the
+/// grace period) then the backup connection becomes
+/// the new primary connection.
+pub struct BackupHandler {
+	reader: Option<OnBackupReader>,
+}
+
+#[derive(SystemData)]
+pub struct BackupHandlerData<'a> {
+	channel: Read<'a, OnBackup>,
+	conns: Write<'a, Connections>,
+
+	entities: Entities<'a>,
+	backup_token: ReadStorage<'a, BackupToken>,
+	is_player: ReadStorage<'a, IsPlayer>,
+	associated: WriteStorage<'a, AssociatedConnection>,
+}
+
+impl BackupHandler {
+	pub fn new() -> Self {
+		Self { reader: None }
+	}
+}
+
+impl<'a> System<'a> for BackupHandler {
+	type SystemData = BackupHandlerData<'a>;
+
+	fn setup(&mut self, res: &mut Resources) {
+		Self::SystemData::setup(res);
+
+		self.reader = Some(res.fetch_mut::<OnBackup>().register_reader());
+	}
+
+	fn run(&mut self, mut data: Self::SystemData) {
+		for evt in data.channel.read(self.reader.as_mut().unwrap()) {
+			let player = (&*data.entities, &data.backup_token, data.is_player.mask())
+				.join()
+				.find(|(_, token, ..)| token.0 == evt.1.token)
+				.map(|(ent, ..)| ent);
+
+			let player = match player {
+				Some(player) => player,
+				None => {
+					info!("{:?} sent a backup token with no matching player", evt.0);
+					data.conns.close(evt.0);
+					continue;
+				}
+			};
+
+			let has_primary = data
+				.conns
+				.iter()
+				.any(|c| c.player == Some(player) && c.ty == ConnectionType::Primary);
+
+			let ty = if has_primary {
+				ConnectionType::Backup
+			} else {
+				ConnectionType::Primary
+			};
+
+			data.conns.associate(evt.0, player, ty);
+			if ty == ConnectionType::Primary {
+				data.associated
+					.insert(player, AssociatedConnection(evt.0))
+					.unwrap();
+			}
+			data.conns.send_to(evt.0, ServerPacket::Backup);
+
+			info!("{:?} associated with player {:?} as {:?}", evt.0, player, ty);
+		}
+	}
+}
+
+use dispatch::SystemInfo;
+use handlers::{OnCloseHandler, OnOpenHandler};
+
+impl SystemInfo for BackupHandler {
+	type Dependencies = (OnOpenHandler, OnCloseHandler);
+
+	fn new() -> Self {
+		Self::new()
+	}
+
+	fn name() -> &'static str {
+		concat!(module_path!(), "::", line!())
+	}
+}
@@ -5,6 +5,7 @@ use types::*;
 use protocol::client::Key;
 use protocol::KeyCode;
 
+use component::prediction::LastKeySeq;
 use component::time::{LastKeyTime, ThisFrame};
 
 pub struct KeyHandler {
@@ -25,6 +26,7 @@ pub struct KeyHandlerData<'a> {
 
 	keystate: WriteStorage<'a, KeyState>,
 	last_key: WriteStorage<'a, LastKeyTime>,
+	last_seq: WriteStorage<'a, LastKeySeq>,
 }
 
 impl<'a> System<'a> for KeyHandler {
@@ -40,34 +42,61 @@ impl<'a> System<'a> for KeyHandler {
 	}
 
 	fn run(&mut self, mut data: Self::SystemData) {
-		if let Some(ref mut reader) = self.reader {
-			for evt in data.channel.read(reader) {
-				let player = match data.conns.associated_player(evt.0) {
-					Some(player) => player,
-					None => continue,
-				};
-
-				let keystate = data.keystate.get_mut(player).unwrap();
-
-				debug!(
-					target: "server",
-					"Received key {:?}",
-					evt
-				);
-
-				data.last_key
-					.insert(player, LastKeyTime(data.this_frame.0))
-					.unwrap();
-
-				match evt.1.key {
-					KeyCode::Up => keystate.up = evt.1.state,
-					KeyCode::Down => keystate.down = evt.1.state,
-					KeyCode::Left => keystate.left = evt.1.state,
-					KeyCode::Right => keystate.right = evt.1.state,
-					KeyCode::Fire => keystate.fire = evt.1.state,
-					KeyCode::Special => keystate.special = evt.1.state,
+		let mut keys = {
+			let conns = &data.conns;
+
+			data.channel
+				.read(self.reader.as_mut().unwrap())
+				.filter_map(|(conn, key)| {
+					debug!(
+						target: "server",
+						"Received key {:?}",
+						key
+					);
+
+					conns
+						.associated_player(*conn)
+						.map(|player| (player, key.seq, key.key, key.state))
+				})
+				.collect::<Vec<_>>()
+		};
+
+		// Packets can arrive in a different order than the
+		// client sent them (e.g. over a backup connection),
+		// apply them in the order that the client pressed
+		// the keys.
+		keys.sort_by_key(|&(player, seq, ..)| (player.id(), seq));
+
+		for (player, seq, key, state) in keys {
+			if let Some(last) = data.last_seq.get(player) {
+				if seq <= last.0 {
+					debug!(
+						target: "server",
+						"Dropped stale key {:?} with seq {} from {:?}",
+						key, seq, player
+					);
+					continue;
 				}
 			}
+
+			let keystate = match data.keystate.get_mut(player) {
+				Some(keystate) => keystate,
+				None => continue,
+			};
+
+			data.last_seq.insert(player, LastKeySeq(seq)).unwrap();
+			data.last_key
+				.insert(player, LastKeyTime(data.this_frame.0))
+				.unwrap();
+
+			match key {
+				KeyCode::Up => keystate.up = state,
+				KeyCode::Down => keystate.down = state,
+				KeyCode::Left => keystate.left = state,
+				KeyCode::Right => keystate.right = state,
+				KeyCode::Fire => keystate.fire = state,
+				KeyCode::Special => keystate.special = state,
+			}
 		}
 	}
 }
@@ -9,7 +9,7 @@ use std::env;
 use std::io::Read as IoRead;
 use std::sync::mpsc::*;
 use std::sync::Arc;
-use std::time::Instant;
+use std::time::{Instant, SystemTime};
 
 use hyper::{Client, Url};
 
@@ -32,7 +32,7 @@ impl LoginHandler {
 }
 
 impl<'a> System<'a> for LoginHandler {
-	type SystemData = (Read<'a, OnLogin>, Read<'a, Connections>);
+	type SystemData = (Read<'a, OnLogin>, Read<'a, Connections>, Read<'a, BanList>);
 
 	fn setup(&mut self, res: &mut Resources) {
 		Self::SystemData::setup(res);
@@ -41,7 +41,7 @@ impl<'a> System<'a> for LoginHandler {
 		self.channel = Some(res.fetch_mut::<FutureDispatcher>().get_channel());
 	}
 
-	fn run(&mut self, (channel, conns): Self::SystemData) {
+	fn run(&mut self, (channel, conns, bans): Self::SystemData) {
 		for evt in channel.read(self.reader.as_mut().unwrap()).cloned() {
 			let conn_opt = conns.conns.get(&evt.0);
 
@@ -52,6 +52,16 @@ impl<'a> System<'a> for LoginHandler {
 					continue;
 				}
 			};
+
+			if let Some(ban) = bans.get(conninfo.addr, SystemTime::now()) {
+				info!(
+					"{:?} with banned addr {:?} tried to log in (reason: {:?})",
+					evt.0, conninfo.addr, ban.reason
+				);
+				conns.close(evt.0);
+				continue;
+			}
+
 			let channel = self.channel.as_ref().unwrap().clone();
 
 			let connid = evt.0;
@@ -1,3 +1,4 @@
+mod backup;
 mod chat;
 mod key;
 mod login;
@@ -10,6 +11,7 @@ mod scoreboard;
 mod signal;
 mod teamchat;
 
+pub use self::backup::BackupHandler;
 pub use self::chat::ChatHandler;
 pub use self::key::KeyHandler;
 pub use self::login::LoginHandler;
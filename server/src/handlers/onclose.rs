@@ -2,77 +2,208 @@ use airmash_protocol::server::PlayerLeave;
 use specs::*;
 use types::*;
 
-use component::channel::{OnClose, OnCloseReader, OnPlayerLeave};
+use component::channel::{OnClose, OnCloseReader, OnPlayerLeave, OnTimerEvent, OnTimerEventReader};
 use component::counter::PlayersGame;
 use component::event::PlayerLeave as EvtPlayerLeave;
+use component::event::TimerEvent;
+use component::flag::IsKicked;
+use consts::spectate::SPECTATOR_TEAM;
+use consts::timer::BACKUP_GRACE_EXPIRED;
 use dispatch::SystemInfo;
 use handlers::OnOpenHandler;
 
+use fnv::FnvHashMap;
+use std::time::Instant;
+
+/// Handles connections closing.
+///
+/// When the primary connection of a player closes
+/// then one of their backup connections (if any)
+/// will be promoted to be the new primary connection.
+/// If there are no backup connections then the
+/// player is kept in the game for the grace period
+/// given in [`Config::backup_grace_period`] to
+/// allow them to reconnect with a backup connection.
 pub struct OnCloseHandler {
 	reader: Option<OnCloseReader>,
+	timer_reader: Option<OnTimerEventReader>,
+	/// Players that are currently in the grace
+	/// period along with the time at which the
+	/// grace period will expire.
+	pending: FnvHashMap<Entity, Instant>,
+}
+
+#[derive(SystemData)]
+pub struct OnCloseHandlerData<'a> {
+	entities: Entities<'a>,
+	channel: Read<'a, OnClose>,
+	timer_channel: Read<'a, OnTimerEvent>,
+	connections: Write<'a, Connections>,
+	players: Write<'a, PlayersGame>,
+	onleave: Write<'a, OnPlayerLeave>,
+	config: Read<'a, Config>,
+	future: ReadExpect<'a, FutureDispatcher>,
+	is_kicked: ReadStorage<'a, IsKicked>,
+	teams: ReadStorage<'a, Team>,
+	associated: WriteStorage<'a, AssociatedConnection>,
 }
 
 impl OnCloseHandler {
 	pub fn new() -> Self {
-		Self { reader: None }
+		Self {
+			reader: None,
+			timer_reader: None,
+			pending: FnvHashMap::default(),
+		}
+	}
+
+	fn remove_player<'a>(data: &mut OnCloseHandlerData<'a>, ent: Entity) {
+		data.connections.remove_player(ent);
+		if data.teams.get(ent) != Some(&SPECTATOR_TEAM) {
+			data.players.0 -= 1;
+		}
+
+		// Send out PlayerLeave message
+		let player_leave = PlayerLeave { id: ent.into() };
+		data.connections.send_to_all(player_leave);
+
+		data.onleave.single_write(EvtPlayerLeave(ent));
+		// Delete player entity
+		data.entities.delete(ent).unwrap();
+
+		// Log
+		info!("Player {:?} left", ent);
 	}
 }
 
 impl<'a> System<'a> for OnCloseHandler {
-	type SystemData = (
-		Entities<'a>,
-		Read<'a, OnClose>,
-		Write<'a, Connections>,
-		Write<'a, PlayersGame>,
-		Write<'a, OnPlayerLeave>,
-	);
+	type SystemData = OnCloseHandlerData<'a>;
 
 	fn setup(&mut self, res: &mut Resources) {
-		self.reader = Some(res.fetch_mut::<OnClose>().register_reader());
-
 		Self::SystemData::setup(res);
+
+		self.reader = Some(res.fetch_mut::<OnClose>().register_reader());
+		self.timer_reader = Some(res.fetch_mut::<OnTimerEvent>().register_reader());
 	}
 
-	fn run(
-		&mut self,
-		(entities, channel, mut connections, mut players, mut onleave): Self::SystemData,
-	) {
-		if let Some(ref mut reader) = self.reader {
-			for evt in channel.read(reader) {
-				let (player, ty) = {
-					let conn = match connections.conns.get(&evt.conn) {
-						Some(c) => c,
+	fn run(&mut self, mut data: Self::SystemData) {
+		for evt in data.channel.read(self.reader.as_mut().unwrap()) {
+			let (player, ty) = {
+				let conn = match data.connections.conns.get(&evt.conn) {
+					Some(c) => c,
+					None => {
+						// This can sometimes happen legitimately if a disconnect occurrs.
+						continue;
+					}
+				};
+
+				(conn.player, conn.ty)
+			};
+
+			data.connections.remove(evt.conn);
+
+			let ent = match player {
+				Some(ent) => ent,
+				None => continue,
+			};
+
+			if ty != ConnectionType::Primary {
+				continue;
+			}
+
+			// Kicked players don't get to keep playing
+			// on a backup connection.
+			if data.is_kicked.get(ent).is_some() {
+				if data.entities.is_alive(ent) {
+					Self::remove_player(&mut data, ent);
+				}
+				continue;
+			}
+
+			let backup = data
+				.connections
+				.iter()
+				.find(|c| c.player == Some(ent) && c.ty == ConnectionType::Backup)
+				.map(|c| c.id);
+
+			if let Some(backup) = backup {
+				data.connections.associate(backup, ent, ConnectionType::Primary);
+				// Packets sent to teams or to everyone that can
+				// see a position go to the associated connection.
+				data.associated
+					.insert(ent, AssociatedConnection(backup))
+					.unwrap();
+
+				info!(
+					"Primary connection {:?} of player {:?} closed, promoted backup {:?}",
+					evt.conn, ent, backup
+				);
+				continue;
+			}
+
+			let grace = data.config.backup_grace_period;
+			self.pending.insert(ent, Instant::now() + grace);
+
+			data.future.run_delayed(grace, move |inst| TimerEvent {
+				ty: *BACKUP_GRACE_EXPIRED,
+				instant: inst,
+				data: Some(Box::new(ent)),
+			});
+
+			info!(
+				"Primary connection {:?} of player {:?} closed, waiting for a backup",
+				evt.conn, ent
+			);
+		}
+
+		let expired = data
+			.timer_channel
+			.read(self.timer_reader.as_mut().unwrap())
+			.filter(|evt| evt.ty == *BACKUP_GRACE_EXPIRED)
+			.filter_map(|evt| {
+				let ent = match evt.data {
+					Some(ref dat) => match (*dat).downcast_ref::<Entity>() {
+						Some(ent) => *ent,
 						None => {
-							// This can sometimes happen legitimately if a disconnect occurrs.
-							continue;
+							error!("Unable to downcast TimerEvent data to Entity!");
+							return None;
 						}
-					};
-
-					(conn.player, conn.ty)
+					},
+					None => return None,
 				};
 
-				if ty == ConnectionType::Primary {
-					if let Some(ent) = player {
-						connections.remove_player(ent);
-						players.0 -= 1;
+				Some((ent, evt.instant))
+			})
+			.collect::<Vec<_>>();
 
-						// Send out PlayerLeave message
-						let player_leave = PlayerLeave { id: ent.into() };
-						connections.send_to_all(player_leave);
+		for (ent, instant) in expired {
+			match self.pending.get(&ent) {
+				// The primary connection was lost again after
+				// this timer was started, a later timer will
+				// take care of this player.
+				Some(&expiry) if expiry > instant => continue,
+				Some(_) => (),
+				None => continue,
+			}
 
-						onleave.single_write(EvtPlayerLeave(ent));
-						// Delete player entity
-						entities.delete(ent).unwrap();
+			self.pending.remove(&ent);
 
-						// Log
-						info!("Player {:?} left", ent);
-					} else {
-						connections.remove(evt.conn);
-					}
-				} else {
-					connections.remove(evt.conn);
-				}
+			if !data.entities.is_alive(ent) {
+				continue;
 			}
+
+			// A backup connection was established and promoted
+			// to primary while the player was in the grace period.
+			let reconnected = data
+				.connections
+				.iter()
+				.any(|c| c.player == Some(ent) && c.ty == ConnectionType::Primary);
+
+			if reconnected {
+				continue;
+			}
+
+			Self::remove_player(&mut data, ent);
 		}
 	}
 }
@@ -88,3 +219,85 @@ impl SystemInfo for OnCloseHandler {
 		concat!(module_path!(), line!())
 	}
 }
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	use component::event::ConnectionClose;
+	use protocol::ServerPacket;
+	use std::net::{IpAddr, Ipv4Addr};
+	use std::sync::mpsc::channel;
+	use ws;
+
+	fn add_connection(conns: &mut Connections, id: ConnectionId) {
+		// Nothing gets sent during the test, so a sender
+		// from a socket that never runs is good enough.
+		let sink = ws::Builder::new()
+			.build(|_: ws::Sender| |_: ws::Message| Ok(()))
+			.unwrap()
+			.broadcaster();
+
+		let info = ConnectionInfo {
+			addr: IpAddr::V4(Ipv4Addr::LOCALHOST),
+			origin: None,
+			spectate: false,
+		};
+
+		conns.add(id, sink, info);
+	}
+
+	#[test]
+	fn team_messages_go_to_promoted_backup() {
+		let primary = ConnectionId(1);
+		let backup = ConnectionId(2);
+
+		let mut world = World::new();
+		let (msg_send, msg_recv) = channel();
+		world.add_resource(Connections::new(msg_send));
+		world.add_resource(FutureDispatcher::new(channel().0));
+
+		let mut system = OnCloseHandler::new();
+		System::setup(&mut system, &mut world.res);
+
+		let player = world.create_entity().with(Team(1)).build();
+		world
+			.write_storage::<AssociatedConnection>()
+			.insert(player, AssociatedConnection(primary))
+			.unwrap();
+		{
+			let mut conns = world.write_resource::<Connections>();
+			add_connection(&mut conns, primary);
+			add_connection(&mut conns, backup);
+			conns.associate(primary, player, ConnectionType::Primary);
+			conns.associate(backup, player, ConnectionType::Backup);
+		}
+
+		world
+			.write_resource::<OnClose>()
+			.single_write(ConnectionClose { conn: primary });
+		system.run_now(&world.res);
+
+		world
+			.read_resource::<Connections>()
+			.send_to_team(player, ServerPacket::Backup);
+
+		// PollComplete sends team messages to the associated
+		// connection of every player on the team.
+		let msg = msg_recv.try_recv().unwrap();
+		let team = match msg.info {
+			MessageInfo::ToTeam(ent) => *world.read_storage::<Team>().get(ent).unwrap(),
+			info => panic!("Unexpected message info {:?}", info),
+		};
+		let recipients = (
+			&world.read_storage::<AssociatedConnection>(),
+			&world.read_storage::<Team>(),
+		)
+			.join()
+			.filter(|(_, &t)| t == team)
+			.map(|(conn, _)| conn.0)
+			.collect::<Vec<_>>();
+
+		assert_eq!(recipients, vec![backup]);
+	}
+}
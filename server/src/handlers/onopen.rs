@@ -35,7 +35,13 @@ impl<'a> System<'a> for OnOpenHandler {
 	fn run(&mut self, (channel, mut connections): Self::SystemData) {
 		if let Some(ref mut reader) = self.reader {
 			for evt in channel.read(reader) {
-				connections.add(evt.conn, evt.sink.clone(), evt.addr, evt.origin.clone());
+				let info = ConnectionInfo {
+					addr: evt.addr,
+					origin: evt.origin.clone(),
+					spectate: evt.spectate,
+				};
+
+				connections.add(evt.conn, evt.sink.clone(), info);
 			}
 		}
 	}
@@ -8,6 +8,8 @@ use std::time::{Duration, Instant};
 use protocol::server::ServerMessage;
 use protocol::ServerMessageType;
 
+use systems::handlers::game::on_leave::SaveAccountData;
+
 use std::process;
 
 #[derive(Default)]
@@ -15,10 +17,28 @@ pub struct SignalHandler {
 	time: Option<Instant>,
 }
 
+#[derive(SystemData)]
+pub struct SignalHandlerData<'a> {
+	conns: Read<'a, Connections>,
+	entities: Entities<'a>,
+	accounts: SaveAccountData<'a>,
+}
+
+impl SignalHandler {
+	fn shutdown<'a>(data: &mut SignalHandlerData<'a>) -> ! {
+		// Nobody gets a PlayerLeave event when the
+		// process exits, so accounts have to be
+		// saved here.
+		data.accounts.save_all(&data.entities);
+
+		process::exit(0);
+	}
+}
+
 impl<'a> System<'a> for SignalHandler {
-	type SystemData = Read<'a, Connections>;
+	type SystemData = SignalHandlerData<'a>;
 
-	fn run(&mut self, data: Self::SystemData) {
+	fn run(&mut self, mut data: Self::SystemData) {
 		if SHUTDOWN.swap(false, Ordering::Relaxed) {
 			if self.time.is_none() {
 				self.time = Some(Instant::now());
@@ -29,7 +49,7 @@ impl<'a> System<'a> for SignalHandler {
 					text: "Server shutting down in 30 seconds!".to_string(),
 				};
 
-				data.send_to_all(msg);
+				data.conns.send_to_all(msg);
 
 				info!(
 					target:"server",
@@ -38,13 +58,13 @@ impl<'a> System<'a> for SignalHandler {
 			} else {
 				info!("Received second interrupt, server shutting down NOW!");
 
-				process::exit(0);
+				Self::shutdown(&mut data);
 			}
 		} else if self.time.is_some() {
 			let t = self.time.unwrap();
 
 			if Instant::now() - t > Duration::from_secs(30) {
-				process::exit(0);
+				Self::shutdown(&mut data);
 			}
 		}
 	}
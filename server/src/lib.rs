@@ -30,6 +30,7 @@ extern crate shrev;
 extern crate special_map;
 extern crate specs;
 extern crate tokio;
+extern crate toml;
 extern crate uuid;
 extern crate ws;
 
@@ -45,12 +46,13 @@ mod builder;
 mod dispatch;
 mod handlers;
 mod server;
-mod status;
 mod timeloop;
 mod timers;
 
 pub mod component;
 pub mod consts;
+pub mod replay;
+pub mod status;
 pub mod systems;
 pub mod types;
 
@@ -61,7 +63,7 @@ pub use builder::AirmashServer;
 pub use dispatch::{Builder, SystemDeps, SystemInfo};
 
 pub use types::{
-	Accel, AccelScalar, Config, Connections, Distance, Energy, EnergyRegen, Flag, FutureDispatcher,
-	GameMode, GameModeWriter, Health, HealthRegen, KeyState, Level, Mob, Name, Plane, Position,
-	Score, Speed, Team, Time, Vector2, Velocity,
+	Accel, AccelScalar, Config, ConfigError, Connections, Distance, Energy, EnergyRegen, Flag,
+	FutureDispatcher, GameMode, GameModeWriter, Health, HealthRegen, KeyState, Level, Map, Mob,
+	Name, Plane, Position, Score, Speed, Team, Time, Vector2, Velocity,
 };
@@ -0,0 +1,281 @@
+//! Recording of the packets that the server sends
+//! out so that games can be replayed later.
+//!
+//! A replay file consists of a short header
+//! followed by a sequence of frames. Each frame
+//! contains the time since the start of the
+//! recording (in milliseconds) and a single server
+//! packet, serialized using protocol v5. All
+//! integers are little-endian.
+//!
+//! The header also records the game type and room
+//! of the server, so that the replay can log clients
+//! in to the same kind of game.
+//!
+//! ```text
+//! header: b"AMRP" version:u8 gametype:u8 room_len:u8 room:[u8; room_len]
+//! frame:  time:u32 len:u32 data:[u8; len]
+//! ```
+
+use std::fs::File;
+use std::io::{self, BufReader, BufWriter, ErrorKind, Read, Write};
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+use protocol::GameType;
+
+const MAGIC: &[u8; 4] = b"AMRP";
+const VERSION: u8 = 2;
+
+/// Information about the recorded game.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ReplayHeader {
+	pub gametype: GameType,
+	pub room: String,
+}
+
+/// A single recorded packet.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ReplayFrame {
+	/// Time since the start of the recording.
+	pub time: Duration,
+	/// The packet, serialized using protocol v5.
+	pub data: Vec<u8>,
+}
+
+fn write_u32<W: Write>(out: &mut W, val: u32) -> io::Result<()> {
+	out.write_all(&[
+		val as u8,
+		(val >> 8) as u8,
+		(val >> 16) as u8,
+		(val >> 24) as u8,
+	])
+}
+
+fn read_u32<R: Read>(inp: &mut R) -> io::Result<u32> {
+	let mut buf = [0u8; 4];
+	inp.read_exact(&mut buf)?;
+
+	Ok((buf[0] as u32) | (buf[1] as u32) << 8 | (buf[2] as u32) << 16 | (buf[3] as u32) << 24)
+}
+
+fn gametype_to_u8(ty: GameType) -> u8 {
+	match ty {
+		GameType::FFA => 1,
+		GameType::CTF => 2,
+		GameType::BTR => 3,
+	}
+}
+
+fn gametype_from_u8(val: u8) -> io::Result<GameType> {
+	match val {
+		1 => Ok(GameType::FFA),
+		2 => Ok(GameType::CTF),
+		3 => Ok(GameType::BTR),
+		_ => Err(io::Error::new(
+			ErrorKind::InvalidData,
+			format!("unknown game type {}", val),
+		)),
+	}
+}
+
+fn to_millis(dur: Duration) -> u32 {
+	(dur.as_secs() * 1000 + dur.subsec_millis() as u64) as u32
+}
+
+pub struct ReplayWriter<W: Write> {
+	out: W,
+	start: Instant,
+}
+
+impl<W: Write> ReplayWriter<W> {
+	pub fn new(mut out: W, header: &ReplayHeader) -> io::Result<Self> {
+		// Room names longer than this get cut off
+		let room = &header.room.as_bytes()[..header.room.len().min(255)];
+
+		out.write_all(MAGIC)?;
+		out.write_all(&[VERSION, gametype_to_u8(header.gametype), room.len() as u8])?;
+		out.write_all(room)?;
+
+		Ok(Self {
+			out,
+			start: Instant::now(),
+		})
+	}
+
+	/// Record a packet that was sent at `time`.
+	pub fn write(&mut self, time: Instant, data: &[u8]) -> io::Result<()> {
+		let time = time.duration_since(self.start);
+
+		write_u32(&mut self.out, to_millis(time))?;
+		write_u32(&mut self.out, data.len() as u32)?;
+		self.out.write_all(data)
+	}
+
+	pub fn flush(&mut self) -> io::Result<()> {
+		self.out.flush()
+	}
+}
+
+pub struct ReplayReader<R: Read> {
+	inp: R,
+	header: ReplayHeader,
+}
+
+impl<R: Read> ReplayReader<R> {
+	pub fn new(mut inp: R) -> io::Result<Self> {
+		let mut header = [0u8; 7];
+		inp.read_exact(&mut header)?;
+
+		if &header[..4] != MAGIC {
+			return Err(io::Error::new(
+				ErrorKind::InvalidData,
+				"not an airmash replay file",
+			));
+		}
+		if header[4] != VERSION {
+			return Err(io::Error::new(
+				ErrorKind::InvalidData,
+				format!("unsupported replay version {}", header[4]),
+			));
+		}
+
+		let gametype = gametype_from_u8(header[5])?;
+		let mut room = vec![0; header[6] as usize];
+		inp.read_exact(&mut room)?;
+
+		Ok(Self {
+			inp,
+			header: ReplayHeader {
+				gametype,
+				room: String::from_utf8_lossy(&room).into_owned(),
+			},
+		})
+	}
+
+	pub fn header(&self) -> &ReplayHeader {
+		&self.header
+	}
+
+	/// Read the next frame, returns `None` at the
+	/// end of the recording.
+	pub fn next_frame(&mut self) -> io::Result<Option<ReplayFrame>> {
+		let time = match read_u32(&mut self.inp) {
+			Ok(time) => time,
+			// A recording that was cut off (e.g. by the
+			// server crashing) is still usable up to
+			// that point.
+			Err(ref e) if e.kind() == ErrorKind::UnexpectedEof => return Ok(None),
+			Err(e) => return Err(e),
+		};
+		let len = read_u32(&mut self.inp)?;
+
+		let mut data = vec![0; len as usize];
+		match self.inp.read_exact(&mut data) {
+			Ok(()) => (),
+			Err(ref e) if e.kind() == ErrorKind::UnexpectedEof => return Ok(None),
+			Err(e) => return Err(e),
+		}
+
+		Ok(Some(ReplayFrame {
+			time: Duration::from_millis(time as u64),
+			data,
+		}))
+	}
+}
+
+impl ReplayReader<BufReader<File>> {
+	pub fn open<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+		Self::new(BufReader::new(File::open(path)?))
+	}
+}
+
+/// Resource for recording the packets sent by the
+/// server. Recording is disabled by default.
+#[derive(Default)]
+pub struct ReplayRecorder(pub Option<ReplayWriter<BufWriter<File>>>);
+
+impl ReplayRecorder {
+	pub fn create<P: AsRef<Path>>(path: P, header: &ReplayHeader) -> io::Result<Self> {
+		let file = BufWriter::new(File::create(path)?);
+
+		Ok(ReplayRecorder(Some(ReplayWriter::new(file, header)?)))
+	}
+
+	pub fn is_enabled(&self) -> bool {
+		self.0.is_some()
+	}
+
+	pub fn record(&mut self, time: Instant, data: &[u8]) {
+		let failed = match self.0 {
+			Some(ref mut writer) => writer.write(time, data).is_err(),
+			None => false,
+		};
+
+		if failed {
+			error!("Failed to write to replay file, recording stopped");
+			self.0 = None;
+		}
+	}
+
+	pub fn flush(&mut self) {
+		if let Some(ref mut writer) = self.0 {
+			if let Err(e) = writer.flush() {
+				error!("Failed to flush replay file: {}", e);
+			}
+		}
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	use std::io::Cursor;
+
+	fn header() -> ReplayHeader {
+		ReplayHeader {
+			gametype: GameType::BTR,
+			room: "test-room".to_owned(),
+		}
+	}
+
+	#[test]
+	fn roundtrip() {
+		let mut buf = vec![];
+
+		{
+			let mut writer = ReplayWriter::new(&mut buf, &header()).unwrap();
+			let start = writer.start;
+			writer.write(start, &[1, 2, 3]).unwrap();
+			writer
+				.write(start + Duration::from_millis(1500), &[4])
+				.unwrap();
+		}
+
+		let mut reader = ReplayReader::new(Cursor::new(buf)).unwrap();
+
+		assert_eq!(reader.header(), &header());
+
+		assert_eq!(
+			reader.next_frame().unwrap(),
+			Some(ReplayFrame {
+				time: Duration::from_millis(0),
+				data: vec![1, 2, 3],
+			})
+		);
+		assert_eq!(
+			reader.next_frame().unwrap(),
+			Some(ReplayFrame {
+				time: Duration::from_millis(1500),
+				data: vec![4],
+			})
+		);
+		assert_eq!(reader.next_frame().unwrap(), None);
+	}
+
+	#[test]
+	fn rejects_other_files() {
+		assert!(ReplayReader::new(Cursor::new(b"{\"not\": 1}".to_vec())).is_err());
+	}
+}
@@ -35,6 +35,20 @@ fn get_real_ip(shake: &Handshake) -> WsResult<(IpAddr, Option<String>)> {
 	))
 }
 
+/// Whether the client connected with a `spectate`
+/// query parameter, e.g. `ws://host/?spectate`.
+fn wants_spectate(req: &Request) -> bool {
+	let query = match req.resource().splitn(2, '?').nth(1) {
+		Some(query) => query,
+		None => return false,
+	};
+
+	query.split('&').any(|param| match param {
+		"spectate" | "spectate=1" | "spectate=true" => true,
+		_ => false,
+	})
+}
+
 impl Handler for MessageHandler {
 	fn on_shutdown(&mut self) {
 		if self.closed {
@@ -61,6 +75,7 @@ impl Handler for MessageHandler {
 				sink: self.sender.clone(),
 				addr: realaddr,
 				origin: origin,
+				spectate: wants_spectate(&shake.request),
 			}))
 			.map_err(|e| error!(target: "server", "Channel send error: {}", e))
 			// Swallow error since if this errors
@@ -104,16 +119,25 @@ impl Handler for MessageHandler {
 	}
 
 	fn on_request(&mut self, req: &Request) -> WsResult<Response> {
-		let req = Response::from_request(req);
-
-		Ok(req.unwrap_or_else(|_| {
-			let status = status::generate_status_page();
-
-			let mut res = Response::new(200, "OK", status.into_bytes());
+		let res = Response::from_request(req);
+
+		Ok(res.unwrap_or_else(|_| {
+			let (page, content_type) = match req.resource() {
+				"/metrics" => (
+					status::generate_prometheus_page(),
+					"text/plain; version=0.0.4; charset=utf-8",
+				),
+				_ => (
+					status::generate_status_page(),
+					"application/json; charset=utf-8",
+				),
+			};
+
+			let mut res = Response::new(200, "OK", page.into_bytes());
 
 			res.headers_mut().push((
 				"Content-Type".to_owned(),
-				"application/json; charset=utf-8".to_owned().into_bytes(),
+				content_type.to_owned().into_bytes(),
 			));
 
 			res
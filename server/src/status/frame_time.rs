@@ -0,0 +1,194 @@
+use std::sync::Mutex;
+use std::time::Duration;
+
+/// Number of frames to keep, about 10 seconds
+const WINDOW: usize = 600;
+/// Width of a bucket in the histogram of all frames,
+/// in microseconds.
+const BUCKET_WIDTH: u64 = 10;
+/// Number of buckets in the histogram of all frames.
+/// Anything slower than 100ms goes in the last one.
+const BUCKETS: usize = 10_000;
+
+lazy_static! {
+	static ref FRAME_TIMES: Mutex<FrameTimes> = Mutex::new(FrameTimes::default());
+	static ref ALL_FRAME_TIMES: Mutex<FrameHistogram> = Mutex::new(FrameHistogram::default());
+}
+
+/// Record how long the last frame took to run.
+pub fn record_frame_time(time: Duration) {
+	FRAME_TIMES.lock().unwrap().push(time);
+	ALL_FRAME_TIMES.lock().unwrap().push(time);
+}
+
+/// Frame time percentiles over the last 600 frames.
+pub fn frame_time_stats() -> FrameTimeStats {
+	FRAME_TIMES.lock().unwrap().stats()
+}
+
+/// Frame time percentiles over every frame since
+/// the server started. Percentiles are rounded down
+/// to the nearest 10µs, the max is exact.
+pub fn total_frame_time_stats() -> FrameTimeStats {
+	ALL_FRAME_TIMES.lock().unwrap().stats()
+}
+
+fn ms(d: Duration) -> f64 {
+	d.as_secs() as f64 * 1000.0 + d.subsec_nanos() as f64 * 1e-6
+}
+
+/// Frame time percentiles, in milliseconds.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Serialize)]
+pub struct FrameTimeStats {
+	pub p50: f64,
+	pub p90: f64,
+	pub p99: f64,
+	pub max: f64,
+}
+
+#[derive(Default)]
+struct FrameTimes {
+	times: Vec<Duration>,
+	next: usize,
+}
+
+impl FrameTimes {
+	fn push(&mut self, time: Duration) {
+		if self.times.len() < WINDOW {
+			self.times.push(time);
+		} else {
+			self.times[self.next] = time;
+		}
+
+		self.next = (self.next + 1) % WINDOW;
+	}
+
+	fn stats(&self) -> FrameTimeStats {
+		if self.times.is_empty() {
+			return FrameTimeStats::default();
+		}
+
+		let mut times = self.times.clone();
+		times.sort();
+
+		let percentile = |p: f64| {
+			let idx = ((times.len() - 1) as f64 * p).round() as usize;
+			ms(times[idx])
+		};
+
+		FrameTimeStats {
+			p50: percentile(0.5),
+			p90: percentile(0.9),
+			p99: percentile(0.99),
+			max: ms(*times.last().unwrap()),
+		}
+	}
+}
+
+/// Counts of frame times in fixed size buckets, so
+/// that percentiles over a long run don't need every
+/// frame time to be kept around.
+struct FrameHistogram {
+	counts: Vec<u64>,
+	total: u64,
+	max: Duration,
+}
+
+impl Default for FrameHistogram {
+	fn default() -> Self {
+		Self {
+			counts: vec![0; BUCKETS],
+			total: 0,
+			max: Duration::from_secs(0),
+		}
+	}
+}
+
+impl FrameHistogram {
+	fn push(&mut self, time: Duration) {
+		let micros = time.as_secs() * 1_000_000 + time.subsec_micros() as u64;
+		let bucket = ((micros / BUCKET_WIDTH) as usize).min(BUCKETS - 1);
+
+		self.counts[bucket] += 1;
+		self.total += 1;
+		self.max = self.max.max(time);
+	}
+
+	fn stats(&self) -> FrameTimeStats {
+		if self.total == 0 {
+			return FrameTimeStats::default();
+		}
+
+		let max = ms(self.max);
+		let percentile = |p: f64| {
+			let rank = ((self.total - 1) as f64 * p).round() as u64;
+			let mut seen = 0;
+
+			for (bucket, &count) in self.counts.iter().enumerate() {
+				seen += count;
+
+				if seen > rank {
+					let lower = (bucket as u64 * BUCKET_WIDTH) as f64 / 1000.0;
+					// The last bucket has no upper bound
+					return if bucket == BUCKETS - 1 {
+						max
+					} else {
+						lower.min(max)
+					};
+				}
+			}
+
+			max
+		};
+
+		FrameTimeStats {
+			p50: percentile(0.5),
+			p90: percentile(0.9),
+			p99: percentile(0.99),
+			max,
+		}
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	#[test]
+	fn percentiles() {
+		let mut times = FrameTimes::default();
+		for i in 1..=100 {
+			times.push(Duration::from_millis(i));
+		}
+
+		let stats = times.stats();
+		assert_eq!(stats.p50, 51.0);
+		assert_eq!(stats.p90, 90.0);
+		assert_eq!(stats.max, 100.0);
+	}
+
+	#[test]
+	fn window_drops_old_frames() {
+		let mut times = FrameTimes::default();
+		times.push(Duration::from_secs(1));
+		for _ in 0..WINDOW {
+			times.push(Duration::from_millis(1));
+		}
+
+		assert_eq!(times.stats().max, 1.0);
+	}
+
+	#[test]
+	fn histogram_keeps_all_frames() {
+		let mut times = FrameHistogram::default();
+		times.push(Duration::from_secs(1));
+		for i in 1..=1000 {
+			times.push(Duration::from_micros(i * 50));
+		}
+
+		let stats = times.stats();
+		assert_eq!(stats.max, 1000.0);
+		assert_eq!(stats.p50, 25.05);
+		assert_eq!(stats.p90, 45.05);
+	}
+}
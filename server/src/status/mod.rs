@@ -1,3 +1,16 @@
+//! The status page served over HTTP on the same
+//! port as the game. `/metrics` serves the status in
+//! the prometheus text format, all other paths serve
+//! it as JSON.
+
+mod frame_time;
 mod status_page;
+mod update_status;
 
-pub use self::status_page::generate_status_page;
+pub use self::frame_time::{
+	frame_time_stats, record_frame_time, total_frame_time_stats, FrameTimeStats,
+};
+pub use self::status_page::{
+	generate_prometheus_page, generate_status_page, ConnectionCounts, PacketRates, ServerStatus,
+};
+pub use self::update_status::UpdateStatus;
@@ -1,6 +1,181 @@
 use consts::NUM_PLAYERS;
+
+use serde_json;
+
+use std::collections::BTreeMap;
+use std::fmt::Write;
 use std::sync::atomic::Ordering;
+use std::sync::RwLock;
+
+use super::frame_time::FrameTimeStats;
+
+lazy_static! {
+	static ref STATUS: RwLock<ServerStatus> = RwLock::new(ServerStatus::default());
+}
+
+#[derive(Copy, Clone, Debug, Default, Serialize)]
+pub struct ConnectionCounts {
+	pub primary: usize,
+	pub backup: usize,
+	pub inactive: usize,
+}
+
+/// Packets per second, averaged since the
+/// last status update.
+#[derive(Copy, Clone, Debug, Default, Serialize)]
+pub struct PacketRates {
+	pub received: f64,
+	pub sent: f64,
+}
 
+/// Snapshot of the server state that is shown on
+/// the status page. This is updated periodically
+/// by [`UpdateStatus`][0].
+///
+/// [0]: super::UpdateStatus
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct ServerStatus {
+	pub players: usize,
+	pub teams: BTreeMap<u16, usize>,
+	pub game_type: String,
+	pub room: String,
+	/// Uptime in seconds
+	pub uptime: f64,
+	pub frame_time: FrameTimeStats,
+	pub connections: ConnectionCounts,
+	pub packets: PacketRates,
+	/// Missiles shot down by other missiles
+	/// since the server started.
+	pub interceptions: usize,
+	pub gamemode: BTreeMap<&'static str, f64>,
+}
+
+pub(super) fn set_status(status: ServerStatus) {
+	*STATUS.write().unwrap() = status;
+}
+
+fn current_status() -> ServerStatus {
+	let mut status = STATUS.read().unwrap().clone();
+	// This one is always up to date
+	status.players = NUM_PLAYERS.load(Ordering::Relaxed);
+	status
+}
+
+/// Status of the server as JSON.
 pub fn generate_status_page() -> String {
-	format!("{{\"players\":{}}}", NUM_PLAYERS.load(Ordering::Relaxed))
+	serde_json::to_string(&current_status()).unwrap()
+}
+
+/// Status of the server in the prometheus
+/// text exposition format.
+pub fn generate_prometheus_page() -> String {
+	format_prometheus(&current_status())
+}
+
+fn escape_label(val: &str) -> String {
+	val.replace('\\', "\\\\")
+		.replace('"', "\\\"")
+		.replace('\n', "\\n")
+}
+
+fn format_prometheus(status: &ServerStatus) -> String {
+	let mut out = String::new();
+
+	macro_rules! metric {
+		($name:expr, $help:expr, [$( ($labels:expr, $val:expr) ),*]) => {
+			metric!($name, $help, "gauge", [$( ($labels, $val) ),*])
+		};
+		($name:expr, $help:expr, $ty:expr, [$( ($labels:expr, $val:expr) ),*]) => {{
+			writeln!(out, "# HELP airmash_{} {}", $name, $help).unwrap();
+			writeln!(out, "# TYPE airmash_{} {}", $name, $ty).unwrap();
+			$( writeln!(out, "airmash_{}{} {}", $name, $labels, $val).unwrap(); )*
+		}};
+	}
+
+	metric!(
+		"info",
+		"Information about the running game",
+		[(
+			format!(
+				"{{game_type=\"{}\",room=\"{}\"}}",
+				escape_label(&status.game_type),
+				escape_label(&status.room)
+			),
+			1
+		)]
+	);
+	metric!("players", "Number of players in the game", [("", status.players)]);
+	metric!("uptime_seconds", "Time since the server started", [("", status.uptime)]);
+
+	writeln!(out, "# HELP airmash_team_players Number of players on each team").unwrap();
+	writeln!(out, "# TYPE airmash_team_players gauge").unwrap();
+	for (team, count) in status.teams.iter() {
+		writeln!(out, "airmash_team_players{{team=\"{}\"}} {}", team, count).unwrap();
+	}
+
+	metric!(
+		"frame_time_milliseconds",
+		"Time taken to run a frame over the last 10 seconds",
+		"summary",
+		[
+			("{quantile=\"0.5\"}", status.frame_time.p50),
+			("{quantile=\"0.9\"}", status.frame_time.p90),
+			("{quantile=\"0.99\"}", status.frame_time.p99),
+			("{quantile=\"1\"}", status.frame_time.max)
+		]
+	);
+	metric!(
+		"connections",
+		"Number of open connections by type",
+		[
+			("{type=\"primary\"}", status.connections.primary),
+			("{type=\"backup\"}", status.connections.backup),
+			("{type=\"inactive\"}", status.connections.inactive)
+		]
+	);
+	metric!(
+		"packets_per_second",
+		"Rate of packets sent and received",
+		[
+			("{direction=\"received\"}", status.packets.received),
+			("{direction=\"sent\"}", status.packets.sent)
+		]
+	);
+
+	metric!(
+		"missile_interceptions",
+		"Missiles shot down by other missiles",
+		[("", status.interceptions)]
+	);
+
+	for (name, val) in status.gamemode.iter() {
+		metric!(name, "Game mode specific value", [("", val)]);
+	}
+
+	out
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	#[test]
+	fn prometheus_format() {
+		let mut status = ServerStatus::default();
+		status.players = 3;
+		status.room = "ab\"c".to_owned();
+		status.teams.insert(1, 2);
+		status.gamemode.insert("ctf_blue_captures", 2.0);
+		status.frame_time.p50 = 4.5;
+
+		let page = format_prometheus(&status);
+
+		assert!(page.contains("airmash_players 3\n"));
+		assert!(page.contains("room=\"ab\\\"c\""));
+		assert!(page.contains("airmash_team_players{team=\"1\"} 2\n"));
+		assert!(page.contains("# TYPE airmash_ctf_blue_captures gauge\n"));
+		assert!(page.contains("airmash_ctf_blue_captures 2\n"));
+		assert!(page.contains("# TYPE airmash_frame_time_milliseconds summary\n"));
+		assert!(page.contains("airmash_frame_time_milliseconds{quantile=\"0.5\"} 4.5\n"));
+	}
 }
@@ -0,0 +1,105 @@
+use specs::*;
+use types::*;
+
+use component::collection::GameModeStatus;
+use component::flag::IsPlayer;
+use component::time::{StartTime, ThisFrame};
+use consts::{MISSILE_INTERCEPTIONS, PACKETS_RECEIVED, PACKETS_SENT};
+
+use std::sync::atomic::Ordering;
+use std::time::{Duration, Instant};
+
+use super::frame_time::frame_time_stats;
+use super::status_page::*;
+
+/// How often the status snapshot gets refreshed.
+const UPDATE_INTERVAL: Duration = Duration::from_secs(1);
+
+fn secs(dur: Duration) -> f64 {
+	dur.as_secs() as f64 + dur.subsec_nanos() as f64 * 1e-9
+}
+
+/// Periodically copy the state of the server
+/// into the snapshot used by the status page.
+#[derive(Default)]
+pub struct UpdateStatus {
+	last: Option<(Instant, usize, usize)>,
+}
+
+#[derive(SystemData)]
+pub struct UpdateStatusData<'a> {
+	this_frame: Read<'a, ThisFrame>,
+	start_time: Read<'a, StartTime>,
+	conns: Read<'a, Connections>,
+	gamemode: GameModeWriter<'a, GameMode>,
+	gamemode_status: Read<'a, GameModeStatus>,
+
+	team: ReadStorage<'a, Team>,
+	is_player: ReadStorage<'a, IsPlayer>,
+}
+
+impl<'a> System<'a> for UpdateStatus {
+	type SystemData = UpdateStatusData<'a>;
+
+	fn run(&mut self, data: Self::SystemData) {
+		let now = data.this_frame.0;
+		let received = PACKETS_RECEIVED.load(Ordering::Relaxed);
+		let sent = PACKETS_SENT.load(Ordering::Relaxed);
+
+		let packets = match self.last {
+			Some((last, _, _)) if now - last < UPDATE_INTERVAL => return,
+			Some((last, last_received, last_sent)) => {
+				let elapsed = secs(now - last);
+
+				PacketRates {
+					received: received.wrapping_sub(last_received) as f64 / elapsed,
+					sent: sent.wrapping_sub(last_sent) as f64 / elapsed,
+				}
+			}
+			None => PacketRates::default(),
+		};
+		self.last = Some((now, received, sent));
+
+		let mut connections = ConnectionCounts::default();
+		for conn in data.conns.iter() {
+			match conn.ty {
+				ConnectionType::Primary => connections.primary += 1,
+				ConnectionType::Backup => connections.backup += 1,
+				ConnectionType::Inactive => connections.inactive += 1,
+			}
+		}
+
+		let mut status = ServerStatus {
+			game_type: format!("{:?}", data.gamemode.get().gametype()),
+			room: data.gamemode.get().room(),
+			uptime: secs(now - data.start_time.0),
+			frame_time: frame_time_stats(),
+			connections,
+			packets,
+			interceptions: MISSILE_INTERCEPTIONS.load(Ordering::Relaxed),
+			gamemode: data.gamemode_status.0.clone(),
+			..Default::default()
+		};
+
+		for (team, ..) in (&data.team, data.is_player.mask()).join() {
+			*status.teams.entry(team.0).or_insert(0) += 1;
+			status.players += 1;
+		}
+
+		set_status(status);
+	}
+}
+
+use dispatch::SystemInfo;
+
+impl SystemInfo for UpdateStatus {
+	type Dependencies = ();
+
+	fn name() -> &'static str {
+		concat!(module_path!(), "::", line!())
+	}
+
+	fn new() -> Self {
+		Self::default()
+	}
+}
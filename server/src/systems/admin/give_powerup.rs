@@ -78,6 +78,7 @@ pub struct GivePowerupData<'a> {
 	config: Read<'a, Config>,
 	conns: Read<'a, Connections>,
 	is_player: ReadStorage<'a, IsPlayer>,
+	roles: ReadStorage<'a, Role>,
 }
 
 impl GivePowerup {
@@ -89,7 +90,7 @@ impl GivePowerup {
 
 		let &(conn, ref packet) = evt;
 
-		if !data.config.admin_enabled {
+		if !super::is_admin(&data.config, &data.conns, &data.roles, conn) {
 			return Ok(());
 		}
 
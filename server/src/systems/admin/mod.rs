@@ -1,11 +1,37 @@
 mod register;
 
 mod give_powerup;
+mod reload_config;
 mod spawn_upgrade;
 mod teleport;
 
 pub use self::register::register;
 
 pub use self::give_powerup::GivePowerup;
+pub use self::reload_config::ReloadConfig;
 pub use self::spawn_upgrade::SpawnUpgrade;
 pub use self::teleport::Teleport;
+
+use specs::*;
+use types::*;
+
+/// Whether the player on `conn` is allowed to use
+/// admin commands. This requires both that admin
+/// commands are enabled and that the player has
+/// the admin role.
+fn is_admin(
+	config: &Config,
+	conns: &Connections,
+	roles: &ReadStorage<Role>,
+	conn: ConnectionId,
+) -> bool {
+	if !config.admin_enabled {
+		return false;
+	}
+
+	conns
+		.associated_player(conn)
+		.and_then(|player| roles.get(player))
+		.map(|&role| role == Role::Admin)
+		.unwrap_or(false)
+}
@@ -7,4 +7,5 @@ pub fn register<'a, 'b>(builder: Builder<'a, 'b>) -> Builder<'a, 'b> {
 		.with_handler::<SpawnUpgrade>()
 		.with_handler::<Teleport>()
 		.with_handler::<GivePowerup>()
+		.with_handler::<ReloadConfig>()
 }
@@ -0,0 +1,71 @@
+use specs::*;
+
+use SystemInfo;
+
+use consts::RELOAD_CONFIG;
+use types::*;
+
+use component::event::*;
+use protocol::server::CommandReply;
+use protocol::CommandReplyType;
+use systems::PacketHandler;
+
+use utils::event_handler::*;
+
+use std::sync::atomic::Ordering;
+
+/// Reload the config file between this frame
+/// and the next one.
+#[derive(Default)]
+pub struct ReloadConfig;
+
+#[derive(SystemData)]
+pub struct ReloadConfigData<'a> {
+	config: Read<'a, Config>,
+	conns: Read<'a, Connections>,
+	roles: ReadStorage<'a, Role>,
+}
+
+impl EventHandlerTypeProvider for ReloadConfig {
+	type Event = CommandEvent;
+}
+
+impl<'a> EventHandler<'a> for ReloadConfig {
+	type SystemData = ReloadConfigData<'a>;
+
+	fn on_event(&mut self, evt: &Self::Event, data: &mut Self::SystemData) {
+		let &(conn, ref cmd) = evt;
+
+		if !super::is_admin(&data.config, &data.conns, &data.roles, conn) {
+			return;
+		}
+
+		if cmd.com != "reload-config" {
+			return;
+		}
+
+		info!("{:?} requested a config reload", conn);
+
+		RELOAD_CONFIG.store(true, Ordering::Relaxed);
+
+		data.conns.send_to(
+			conn,
+			CommandReply {
+				ty: CommandReplyType::ShowInConsole,
+				text: "Config will be reloaded at the end of this frame".to_owned(),
+			},
+		);
+	}
+}
+
+impl SystemInfo for ReloadConfig {
+	type Dependencies = PacketHandler;
+
+	fn name() -> &'static str {
+		concat!(module_path!(), "::", line!())
+	}
+
+	fn new() -> Self {
+		Self::default()
+	}
+}
@@ -19,8 +19,10 @@ pub struct SpawnUpgrade {
 pub struct SpawnUpgradeData<'a> {
 	channel: Write<'a, OnUpgradeSpawn>,
 	config: Read<'a, Config>,
+	conns: Read<'a, Connections>,
 
 	entities: Entities<'a>,
+	roles: ReadStorage<'a, Role>,
 	pos: WriteStorage<'a, Position>,
 }
 
@@ -48,11 +50,11 @@ impl<'a> EventHandler<'a> for SpawnUpgrade {
 	}
 
 	fn on_event(&mut self, evt: &Self::Event, data: &mut Self::SystemData) {
-		let (_, cmd) = evt;
+		let &(conn, ref cmd) = evt;
 
-		// If admin commands aren't enabled then
-		// there is nothing to do here.
-		if !data.config.admin_enabled {
+		// If the player isn't allowed to use admin
+		// commands then there is nothing to do here.
+		if !super::is_admin(&data.config, &data.conns, &data.roles, conn) {
 			return;
 		}
 
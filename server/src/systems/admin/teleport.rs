@@ -23,6 +23,8 @@ pub struct TeleportData<'a> {
 	pub entities: Entities<'a>,
 	pub pos: WriteStorage<'a, Position>,
 	pub conns: Read<'a, Connections>,
+	pub config: Read<'a, Config>,
+	pub roles: ReadStorage<'a, Role>,
 }
 
 impl EventHandlerTypeProvider for Teleport {
@@ -44,6 +46,10 @@ impl<'a> EventHandler<'a> for Teleport {
 			return;
 		}
 
+		if !super::is_admin(&data.config, &data.conns, &data.roles, conn) {
+			return;
+		}
+
 		let result = parse_command_data(&packet.data).and_then(|x| {
 			if x.id == 0 {
 				return Ok((player, x));
@@ -0,0 +1,263 @@
+use specs::*;
+use types::*;
+
+use fnv::{FnvHashMap, FnvHashSet};
+use std::time::{Duration, Instant};
+
+use component::channel::OnPlayerSpectate;
+use component::event::{PlayerSpectate, TimerEvent};
+use component::flag::{IsBot, IsDead, IsKicked, IsPlayer, IsSpectating};
+use component::time::{LastChatTime, LastKeyTime, ThisFrame};
+use consts::timer::AFK_TIMER;
+
+use protocol::server::ServerMessage;
+use protocol::ServerMessageType;
+
+use systems::moderation::kick_player;
+use utils::{EventHandler, EventHandlerTypeProvider};
+use SystemInfo;
+
+/// Move idle players to spectate and disconnect
+/// them if they stay idle.
+///
+/// Players that are moved to spectate need to
+/// press a key to count as active again. Players
+/// that went into spectate on their own can also
+/// stay by chatting.
+#[derive(Default)]
+pub struct CheckAfk {
+	/// Players that have already been warned about
+	/// the next thing that will happen to them.
+	warned: FnvHashSet<Entity>,
+	/// Players that were moved to spectate for
+	/// being idle, and when that happened.
+	forced: FnvHashMap<Entity, Instant>,
+}
+
+#[derive(SystemData)]
+pub struct CheckAfkData<'a> {
+	conns: Read<'a, Connections>,
+	config: Read<'a, Config>,
+	this_frame: Read<'a, ThisFrame>,
+	channel: Write<'a, OnPlayerSpectate>,
+
+	entities: Entities<'a>,
+	is_player: ReadStorage<'a, IsPlayer>,
+	is_bot: ReadStorage<'a, IsBot>,
+	is_spec: ReadStorage<'a, IsSpectating>,
+	is_dead: ReadStorage<'a, IsDead>,
+	is_kicked: WriteStorage<'a, IsKicked>,
+	last_key: ReadStorage<'a, LastKeyTime>,
+	last_chat: ReadStorage<'a, LastChatTime>,
+	name: ReadStorage<'a, Name>,
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+enum Idle {
+	Active,
+	Warn,
+	LimitReached,
+}
+
+fn check_idle(idle: Duration, limit: Duration, warning: Duration) -> Idle {
+	if idle >= limit {
+		Idle::LimitReached
+	} else if idle + warning >= limit {
+		Idle::Warn
+	} else {
+		Idle::Active
+	}
+}
+
+fn warning_message(text: String, warning: Duration) -> ServerMessage {
+	ServerMessage {
+		ty: ServerMessageType::Banner,
+		duration: (warning.as_secs() * 1000) as u32 + warning.subsec_millis(),
+		text,
+	}
+}
+
+impl EventHandlerTypeProvider for CheckAfk {
+	type Event = TimerEvent;
+}
+
+impl<'a> EventHandler<'a> for CheckAfk {
+	type SystemData = CheckAfkData<'a>;
+
+	fn on_event(&mut self, evt: &TimerEvent, data: &mut Self::SystemData) {
+		if evt.ty != *AFK_TIMER {
+			return;
+		}
+
+		let config = data.config.afk.clone();
+		if !config.enabled {
+			return;
+		}
+
+		let now = data.this_frame.0;
+
+		// Forget about players that have left
+		{
+			let entities = &data.entities;
+			self.warned.retain(|&ent| entities.is_alive(ent));
+			self.forced.retain(|&ent, _| entities.is_alive(ent));
+		}
+
+		let players = (
+			&*data.entities,
+			&data.last_key,
+			data.is_player.mask(),
+			!data.is_bot.mask(),
+			!data.is_kicked.mask(),
+		)
+			.join()
+			.map(|(ent, last_key, ..)| (ent, last_key.0))
+			.collect::<Vec<_>>();
+
+		for (player, last_key) in players {
+			// Pressing a key after being moved to
+			// spectate means that the player is back.
+			let returned = self
+				.forced
+				.get(&player)
+				.map(|&forced| last_key > forced)
+				.unwrap_or(false);
+			if returned {
+				self.forced.remove(&player);
+			}
+
+			if data.is_spec.get(player).is_none() {
+				// Players that respawn out of a forced spectate
+				// get the full time from when they were moved.
+				let last_active = match self.forced.get(&player) {
+					Some(&forced) => forced.max(last_key),
+					None => last_key,
+				};
+
+				match check_idle(now - last_active, config.spectate_time, config.warning) {
+					Idle::LimitReached => {
+						let target = (&*data.entities, data.is_player.mask(), !data.is_spec.mask())
+							.join()
+							.map(|(ent, ..)| ent)
+							.filter(|&ent| ent != player)
+							.next();
+
+						data.channel.single_write(PlayerSpectate {
+							player,
+							target,
+							is_dead: data.is_dead.get(player).is_some(),
+							is_spec: false,
+						});
+
+						data.conns.send_to_player(
+							player,
+							warning_message(
+								"You have been moved to spectate for being idle".to_owned(),
+								config.warning,
+							),
+						);
+
+						self.forced.insert(player, now);
+						self.warned.remove(&player);
+					}
+					Idle::Warn => {
+						if self.warned.insert(player) {
+							data.conns.send_to_player(
+								player,
+								warning_message(
+									format!(
+										"You will be moved to spectate in {} seconds if you stay idle",
+										config.warning.as_secs()
+									),
+									config.warning,
+								),
+							);
+						}
+					}
+					Idle::Active => {
+						self.warned.remove(&player);
+					}
+				}
+
+				continue;
+			}
+
+			let (last_active, limit) = match self.forced.get(&player) {
+				Some(_) => (last_key, config.disconnect_time),
+				None => {
+					let last_chat = data.last_chat.get(player).map(|x| x.0).unwrap_or(last_key);
+
+					(last_key.max(last_chat), config.spectator_disconnect_time)
+				}
+			};
+
+			match check_idle(now - last_active, limit, config.warning) {
+				Idle::LimitReached => {
+					info!(
+						"Disconnecting {:?} ({:?}) for being idle",
+						data.name.get(player).map(|x| &x.0),
+						player
+					);
+
+					kick_player(&data.conns, &mut data.is_kicked, player);
+
+					self.forced.remove(&player);
+					self.warned.remove(&player);
+				}
+				Idle::Warn => {
+					if self.warned.insert(player) {
+						data.conns.send_to_player(
+							player,
+							warning_message(
+								format!(
+									"You will be disconnected in {} seconds if you stay idle",
+									config.warning.as_secs()
+								),
+								config.warning,
+							),
+						);
+					}
+				}
+				Idle::Active => {
+					self.warned.remove(&player);
+				}
+			}
+		}
+	}
+}
+
+impl SystemInfo for CheckAfk {
+	type Dependencies = ();
+
+	fn name() -> &'static str {
+		concat!(module_path!(), "::", line!())
+	}
+
+	fn new() -> Self {
+		Self::default()
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	#[test]
+	fn warns_before_limit() {
+		let limit = Duration::from_secs(300);
+		let warning = Duration::from_secs(30);
+
+		assert_eq!(
+			check_idle(Duration::from_secs(10), limit, warning),
+			Idle::Active
+		);
+		assert_eq!(
+			check_idle(Duration::from_secs(270), limit, warning),
+			Idle::Warn
+		);
+		assert_eq!(
+			check_idle(Duration::from_secs(300), limit, warning),
+			Idle::LimitReached
+		);
+	}
+}
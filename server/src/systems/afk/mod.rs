@@ -0,0 +1,9 @@
+mod register;
+
+mod check_afk;
+mod track_chat;
+
+pub use self::register::register;
+
+pub use self::check_afk::CheckAfk;
+pub use self::track_chat::TrackChatTime;
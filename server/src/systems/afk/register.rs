@@ -0,0 +1,7 @@
+use dispatch::Builder;
+
+use super::*;
+
+pub fn register<'a, 'b>(builder: Builder<'a, 'b>) -> Builder<'a, 'b> {
+	builder.with::<TrackChatTime>().with_handler::<CheckAfk>()
+}
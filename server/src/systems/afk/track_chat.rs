@@ -0,0 +1,61 @@
+use specs::*;
+use types::*;
+
+use SystemInfo;
+
+use component::channel::*;
+use component::time::{LastChatTime, ThisFrame};
+
+use systems::handlers::packet::ChatEventHandler;
+
+/// Keep track of when each player last chatted,
+/// so that spectators that are still talking to
+/// their team aren't treated as idle.
+#[derive(Default)]
+pub struct TrackChatTime {
+	reader: Option<OnChatEventReader>,
+}
+
+#[derive(SystemData)]
+pub struct TrackChatTimeData<'a> {
+	channel: Read<'a, OnAnyChatEvent>,
+	conns: Read<'a, Connections>,
+	this_frame: Read<'a, ThisFrame>,
+
+	last_chat: WriteStorage<'a, LastChatTime>,
+}
+
+impl<'a> System<'a> for TrackChatTime {
+	type SystemData = TrackChatTimeData<'a>;
+
+	fn setup(&mut self, res: &mut Resources) {
+		Self::SystemData::setup(res);
+
+		self.reader = Some(res.fetch_mut::<OnAnyChatEvent>().register_reader());
+	}
+
+	fn run(&mut self, mut data: Self::SystemData) {
+		for evt in data.channel.read(self.reader.as_mut().unwrap()) {
+			let player = match data.conns.associated_player(evt.conn) {
+				Some(p) => p,
+				None => continue,
+			};
+
+			data.last_chat
+				.insert(player, LastChatTime(data.this_frame.0))
+				.unwrap();
+		}
+	}
+}
+
+impl SystemInfo for TrackChatTime {
+	type Dependencies = ChatEventHandler;
+
+	fn name() -> &'static str {
+		concat!(module_path!(), "::", line!())
+	}
+
+	fn new() -> Self {
+		Self::default()
+	}
+}
@@ -0,0 +1,90 @@
+use specs::*;
+use types::*;
+
+use SystemInfo;
+
+use component::channel::*;
+use component::event::PlayerOffense;
+use component::time::ThisFrame;
+
+use systems::PacketHandler;
+
+use super::InitAntiCheat;
+
+/// Run the anti-cheat checks on every key
+/// packet and emit a [`PlayerOffense`] for
+/// each check that fails.
+pub struct CheckKeys {
+	reader: Option<OnKeyReader>,
+}
+
+#[derive(SystemData)]
+pub struct CheckKeysData<'a> {
+	channel: Read<'a, OnKey>,
+	offenses: Write<'a, OnPlayerOffense>,
+	conns: Read<'a, Connections>,
+	config: Read<'a, Config>,
+	this_frame: Read<'a, ThisFrame>,
+
+	state: WriteStorage<'a, AntiCheatState>,
+	plane: ReadStorage<'a, Plane>,
+}
+
+impl<'a> System<'a> for CheckKeys {
+	type SystemData = CheckKeysData<'a>;
+
+	fn setup(&mut self, res: &mut Resources) {
+		Self::SystemData::setup(res);
+
+		self.reader = Some(res.fetch_mut::<OnKey>().register_reader());
+	}
+
+	fn run(&mut self, mut data: Self::SystemData) {
+		let now = data.this_frame.0;
+		let ref config = *data.config;
+
+		let mut keys = {
+			let conns = &data.conns;
+
+			data.channel
+				.read(self.reader.as_mut().unwrap())
+				.filter_map(|(conn, key)| conns.associated_player(*conn).map(|p| (p, key)))
+				.collect::<Vec<_>>()
+		};
+
+		if !config.anticheat.enabled {
+			return;
+		}
+
+		// Keys from every connection of a player are applied,
+		// so they all need to be checked. Check them in the
+		// same order that KeyHandler applies them in.
+		keys.sort_by_key(|&(player, key)| (player.id(), key.seq));
+
+		for (player, key) in keys {
+			let state = match data.state.get_mut(player) {
+				Some(state) => state,
+				None => continue,
+			};
+
+			let fire_delay = data.plane.get(player).map(|p| config.planes[*p].fire_delay);
+			let offenses = state.check_key(&config.anticheat, key, fire_delay, now);
+
+			for offense in offenses {
+				data.offenses.single_write(PlayerOffense { player, offense });
+			}
+		}
+	}
+}
+
+impl SystemInfo for CheckKeys {
+	type Dependencies = (PacketHandler, InitAntiCheat);
+
+	fn name() -> &'static str {
+		concat!(module_path!(), "::", line!())
+	}
+
+	fn new() -> Self {
+		Self { reader: None }
+	}
+}
@@ -0,0 +1,53 @@
+use specs::*;
+
+use types::*;
+
+use SystemInfo;
+
+use systems::handlers::packet::LoginHandler;
+
+use component::channel::*;
+use component::time::ThisFrame;
+
+pub struct InitAntiCheat {
+	reader: Option<OnPlayerJoinReader>,
+}
+
+#[derive(SystemData)]
+pub struct InitAntiCheatData<'a> {
+	pub channel: Read<'a, OnPlayerJoin>,
+	pub config: Read<'a, Config>,
+	pub this_frame: Read<'a, ThisFrame>,
+
+	pub state: WriteStorage<'a, AntiCheatState>,
+}
+
+impl<'a> System<'a> for InitAntiCheat {
+	type SystemData = InitAntiCheatData<'a>;
+
+	fn setup(&mut self, res: &mut Resources) {
+		Self::SystemData::setup(res);
+
+		self.reader = Some(res.fetch_mut::<OnPlayerJoin>().register_reader());
+	}
+
+	fn run(&mut self, mut data: Self::SystemData) {
+		for evt in data.channel.read(self.reader.as_mut().unwrap()) {
+			let state = AntiCheatState::new(&data.config.anticheat, data.this_frame.0);
+
+			data.state.insert(evt.id, state).unwrap();
+		}
+	}
+}
+
+impl SystemInfo for InitAntiCheat {
+	type Dependencies = LoginHandler;
+
+	fn name() -> &'static str {
+		concat!(module_path!(), "::", line!())
+	}
+
+	fn new() -> Self {
+		Self { reader: None }
+	}
+}
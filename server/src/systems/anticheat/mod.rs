@@ -0,0 +1,16 @@
+//! Systems for detecting and removing scripted
+//! clients. See [`types::anticheat`][0] for the
+//! checks that are done.
+//!
+//! [0]: ::types::anticheat
+
+mod check_keys;
+mod init;
+mod policy;
+mod register;
+
+pub use self::check_keys::CheckKeys;
+pub use self::init::InitAntiCheat;
+pub use self::policy::ApplyAntiCheatPolicy;
+
+pub use self::register::register;
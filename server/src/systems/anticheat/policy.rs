@@ -0,0 +1,112 @@
+use specs::*;
+use types::*;
+
+use std::time::SystemTime;
+
+use component::event::PlayerOffense;
+use component::flag::IsKicked;
+use component::time::ThisFrame;
+use systems::moderation::kick_player;
+use SystemInfo;
+
+use utils::{EventHandler, EventHandlerTypeProvider};
+
+use super::CheckKeys;
+
+/// Add up offenses and log, kick or ban players
+/// once their score passes the thresholds in the
+/// anti-cheat config.
+///
+/// Moderators and admins are only ever logged.
+#[derive(Default)]
+pub struct ApplyAntiCheatPolicy;
+
+#[derive(SystemData)]
+pub struct ApplyAntiCheatPolicyData<'a> {
+	conns: Read<'a, Connections>,
+	config: Read<'a, Config>,
+	bans: Write<'a, BanList>,
+	this_frame: Read<'a, ThisFrame>,
+
+	state: WriteStorage<'a, AntiCheatState>,
+	roles: ReadStorage<'a, Role>,
+	names: ReadStorage<'a, Name>,
+	is_kicked: WriteStorage<'a, IsKicked>,
+}
+
+impl EventHandlerTypeProvider for ApplyAntiCheatPolicy {
+	type Event = PlayerOffense;
+}
+
+impl<'a> EventHandler<'a> for ApplyAntiCheatPolicy {
+	type SystemData = ApplyAntiCheatPolicyData<'a>;
+
+	fn on_event(&mut self, evt: &PlayerOffense, data: &mut Self::SystemData) {
+		let ref config = data.config.anticheat;
+
+		// Already on their way out
+		if data.is_kicked.get(evt.player).is_some() {
+			return;
+		}
+
+		let state = try_get!(evt.player, mut data.state);
+		let action = match state.add_offense(config, evt.offense, data.this_frame.0) {
+			Some(action) => action,
+			None => return,
+		};
+
+		let name = data
+			.names
+			.get(evt.player)
+			.map(|x| &*x.0)
+			.unwrap_or("<unknown>");
+
+		warn!(
+			"Player {:?} ({}) committed offense {:?}, score is now {}",
+			evt.player, name, evt.offense, state.score
+		);
+
+		let role = data.roles.get(evt.player).cloned().unwrap_or_default();
+		if action == AntiCheatAction::Log || role > Role::Player {
+			return;
+		}
+
+		if action == AntiCheatAction::Ban {
+			let addr = data
+				.conns
+				.iter()
+				.find(|c| c.player == Some(evt.player))
+				.map(|c| c.info.addr);
+
+			if let Some(addr) = addr {
+				let ban = Ban {
+					addr,
+					expiry: config.ban_duration.map(|d| SystemTime::now() + d),
+					reason: format!("Anti-cheat: {:?}", evt.offense),
+				};
+
+				if let Err(e) = data.bans.ban(ban) {
+					error!("Failed to save ban for {}: {}", addr, e);
+				}
+
+				info!("Player {:?} ({}) with addr {} was banned", evt.player, name, addr);
+			}
+		} else {
+			info!("Player {:?} ({}) was kicked", evt.player, name);
+		}
+
+		kick_player(&data.conns, &mut data.is_kicked, evt.player);
+	}
+}
+
+impl SystemInfo for ApplyAntiCheatPolicy {
+	type Dependencies = CheckKeys;
+
+	fn name() -> &'static str {
+		concat!(module_path!(), "::", line!())
+	}
+
+	fn new() -> Self {
+		Self::default()
+	}
+}
@@ -0,0 +1,9 @@
+use super::*;
+use Builder;
+
+pub fn register<'a, 'b>(builder: Builder<'a, 'b>) -> Builder<'a, 'b> {
+	builder
+		.with::<InitAntiCheat>()
+		.with::<CheckKeys>()
+		.with_handler::<ApplyAntiCheatPolicy>()
+}
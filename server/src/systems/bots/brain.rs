@@ -0,0 +1,73 @@
+use specs::*;
+use types::*;
+
+/// Another player that a bot can see.
+#[derive(Copy, Clone, Debug)]
+pub struct BotTarget {
+	pub ent: Entity,
+	pub pos: Position,
+	pub team: Team,
+}
+
+/// Everything that a bot knows about the game
+/// when it decides what to do.
+pub struct BotView<'a> {
+	pub bot: Entity,
+	pub pos: Position,
+	pub rot: Rotation,
+	pub team: Team,
+	pub plane: Plane,
+	pub energy: Energy,
+	pub health: Health,
+	/// Where the game mode wants the bot to go.
+	pub goal: Option<Position>,
+	/// All other players that are currently alive.
+	pub players: &'a [BotTarget],
+	pub map: &'a Map,
+	pub config: &'a BotConfig,
+}
+
+impl<'a> BotView<'a> {
+	/// The closest player on another team that is
+	/// within `range` of the bot.
+	pub fn nearest_enemy(&self, range: Distance) -> Option<&'a BotTarget> {
+		let range2 = range * range;
+
+		self.players
+			.iter()
+			.filter(|p| p.team != self.team && p.ent != self.bot)
+			.map(|p| (p, (p.pos - self.pos).length2()))
+			.filter(|(_, dist2)| *dist2 < range2)
+			.min_by(|a, b| a.1.partial_cmp(&b.1).unwrap())
+			.map(|(p, _)| p)
+	}
+}
+
+/// Decides how a bot should move.
+///
+/// `think` is called once per frame for every bot
+/// that is alive and the returned keys are applied
+/// to the bot as if it had sent them.
+pub trait BotBrain: Send + Sync {
+	fn think(&mut self, view: &BotView) -> KeyState;
+}
+
+#[derive(Component)]
+pub struct Bot(pub Box<BotBrain>);
+
+/// A position that the game mode wants a bot to
+/// move towards (e.g. a flag in CTF).
+#[derive(Copy, Clone, Debug, Component)]
+pub struct BotGoal(pub Position);
+
+/// Creates the brains for new bots. Defaults to
+/// [`DefaultBrain`][0].
+///
+/// [0]: ::systems::bots::DefaultBrain
+pub struct BotBrainFactory(pub Box<Fn() -> Box<BotBrain> + Send + Sync>);
+
+impl Default for BotBrainFactory {
+	fn default() -> Self {
+		BotBrainFactory(Box::new(|| Box::new(super::DefaultBrain::default())))
+	}
+}
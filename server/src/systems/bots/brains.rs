@@ -0,0 +1,244 @@
+use rand::{self, Closed01};
+
+use std::f32::consts::PI;
+
+use types::*;
+
+use super::{BotBrain, BotView};
+
+/// How far off (in radians) the bot can be from
+/// facing its target before it starts turning.
+const TURN_TOLERANCE: f32 = 0.1;
+/// How far off the bot can be from facing an
+/// enemy when firing.
+const AIM_TOLERANCE: f32 = 0.2;
+/// Distance at which a waypoint counts as reached.
+const WAYPOINT_RADIUS: f32 = 300.0;
+
+/// Angle between the direction that the bot is
+/// facing and the direction towards `target`. A
+/// positive angle means the target is to the right.
+fn angle_to(view: &BotView, target: Position) -> f32 {
+	let dir = target - view.pos;
+	// Rotation 0 faces up (towards negative y)
+	// and increases clockwise.
+	let desired = dir.x.inner().atan2(-dir.y.inner());
+	let mut diff = desired - view.rot.inner();
+
+	while diff > PI {
+		diff -= 2.0 * PI;
+	}
+	while diff < -PI {
+		diff += 2.0 * PI;
+	}
+
+	diff
+}
+
+/// Keys that fly the bot towards `target`.
+fn steer(view: &BotView, target: Position) -> KeyState {
+	let diff = angle_to(view, target);
+
+	KeyState {
+		up: true,
+		left: diff < -TURN_TOLERANCE,
+		right: diff > TURN_TOLERANCE,
+		..Default::default()
+	}
+}
+
+/// Fly between random points on the map.
+#[derive(Default)]
+pub struct Wander {
+	waypoint: Option<Position>,
+}
+
+impl Wander {
+	fn random_waypoint(view: &BotView) -> Position {
+		let Closed01(x) = rand::random::<Closed01<f32>>();
+		let Closed01(y) = rand::random::<Closed01<f32>>();
+
+		// Stay away from the edges of the map
+		let bounds = view.map.bounds;
+		Position::new(bounds.x * (x * 1.6 - 0.8), bounds.y * (y * 1.6 - 0.8))
+	}
+}
+
+impl BotBrain for Wander {
+	fn think(&mut self, view: &BotView) -> KeyState {
+		let reached = match self.waypoint {
+			Some(pos) => (pos - view.pos).length2().inner() < WAYPOINT_RADIUS * WAYPOINT_RADIUS,
+			None => true,
+		};
+
+		if reached {
+			self.waypoint = Some(Self::random_waypoint(view));
+		}
+
+		steer(view, self.waypoint.unwrap())
+	}
+}
+
+/// Go after the nearest enemy and shoot at them.
+/// Does nothing if there are no enemies nearby.
+#[derive(Default)]
+pub struct Chase;
+
+impl BotBrain for Chase {
+	fn think(&mut self, view: &BotView) -> KeyState {
+		let target = match view.nearest_enemy(view.config.chase_range) {
+			Some(target) => target,
+			None => return KeyState::default(),
+		};
+
+		let mut keys = steer(view, target.pos);
+		let dist2 = (target.pos - view.pos).length2();
+		let range = view.config.fire_range;
+		keys.fire = dist2 < range * range && angle_to(view, target.pos).abs() < AIM_TOLERANCE;
+
+		keys
+	}
+}
+
+/// Head for the goal set by the game mode if there
+/// is one, otherwise chase nearby enemies or wander
+/// around. Bots will shoot at enemies that get in
+/// their way in either case.
+#[derive(Default)]
+pub struct DefaultBrain {
+	wander: Wander,
+	chase: Chase,
+}
+
+impl BotBrain for DefaultBrain {
+	fn think(&mut self, view: &BotView) -> KeyState {
+		let chase = self.chase.think(view);
+
+		match view.goal {
+			Some(goal) => KeyState {
+				fire: chase.fire,
+				..steer(view, goal)
+			},
+			None if chase.up => chase,
+			None => self.wander.think(view),
+		}
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	use specs::*;
+
+	use systems::bots::BotTarget;
+
+	fn view<'a>(
+		bot: Entity,
+		players: &'a [BotTarget],
+		map: &'a Map,
+		config: &'a BotConfig,
+	) -> BotView<'a> {
+		// Bot at the origin facing up
+		BotView {
+			bot,
+			pos: Position::new(0.0, 0.0),
+			rot: Rotation::new(0.0),
+			team: Team(1),
+			plane: Plane::Predator,
+			energy: Energy::new(1.0),
+			health: Health::new(1.0),
+			goal: None,
+			players,
+			map,
+			config,
+		}
+	}
+
+	#[test]
+	fn angle_to_target() {
+		let mut world = World::new();
+		let bot = world.create_entity().build();
+		let map = Map::default();
+		let config = BotConfig::default();
+		let view = view(bot, &[], &map, &config);
+
+		let ahead = angle_to(&view, Position::new(0.0, -100.0));
+		let right = angle_to(&view, Position::new(100.0, 0.0));
+		let left = angle_to(&view, Position::new(-100.0, 0.0));
+		let behind = angle_to(&view, Position::new(0.0, 100.0));
+
+		assert!(ahead.abs() < 1e-5);
+		assert!((right - PI / 2.0).abs() < 1e-5);
+		assert!((left + PI / 2.0).abs() < 1e-5);
+		assert!((behind.abs() - PI).abs() < 1e-5);
+	}
+
+	#[test]
+	fn angle_to_wraps() {
+		let mut world = World::new();
+		let bot = world.create_entity().build();
+		let map = Map::default();
+		let config = BotConfig::default();
+		let mut view = view(bot, &[], &map, &config);
+
+		// Facing down and slightly to the right, a target
+		// down and slightly to the left is a small turn to
+		// the right, not most of a circle to the left.
+		view.rot = Rotation::new(PI - 0.1);
+		let diff = angle_to(&view, Position::new(-10.0, 100.0));
+
+		assert!(diff > 0.0 && diff < 0.3, "{}", diff);
+	}
+
+	#[test]
+	fn steer_towards_target() {
+		let mut world = World::new();
+		let bot = world.create_entity().build();
+		let map = Map::default();
+		let config = BotConfig::default();
+		let view = view(bot, &[], &map, &config);
+
+		let ahead = steer(&view, Position::new(0.0, -100.0));
+		assert!(ahead.up && !ahead.left && !ahead.right);
+
+		let right = steer(&view, Position::new(100.0, -10.0));
+		assert!(right.up && right.right && !right.left);
+
+		let left = steer(&view, Position::new(-100.0, -10.0));
+		assert!(left.up && left.left && !left.right);
+	}
+
+	#[test]
+	fn nearest_enemy_in_range() {
+		let mut world = World::new();
+		let bot = world.create_entity().build();
+		let mut target = |pos: Position, team| BotTarget {
+			ent: world.create_entity().build(),
+			pos,
+			team: Team(team),
+		};
+		let players = [
+			// The bot itself
+			BotTarget {
+				ent: bot,
+				pos: Position::new(0.0, 0.0),
+				team: Team(2),
+			},
+			// Closest, but on the same team
+			target(Position::new(10.0, 0.0), 1),
+			target(Position::new(500.0, 0.0), 2),
+			target(Position::new(0.0, 200.0), 2),
+			// Out of range
+			target(Position::new(0.0, 5000.0), 2),
+		];
+		let map = Map::default();
+		let config = BotConfig::default();
+		let view = view(bot, &players, &map, &config);
+
+		let nearest = view.nearest_enemy(Distance::new(1000.0)).unwrap();
+		assert_eq!(nearest.ent, players[3].ent);
+
+		assert!(view.nearest_enemy(Distance::new(100.0)).is_none());
+	}
+}
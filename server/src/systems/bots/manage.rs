@@ -0,0 +1,172 @@
+use specs::*;
+use types::*;
+
+use SystemInfo;
+
+use component::channel::*;
+use component::collection::PlayerNames;
+use component::counter::PlayersGame;
+use component::event::{PlayerJoin, PlayerLeave as EvtPlayerLeave};
+use component::flag::{IsBot, IsPlayer};
+use consts::spectate::SPECTATOR_TEAM;
+use protocol::server::PlayerLeave;
+use protocol::FlagCode;
+use systems::PacketHandler;
+
+use GameMode;
+
+use super::*;
+
+/// Add and remove bots so that the number of bots
+/// matches the [`BotConfig`].
+///
+/// At most one bot joins or leaves per frame.
+#[derive(Default)]
+pub struct ManageBots {
+	next_id: usize,
+}
+
+#[derive(SystemData)]
+pub struct ManageBotsData<'a> {
+	entities: Entities<'a>,
+	config: Read<'a, Config>,
+	conns: Read<'a, Connections>,
+	factory: Read<'a, BotBrainFactory>,
+	player_names: Write<'a, PlayerNames>,
+	players: Write<'a, PlayersGame>,
+	gamemode: GameModeWriter<'a, GameMode>,
+
+	onjoin: Write<'a, OnPlayerJoin>,
+	onleave: Write<'a, OnPlayerLeave>,
+
+	bots: WriteStorage<'a, Bot>,
+	is_bot: WriteStorage<'a, IsBot>,
+	is_player: ReadStorage<'a, IsPlayer>,
+	team: ReadStorage<'a, Team>,
+}
+
+impl ManageBots {
+	/// How many bots there should be when there are
+	/// `humans` real players in the game.
+	fn target_bots(config: &BotConfig, humans: usize) -> usize {
+		if config.fill {
+			config.count.saturating_sub(humans)
+		} else {
+			config.count
+		}
+	}
+
+	fn add_bot<'a>(&mut self, data: &mut ManageBotsData<'a>) {
+		let entity = data.entities.create();
+
+		if entity.id() > 0xFFFF {
+			error!("Entity created with id greater than 0xFFFF, not adding a bot");
+			data.entities.delete(entity).unwrap();
+			return;
+		}
+
+		let team = data.gamemode.get_mut().assign_team(entity);
+		let plane = data.gamemode.get_mut().assign_plane(entity, team);
+
+		let mut name;
+		loop {
+			self.next_id += 1;
+			name = format!("Bot {}", self.next_id);
+
+			if !data.player_names.0.contains(&name) {
+				break;
+			}
+		}
+		data.player_names.0.insert(name.clone(), entity);
+
+		data.is_bot.insert(entity, IsBot).unwrap();
+		data.bots.insert(entity, Bot((data.factory.0)())).unwrap();
+
+		info!("Bot {} joined with id {}", name, entity.id());
+
+		data.onjoin.single_write(PlayerJoin {
+			id: entity,
+			level: Level(0),
+			name: Name(name),
+			session: Session(None),
+			flag: FlagCode::UnitedNations,
+			team,
+			plane,
+			// Doesn't correspond to any actual connection
+			conn: ConnectionId::new(),
+			account: None,
+		});
+	}
+
+	fn remove_bot<'a>(data: &mut ManageBotsData<'a>, ent: Entity) {
+		data.players.0 -= 1;
+
+		data.conns.send_to_all(PlayerLeave { id: ent.into() });
+		data.onleave.single_write(EvtPlayerLeave(ent));
+		data.entities.delete(ent).unwrap();
+
+		info!("Bot {:?} left", ent);
+	}
+}
+
+impl<'a> System<'a> for ManageBots {
+	type SystemData = ManageBotsData<'a>;
+
+	fn run(&mut self, mut data: Self::SystemData) {
+		let bots = (&*data.entities, &data.is_bot)
+			.join()
+			.map(|(ent, _)| ent)
+			.collect::<Vec<_>>();
+
+		let target = {
+			let is_bot = &data.is_bot;
+			// Players that joined as spectators aren't taking
+			// up a spot on any team, so bots shouldn't leave
+			// to make room for them.
+			let humans = (&*data.entities, &data.is_player, &data.team)
+				.join()
+				.filter(|(ent, ..)| is_bot.get(*ent).is_none())
+				.filter(|(_, _, &team)| team != SPECTATOR_TEAM)
+				.count();
+
+			Self::target_bots(&data.config.bots, humans)
+		};
+
+		if bots.len() < target {
+			self.add_bot(&mut data);
+		} else if bots.len() > target {
+			Self::remove_bot(&mut data, bots[0]);
+		}
+	}
+}
+
+impl SystemInfo for ManageBots {
+	type Dependencies = PacketHandler;
+
+	fn name() -> &'static str {
+		concat!(module_path!(), "::", line!())
+	}
+
+	fn new() -> Self {
+		Self::default()
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	#[test]
+	fn fill_count() {
+		let mut config = BotConfig::default();
+		config.count = 10;
+
+		assert_eq!(ManageBots::target_bots(&config, 3), 10);
+
+		config.fill = true;
+		assert_eq!(ManageBots::target_bots(&config, 0), 10);
+		assert_eq!(ManageBots::target_bots(&config, 3), 7);
+		assert_eq!(ManageBots::target_bots(&config, 10), 0);
+		assert_eq!(ManageBots::target_bots(&config, 15), 0);
+	}
+}
@@ -0,0 +1,24 @@
+//! Bots that are run within the server.
+//!
+//! Bots are regular players (they have `IsPlayer`,
+//! a `Plane`, a `Team`, etc.) which join and leave
+//! through the same `PlayerJoin` and `PlayerLeave`
+//! events that real players do. Instead of having a
+//! connection, they are controlled by a [`BotBrain`]
+//! which sets their `KeyState` every frame.
+//!
+//! Game modes can steer bots towards objectives by
+//! giving them a [`BotGoal`].
+
+mod brain;
+mod brains;
+mod manage;
+mod register;
+mod update;
+
+pub use self::brain::{Bot, BotBrain, BotBrainFactory, BotGoal, BotTarget, BotView};
+pub use self::brains::{Chase, DefaultBrain, Wander};
+pub use self::manage::ManageBots;
+pub use self::update::UpdateBots;
+
+pub use self::register::register;
@@ -0,0 +1,6 @@
+use super::*;
+use Builder;
+
+pub fn register<'a, 'b>(builder: Builder<'a, 'b>) -> Builder<'a, 'b> {
+	builder.with::<ManageBots>().with::<UpdateBots>()
+}
@@ -0,0 +1,120 @@
+use specs::*;
+use types::systemdata::*;
+use types::*;
+
+use SystemInfo;
+
+use component::flag::{IsBot, IsPlayer};
+use component::time::{LastKeyTime, ThisFrame};
+use systems::PositionUpdate;
+
+use super::*;
+
+/// Let each bot's brain decide which keys it
+/// is pressing.
+#[derive(Default)]
+pub struct UpdateBots;
+
+#[derive(SystemData)]
+pub struct UpdateBotsData<'a> {
+	entities: Entities<'a>,
+	config: Read<'a, Config>,
+	map: Read<'a, Map>,
+	this_frame: Read<'a, ThisFrame>,
+	is_alive: IsAlive<'a>,
+
+	bots: WriteStorage<'a, Bot>,
+	goals: ReadStorage<'a, BotGoal>,
+	keystate: WriteStorage<'a, KeyState>,
+	last_key: WriteStorage<'a, LastKeyTime>,
+
+	pos: ReadStorage<'a, Position>,
+	rot: ReadStorage<'a, Rotation>,
+	team: ReadStorage<'a, Team>,
+	plane: ReadStorage<'a, Plane>,
+	energy: ReadStorage<'a, Energy>,
+	health: ReadStorage<'a, Health>,
+	is_player: ReadStorage<'a, IsPlayer>,
+	is_bot: ReadStorage<'a, IsBot>,
+}
+
+impl<'a> System<'a> for UpdateBots {
+	type SystemData = UpdateBotsData<'a>;
+
+	fn run(&mut self, mut data: Self::SystemData) {
+		let players = (
+			&*data.entities,
+			&data.pos,
+			&data.team,
+			data.is_player.mask() & data.is_alive.mask(),
+		)
+			.join()
+			.map(|(ent, pos, team, ..)| BotTarget {
+				ent,
+				pos: *pos,
+				team: *team,
+			})
+			.collect::<Vec<_>>();
+
+		let ref config = data.config.bots;
+		let ref map = *data.map;
+		let this_frame = data.this_frame.0;
+		let goals = &data.goals;
+		let last_key = &mut data.last_key;
+
+		(
+			&*data.entities,
+			&mut data.bots,
+			&mut data.keystate,
+			&data.pos,
+			&data.rot,
+			&data.team,
+			&data.plane,
+			&data.energy,
+			&data.health,
+			data.is_bot.mask() & data.is_alive.mask(),
+		)
+			.join()
+			.for_each(
+				|(ent, bot, keystate, pos, rot, team, plane, energy, health, ..)| {
+					let view = BotView {
+						bot: ent,
+						pos: *pos,
+						rot: *rot,
+						team: *team,
+						plane: *plane,
+						energy: *energy,
+						health: *health,
+						goal: goals.get(ent).map(|x| x.0),
+						players: &players,
+						map,
+						config,
+					};
+
+					let mut keys = bot.0.think(&view);
+					// These are controlled by the server
+					keys.stealthed = keystate.stealthed;
+					keys.flagspeed = keystate.flagspeed;
+
+					// Only touch the keystate when it changes since
+					// every modification sends out a PlayerUpdate.
+					if keys != *keystate {
+						*keystate = keys;
+						last_key.insert(ent, LastKeyTime(this_frame)).unwrap();
+					}
+				},
+			);
+	}
+}
+
+impl SystemInfo for UpdateBots {
+	type Dependencies = (PositionUpdate, ManageBots);
+
+	fn name() -> &'static str {
+		concat!(module_path!(), "::", line!())
+	}
+
+	fn new() -> Self {
+		Self::default()
+	}
+}
@@ -43,35 +43,41 @@ impl<'a> EventHandler<'a> for MissileExplodeSystem {
 			missile_ent = c1.ent;
 		}
 
-		// Remove a bunch of components that are used to
-		// recognize missiles lazily (they will get
-		// removed at the end of the frame)
-		data.lazy.remove::<Position>(missile_ent);
-		data.lazy.remove::<Mob>(missile_ent);
-		data.lazy.remove::<IsMissile>(missile_ent);
-		data.lazy.remove::<Velocity>(missile_ent);
-		data.lazy.remove::<Team>(missile_ent);
-		data.lazy.remove::<PlayerRef>(missile_ent);
-
-		data.dispatch
-			.run_delayed(*ID_REUSE_TIME, move |inst| TimerEvent {
-				ty: *DELETE_ENTITY,
-				instant: inst,
-				data: Some(Box::new(missile_ent)),
-			});
-
-		let pos = *try_get!(missile_ent, data.pos);
-
-		let packet = MobDespawnCoords {
-			id: missile_ent.into(),
-			ty: *try_get!(missile_ent, data.types),
-			pos,
-		};
-
-		data.conns.send_to_visible(pos, packet);
+		explode_missile(missile_ent, data);
 	}
 }
 
+/// Despawn a missile and show the explosion to
+/// everyone that can see it.
+pub(super) fn explode_missile<'a>(missile_ent: Entity, data: &MissileExplodeSystemData<'a>) {
+	// Remove a bunch of components that are used to
+	// recognize missiles lazily (they will get
+	// removed at the end of the frame)
+	data.lazy.remove::<Position>(missile_ent);
+	data.lazy.remove::<Mob>(missile_ent);
+	data.lazy.remove::<IsMissile>(missile_ent);
+	data.lazy.remove::<Velocity>(missile_ent);
+	data.lazy.remove::<Team>(missile_ent);
+	data.lazy.remove::<PlayerRef>(missile_ent);
+
+	data.dispatch
+		.run_delayed(*ID_REUSE_TIME, move |inst| TimerEvent {
+			ty: *DELETE_ENTITY,
+			instant: inst,
+			data: Some(Box::new(missile_ent)),
+		});
+
+	let pos = *try_get!(missile_ent, data.pos);
+
+	let packet = MobDespawnCoords {
+		id: missile_ent.into(),
+		ty: *try_get!(missile_ent, data.types),
+		pos,
+	};
+
+	data.conns.send_to_visible(pos, packet);
+}
+
 use super::MissileTerrainCollisionSystem;
 use dispatch::SystemInfo;
 
@@ -27,12 +27,17 @@ pub struct GenPlaneGridData<'a> {
 
 	is_player: ReadStorage<'a, IsPlayer>,
 	is_alive: IsAlive<'a>,
+	config: Read<'a, Config>,
 }
 
 impl<'a> System<'a> for GenPlaneGrid {
 	type SystemData = GenPlaneGridData<'a>;
 
 	fn run(&mut self, mut data: Self::SystemData) {
+		// With friendly fire every plane gets its own
+		// layer so that missiles can hit teammates.
+		let friendly_fire = data.config.friendly_fire.enabled();
+
 		let it = (
 			&*data.ent,
 			&data.pos,
@@ -43,13 +48,19 @@ impl<'a> System<'a> for GenPlaneGrid {
 		)
 			.join()
 			.map(|(ent, &pos, &rot, &team, &plane, ..)| {
+				let layer = if friendly_fire {
+					ent.id() as u16
+				} else {
+					team.0
+				};
+
 				PLANE_HIT_CIRCLES[&plane].iter().map(move |hc| {
 					let offset = hc.offset.rotate(rot);
 
 					HitCircle {
 						pos: pos + offset,
 						rad: hc.radius,
-						layer: team.0,
+						layer,
 						ent: ent,
 					}
 				})
@@ -0,0 +1,93 @@
+use specs::*;
+
+use types::systemdata::IsAlive;
+use types::*;
+
+use std::time::Duration;
+
+use component::flag::IsPlayer;
+use component::time::ThisFrame;
+
+/// Length of a single frame of the game loop.
+const FRAME_TIME: Duration = Duration::from_micros(16667);
+
+/// Record the position of every player each frame
+/// so that missile hits can be checked against
+/// where the shooter saw them.
+#[derive(Default)]
+pub struct RecordPositionHistory;
+
+#[derive(SystemData)]
+pub struct RecordPositionHistoryData<'a> {
+	entities: Entities<'a>,
+	config: Read<'a, Config>,
+	this_frame: Read<'a, ThisFrame>,
+
+	pos: ReadStorage<'a, Position>,
+	rot: ReadStorage<'a, Rotation>,
+	history: WriteStorage<'a, PositionHistory>,
+	is_player: ReadStorage<'a, IsPlayer>,
+	is_alive: IsAlive<'a>,
+}
+
+impl<'a> System<'a> for RecordPositionHistory {
+	type SystemData = RecordPositionHistoryData<'a>;
+
+	fn run(&mut self, mut data: Self::SystemData) {
+		let ref config = data.config.lag_compensation;
+		if !config.enabled {
+			data.history.clear();
+			return;
+		}
+
+		let now = data.this_frame.0;
+		let frames = PositionHistory::frames_for(config.max_rewind, FRAME_TIME);
+
+		// A player that just respawned shouldn't be hit
+		// at the place where they died.
+		for (history, ..) in (
+			&mut data.history,
+			data.is_alive.is_dead.mask() | data.is_alive.is_spec.mask(),
+		)
+			.join()
+		{
+			history.clear();
+		}
+
+		for (ent, &pos, &rot, ..) in (
+			&*data.entities,
+			&data.pos,
+			&data.rot,
+			data.is_player.mask() & data.is_alive.mask(),
+		)
+			.join()
+		{
+			let needs_history = match data.history.get(ent) {
+				Some(history) => history.capacity() != frames,
+				None => true,
+			};
+			if needs_history {
+				data.history
+					.insert(ent, PositionHistory::new(frames))
+					.unwrap();
+			}
+
+			try_get!(ent, mut data.history).record(now, pos, rot);
+		}
+	}
+}
+
+use dispatch::SystemInfo;
+use systems::PositionUpdate;
+
+impl SystemInfo for RecordPositionHistory {
+	type Dependencies = PositionUpdate;
+
+	fn name() -> &'static str {
+		concat!(module_path!(), "::", line!())
+	}
+
+	fn new() -> Self {
+		Self::default()
+	}
+}
@@ -0,0 +1,77 @@
+use specs::*;
+
+use types::collision::Collision;
+
+use std::sync::atomic::Ordering;
+
+use component::counter::Interceptions;
+use component::event::*;
+use component::flag::HitMarker;
+use component::reference::PlayerRef;
+use consts::MISSILE_INTERCEPTIONS;
+use utils::{EventHandler, EventHandlerTypeProvider};
+
+use super::explode::{explode_missile, MissileExplodeSystemData};
+
+/// Blow up both missiles when two missiles from
+/// different teams collide.
+#[derive(Default)]
+pub struct MissileInterceptSystem;
+
+#[derive(SystemData)]
+pub struct MissileInterceptSystemData<'a> {
+	explode: MissileExplodeSystemData<'a>,
+	entities: Entities<'a>,
+
+	hitmarker: WriteStorage<'a, HitMarker>,
+	owner: ReadStorage<'a, PlayerRef>,
+	interceptions: WriteStorage<'a, Interceptions>,
+}
+
+impl EventHandlerTypeProvider for MissileInterceptSystem {
+	type Event = MissileMissileCollision;
+}
+
+impl<'a> EventHandler<'a> for MissileInterceptSystem {
+	type SystemData = MissileInterceptSystemData<'a>;
+
+	fn on_event(&mut self, evt: &MissileMissileCollision, data: &mut Self::SystemData) {
+		let Collision(c1, c2) = evt.0;
+
+		// Either missile might have already hit a player
+		// or another missile during this frame.
+		for ent in [c1.ent, c2.ent].iter() {
+			if !data.entities.is_alive(*ent) || data.hitmarker.get(*ent).is_some() {
+				return;
+			}
+		}
+
+		for ent in [c1.ent, c2.ent].iter() {
+			data.hitmarker.insert(*ent, HitMarker {}).unwrap();
+
+			let owner = data.owner.get(*ent).map(|x| x.0);
+			if let Some(count) = owner.and_then(|x| data.interceptions.get_mut(x)) {
+				count.0 += 1;
+			}
+
+			explode_missile(*ent, &data.explode);
+		}
+
+		MISSILE_INTERCEPTIONS.fetch_add(1, Ordering::Relaxed);
+	}
+}
+
+use super::MissileMissileCollisionSystem;
+use dispatch::SystemInfo;
+
+impl SystemInfo for MissileInterceptSystem {
+	type Dependencies = MissileMissileCollisionSystem;
+
+	fn name() -> &'static str {
+		concat!(module_path!(), "::", line!())
+	}
+
+	fn new() -> Self {
+		Self::default()
+	}
+}
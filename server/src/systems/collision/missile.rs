@@ -36,7 +36,8 @@ impl<'a> System<'a> for MissileTerrainCollisionSystem {
 	fn setup(&mut self, res: &mut Resources) {
 		Self::SystemData::setup(res);
 
-		self.terrain = Terrain::from_default(&*res.fetch::<EntitiesRes>());
+		Read::<Map>::setup(res);
+		self.terrain = Terrain::from_map(&*res.fetch::<Map>(), &*res.fetch::<EntitiesRes>());
 
 		// Hopefully 1000 collision events is enough during
 		// each 16ms frame. If not, this number should be
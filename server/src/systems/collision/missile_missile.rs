@@ -0,0 +1,94 @@
+use specs::prelude::*;
+
+use types::collision::*;
+use types::*;
+
+use component::channel::OnMissileMissileCollision;
+use component::event::MissileMissileCollision;
+use component::flag::IsMissile;
+
+/// Collide missiles with the missiles of other
+/// teams. This only runs if `missile_collisions`
+/// is enabled in the config.
+#[derive(Default)]
+pub struct MissileMissileCollisionSystem;
+
+#[derive(SystemData)]
+pub struct MissileMissileCollisionSystemData<'a> {
+	pub entities: Entities<'a>,
+	pub channel: Write<'a, OnMissileMissileCollision>,
+	pub config: Read<'a, Config>,
+
+	pub pos: ReadStorage<'a, Position>,
+	pub mob: ReadStorage<'a, Mob>,
+	pub team: ReadStorage<'a, Team>,
+	pub flag: ReadStorage<'a, IsMissile>,
+}
+
+impl MissileMissileCollisionSystem {
+	pub fn new() -> Self {
+		Self::default()
+	}
+}
+
+impl<'a> System<'a> for MissileMissileCollisionSystem {
+	type SystemData = MissileMissileCollisionSystemData<'a>;
+
+	fn setup(&mut self, res: &mut Resources) {
+		Self::SystemData::setup(res);
+
+		res.insert::<OnMissileMissileCollision>(OnMissileMissileCollision::with_capacity(1000));
+	}
+
+	fn run(&mut self, mut data: Self::SystemData) {
+		if !data.config.missile_collisions {
+			return;
+		}
+
+		let circles = (
+			&*data.entities,
+			&data.pos,
+			&data.mob,
+			&data.team,
+			&data.flag,
+		)
+			.join()
+			.flat_map(|(ent, &pos, mob, &team, _)| {
+				COLLIDERS[mob].iter().map(move |(offset, rad)| HitCircle {
+					pos: pos + *offset,
+					rad: *rad,
+					layer: team.0,
+					ent: ent,
+				})
+			})
+			.collect::<Vec<HitCircle>>();
+
+		// Every collision would be found twice, once
+		// for each missile. Only keep the one where the
+		// first missile has the lower id.
+		let grid = Grid::new(circles.clone());
+		let collisions = grid
+			.collide(circles.into_iter())
+			.into_iter()
+			.filter(|Collision(c1, c2)| c1.ent.id() < c2.ent.id())
+			.map(|x| MissileMissileCollision(x))
+			.collect::<Vec<MissileMissileCollision>>();
+
+		data.channel.iter_write(collisions.into_iter());
+	}
+}
+
+use dispatch::SystemInfo;
+use systems::PositionUpdate;
+
+impl SystemInfo for MissileMissileCollisionSystem {
+	type Dependencies = PositionUpdate;
+
+	fn name() -> &'static str {
+		concat!(module_path!(), "::", line!())
+	}
+
+	fn new() -> Self {
+		Self::new()
+	}
+}
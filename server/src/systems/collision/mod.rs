@@ -1,8 +1,12 @@
 mod bounce;
 mod explode;
+mod history;
+mod intercept;
 mod missile;
+mod missile_missile;
 mod plane;
 mod player_missile;
+mod powerup;
 mod upgrade;
 
 mod gen_plane_grid;
@@ -14,7 +18,44 @@ pub use self::register::register;
 pub use self::bounce::BounceSystem;
 pub use self::explode::MissileExplodeSystem;
 pub use self::gen_plane_grid::GenPlaneGrid;
+pub use self::history::RecordPositionHistory;
+pub use self::intercept::MissileInterceptSystem;
 pub use self::missile::MissileTerrainCollisionSystem;
+pub use self::missile_missile::MissileMissileCollisionSystem;
 pub use self::plane::PlaneCollisionSystem;
 pub use self::player_missile::PlayerMissileCollisionSystem;
+pub use self::powerup::PlayerPowerupCollisionSystem;
 pub use self::upgrade::PlayerUpgradeCollisionSystem;
+
+use fnv::FnvHashSet;
+use specs::*;
+
+use component::flag::IsPlayer;
+use types::collision::{Collision, HitCircle};
+
+/// Pair up players with the upgrades or powerup
+/// crates that they ran into, as `(player, mob)`.
+///
+/// Mobs that get picked up aren't removed until the
+/// end of the frame, so a mob that several players
+/// ran into only goes to the first one of them.
+pub(crate) fn pickups<'a, I>(
+	collisions: I,
+	entities: &EntitiesRes,
+	is_player: &ReadStorage<IsPlayer>,
+) -> Vec<(HitCircle, HitCircle)>
+where
+	I: IntoIterator<Item = &'a Collision>,
+{
+	let mut picked_up = FnvHashSet::default();
+
+	collisions
+		.into_iter()
+		.map(|&Collision(c1, c2)| match is_player.get(c1.ent) {
+			Some(_) => (c1, c2),
+			None => (c2, c1),
+		})
+		.filter(|(_, mob)| entities.is_alive(mob.ent))
+		.filter(|(_, mob)| picked_up.insert(mob.ent))
+		.collect()
+}
@@ -4,75 +4,277 @@ use specs::prelude::*;
 use types::collision::*;
 use types::*;
 
+use std::time::{Duration, Instant};
+
 use component::channel::*;
 use component::collision::PlaneGrid;
+use component::counter::PlayerPing;
 use component::event::PlayerMissileCollision;
 use component::flag::*;
+use component::reference::PlayerRef;
+use component::time::ThisFrame;
+
+use consts::config::PLANE_HIT_CIRCLES;
+
+/// How often the lag compensation stats get logged.
+const LOG_INTERVAL: Duration = Duration::from_secs(60);
+
+/// Counts of how often lag compensation changed
+/// whether a missile hit a player.
+#[derive(Default)]
+struct CompensationStats {
+	/// Hits that would have happened either way.
+	unchanged: usize,
+	/// Hits that only happened because the target
+	/// was rewound.
+	added: usize,
+	/// Hits against current positions that missed
+	/// once the target was rewound.
+	removed: usize,
+	last_log: Option<Instant>,
+}
 
-pub struct PlayerMissileCollisionSystem;
+pub struct PlayerMissileCollisionSystem {
+	stats: CompensationStats,
+}
 
 #[derive(SystemData)]
 pub struct PlayerMissileCollisionSystemData<'a> {
 	pub channel: Write<'a, OnPlayerMissileCollision>,
 	pub ent: Entities<'a>,
 	pub grid: Read<'a, PlaneGrid>,
+	pub this_frame: Read<'a, ThisFrame>,
 
 	pub mob: ReadStorage<'a, Mob>,
 	pub missile_flag: ReadStorage<'a, IsMissile>,
 	pub pos: ReadStorage<'a, Position>,
+	pub rot: ReadStorage<'a, Rotation>,
 	pub team: ReadStorage<'a, Team>,
+	pub owner: ReadStorage<'a, PlayerRef>,
+	pub config: Read<'a, Config>,
+
+	pub plane: ReadStorage<'a, Plane>,
+	pub ping: ReadStorage<'a, PlayerPing>,
+	pub history: ReadStorage<'a, PositionHistory>,
+	pub is_player: ReadStorage<'a, IsPlayer>,
 }
 
 impl PlayerMissileCollisionSystem {
 	pub fn new() -> Self {
-		Self {}
+		Self {
+			stats: CompensationStats::default(),
+		}
+	}
+
+	/// Collide missile hit circles against the positions
+	/// that players had at `time`.
+	///
+	/// Players are looked up in the plane grid, which has
+	/// their current positions. Any player that could have
+	/// been hit is within `reach` of where they are now.
+	fn collide_rewound(
+		data: &PlayerMissileCollisionSystemData,
+		missile: &[HitCircle],
+		time: Instant,
+		reach: Distance,
+		friendly_fire: bool,
+	) -> Vec<Collision> {
+		let layer = match missile.first() {
+			Some(hc) => hc.layer,
+			None => return vec![],
+		};
+		let mut out = vec![];
+
+		let candidates = missile
+			.iter()
+			.flat_map(|mc| {
+				data.grid.0.rough_collide(HitCircle {
+					rad: mc.rad + reach,
+					..*mc
+				})
+			})
+			.collect::<FnvHashSet<_>>();
+
+		for ent in candidates {
+			if data.is_player.get(ent).is_none() {
+				continue;
+			}
+
+			let (&pos, &rot, &team, plane) = match (
+				data.pos.get(ent),
+				data.rot.get(ent),
+				data.team.get(ent),
+				data.plane.get(ent),
+			) {
+				(Some(pos), Some(rot), Some(team), Some(plane)) => (pos, rot, team, plane),
+				_ => continue,
+			};
+
+			let player_layer = if friendly_fire { ent.id() as u16 } else { team.0 };
+			if player_layer == layer {
+				continue;
+			}
+
+			// Players that haven't been around for long enough
+			// are checked at their current position.
+			let (pos, rot) = match data.history.get(ent).and_then(|x| x.at(time)) {
+				Some(entry) => (entry.pos, entry.rot),
+				None => (pos, rot),
+			};
+
+			for hc in PLANE_HIT_CIRCLES[plane].iter() {
+				let target = HitCircle {
+					pos: pos + hc.offset.rotate(rot),
+					rad: hc.radius,
+					layer: player_layer,
+					ent,
+				};
+
+				for mc in missile {
+					let r = mc.rad + target.rad;
+
+					if (mc.pos - target.pos).length2() < r * r {
+						out.push(Collision(*mc, target));
+					}
+				}
+			}
+		}
+
+		out
+	}
+
+	fn log_stats(&mut self, now: Instant) {
+		let last = *self.stats.last_log.get_or_insert(now);
+		if now - last < LOG_INTERVAL {
+			return;
+		}
+
+		let stats = &mut self.stats;
+		let total = stats.unchanged + stats.added + stats.removed;
+		if total != 0 {
+			info!(
+				target: "airmash:lag_compensation",
+				"Lag compensation changed {} of {} missile hits ({} added, {} removed)",
+				stats.added + stats.removed,
+				total,
+				stats.added,
+				stats.removed
+			);
+		}
+
+		*stats = CompensationStats {
+			last_log: Some(now),
+			..Default::default()
+		};
 	}
 }
 
-impl<'a> System<'a> for PlayerMissileCollisionSystem {
-	type SystemData = PlayerMissileCollisionSystemData<'a>;
+/// Fastest that any plane can fly with the given
+/// config, including boosting, upgrades and powerups.
+fn max_plane_speed(config: &Config) -> Speed {
+	let upgrade = config
+		.upgrades
+		.speed
+		.factor
+		.iter()
+		.cloned()
+		.fold(1.0, f32::max);
 
-	fn run(&mut self, data: Self::SystemData) {
-		let Self::SystemData {
-			mut channel,
-			ent,
+	let speed = config
+		.planes
+		.0
+		.values()
+		.map(|info| {
+			let speed = info.max_speed.inner() * info.boost_factor.max(1.0) * upgrade;
+			let speed = speed * info.inferno_factor.max(1.0);
 
-			grid,
-			pos,
-			team,
+			speed.max(info.flag_speed.inner())
+		})
+		.fold(0.0, f32::max);
 
-			mob,
-			missile_flag,
-		} = data;
+	Speed::new(speed)
+}
 
-		let grid = &grid.0;
+impl<'a> System<'a> for PlayerMissileCollisionSystem {
+	type SystemData = PlayerMissileCollisionSystemData<'a>;
 
-		let collisions = (&*ent, &pos, &team, &mob, &missile_flag)
+	fn run(&mut self, mut data: Self::SystemData) {
+		let grid = &data.grid.0;
+		let friendly_fire = data.config.friendly_fire.enabled();
+		let ref lagconf = data.config.lag_compensation;
+		let now = data.this_frame.0;
+		let max_speed = max_plane_speed(&data.config);
+
+		let results = (&*data.ent, &data.pos, &data.team, &data.mob, &data.missile_flag)
 			.par_join()
 			.map(|(ent, &pos, &team, &mob, _)| {
+				let owner = data.owner.get(ent).map(|x| x.0);
+
+				// Planes are on their own layer when friendly
+				// fire is enabled, missiles then only need to
+				// skip the player that fired them.
+				let layer = match owner {
+					Some(owner) if friendly_fire => owner.id() as u16,
+					_ => team.0,
+				};
+
 				let it = COLLIDERS[&mob].iter().map(move |(offset, rad)| HitCircle {
 					pos: pos + *offset,
 					rad: *rad,
-					layer: team.0,
+					layer,
 					ent: ent,
 				});
 
-				grid.collide(it)
+				let current = grid.collide(it.clone());
+
+				let rewind = owner
+					.and_then(|x| data.ping.get(x))
+					.map(|x| lagconf.rewind(x.0))
+					.unwrap_or_default();
+
+				if !lagconf.enabled || rewind == Duration::default() {
+					return (current, (0, 0, 0));
+				}
+
+				let missile = it.collect::<Vec<_>>();
+				let reach = max_speed * Time::from(rewind);
+				let rewound =
+					Self::collide_rewound(&data, &missile, now - rewind, reach, friendly_fire);
+
+				let hit_now = current.iter().map(|x| x.1.ent).collect::<FnvHashSet<_>>();
+				let hit_then = rewound.iter().map(|x| x.1.ent).collect::<FnvHashSet<_>>();
+
+				let changes = (
+					hit_now.intersection(&hit_then).count(),
+					hit_then.difference(&hit_now).count(),
+					hit_now.difference(&hit_then).count(),
+				);
+
+				(rewound, changes)
 			})
-			.flatten()
-			.map(|x| PlayerMissileCollision(x))
-			.collect::<FnvHashSet<PlayerMissileCollision>>();
+			.collect::<Vec<_>>();
+
+		let mut collisions = FnvHashSet::default();
+		for (hits, (unchanged, added, removed)) in results {
+			collisions.extend(hits.into_iter().map(|x| PlayerMissileCollision(x)));
+
+			self.stats.unchanged += unchanged;
+			self.stats.added += added;
+			self.stats.removed += removed;
+		}
+
+		data.channel.iter_write(collisions.into_iter());
 
-		channel.iter_write(collisions.into_iter());
+		self.log_stats(now);
 	}
 }
 
 use dispatch::SystemInfo;
-use systems::collision::GenPlaneGrid;
+use systems::collision::{GenPlaneGrid, RecordPositionHistory};
 use systems::PositionUpdate;
 
 impl SystemInfo for PlayerMissileCollisionSystem {
-	type Dependencies = (PositionUpdate, GenPlaneGrid);
+	type Dependencies = (PositionUpdate, GenPlaneGrid, RecordPositionHistory);
 
 	fn name() -> &'static str {
 		concat!(module_path!(), "::", line!())
@@ -0,0 +1,87 @@
+use fnv::FnvHashSet;
+use specs::prelude::*;
+
+use Mob;
+
+use types::collision::*;
+use types::*;
+
+use component::channel::*;
+use component::collision::PlaneGrid;
+use component::event::PlayerPowerupCollision;
+use component::flag::*;
+
+pub struct PlayerPowerupCollisionSystem;
+
+#[derive(SystemData)]
+pub struct PlayerPowerupCollisionSystemData<'a> {
+	pub channel: Write<'a, OnPlayerPowerupCollision>,
+	pub ent: Entities<'a>,
+	pub grid: Read<'a, PlaneGrid>,
+
+	pub pos: ReadStorage<'a, Position>,
+	pub team: ReadStorage<'a, Team>,
+
+	pub mob: ReadStorage<'a, Mob>,
+	pub powerup_flag: ReadStorage<'a, IsPowerup>,
+}
+
+impl PlayerPowerupCollisionSystem {
+	pub fn new() -> Self {
+		Self {}
+	}
+}
+
+impl<'a> System<'a> for PlayerPowerupCollisionSystem {
+	type SystemData = PlayerPowerupCollisionSystemData<'a>;
+
+	fn run(&mut self, data: Self::SystemData) {
+		let Self::SystemData {
+			mut channel,
+			ent,
+			grid,
+
+			pos,
+			team,
+
+			mob,
+			powerup_flag,
+		} = data;
+
+		let grid = &grid.0;
+
+		let collisions = (&*ent, &pos, &team, &mob, powerup_flag.mask())
+			.par_join()
+			.map(|(ent, pos, team, mob, ..)| {
+				let it = COLLIDERS[mob].iter().map(|(offset, rad)| HitCircle {
+					pos: *pos + *offset,
+					rad: *rad,
+					layer: team.0,
+					ent: ent,
+				});
+
+				grid.collide(it)
+			})
+			.flatten()
+			.map(PlayerPowerupCollision)
+			.collect::<FnvHashSet<_>>();
+
+		channel.iter_write(collisions.into_iter());
+	}
+}
+
+use super::GenPlaneGrid;
+use dispatch::SystemInfo;
+use systems::PositionUpdate;
+
+impl SystemInfo for PlayerPowerupCollisionSystem {
+	type Dependencies = (PositionUpdate, GenPlaneGrid);
+
+	fn name() -> &'static str {
+		concat!(module_path!(), "::", line!())
+	}
+
+	fn new() -> Self {
+		Self::new()
+	}
+}
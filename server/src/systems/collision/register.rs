@@ -4,9 +4,13 @@ use dispatch::Builder;
 pub fn register<'a, 'b>(disp: Builder<'a, 'b>) -> Builder<'a, 'b> {
 	disp.with::<PlaneCollisionSystem>()
 		.with::<MissileTerrainCollisionSystem>()
+		.with::<MissileMissileCollisionSystem>()
+		.with::<RecordPositionHistory>()
 		.with::<PlayerMissileCollisionSystem>()
 		.with::<PlayerUpgradeCollisionSystem>()
+		.with::<PlayerPowerupCollisionSystem>()
 		.with_handler::<BounceSystem>()
 		.with_handler::<MissileExplodeSystem>()
+		.with_handler::<MissileInterceptSystem>()
 		.with::<GenPlaneGrid>()
 }
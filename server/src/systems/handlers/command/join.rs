@@ -0,0 +1,171 @@
+use specs::*;
+use types::*;
+
+use component::channel::*;
+use component::counter::PlayersGame;
+use component::event::*;
+use component::flag::*;
+use consts::spectate::SPECTATOR_TEAM;
+
+use protocol::server::{CommandReply, PlayerReteam, PlayerReteamPlayer};
+use protocol::CommandReplyType;
+
+use utils::{EventHandler, EventHandlerTypeProvider};
+
+use systems::handlers::game::on_join::InitTraits;
+use systems::PacketHandler;
+use SystemInfo;
+
+/// Handles the `/join` command.
+///
+/// Players that joined as spectators get put on
+/// a team by the game mode, which picks the team
+/// with the fewest players, and are respawned if
+/// the game mode allows it.
+#[derive(Default)]
+pub struct Join;
+
+#[derive(SystemData)]
+pub struct JoinData<'a> {
+	conns: Read<'a, Connections>,
+	channel: Write<'a, OnPlayerRespawn>,
+	players_game: Write<'a, PlayersGame>,
+	gamemode: GameModeWriter<'a, GameMode>,
+
+	teams: WriteStorage<'a, Team>,
+	is_spec: WriteStorage<'a, IsSpectating>,
+	is_dead: WriteStorage<'a, IsDead>,
+}
+
+/// Pick a team for a player that is currently on
+/// `team`. Returns `None` if they already have one.
+fn join_team(team: Option<&Team>, gamemode: &mut GameMode, player: Entity) -> Option<Team> {
+	if team != Some(&SPECTATOR_TEAM) {
+		return None;
+	}
+
+	Some(gamemode.assign_team(player))
+}
+
+impl EventHandlerTypeProvider for Join {
+	type Event = CommandEvent;
+}
+
+impl<'a> EventHandler<'a> for Join {
+	type SystemData = JoinData<'a>;
+
+	fn on_event(&mut self, evt: &CommandEvent, data: &mut Self::SystemData) {
+		let &(conn, ref packet) = evt;
+
+		let player = match data.conns.associated_player(conn) {
+			Some(p) => p,
+			None => return,
+		};
+
+		if packet.com != "join" {
+			return;
+		}
+
+		let team = match join_team(data.teams.get(player), data.gamemode.get_mut(), player) {
+			Some(team) => team,
+			None => {
+				data.conns.send_to(
+					conn,
+					CommandReply {
+						ty: CommandReplyType::ShowInPopup,
+						text: "You are already on a team".to_owned(),
+					},
+				);
+
+				return;
+			}
+		};
+
+		data.teams.insert(player, team).unwrap();
+		data.players_game.0 += 1;
+
+		info!("Spectator {:?} joined team {}", player, team.0);
+
+		data.conns.send_to_all(PlayerReteam {
+			players: vec![PlayerReteamPlayer {
+				id: player.into(),
+				team,
+			}],
+		});
+
+		// Players that are still in the 2s cooldown after
+		// going into spec, or that aren't allowed back in
+		// yet, can respawn normally later on.
+		if data.is_dead.get(player).is_some() || !data.gamemode.get().allow_respawn(player) {
+			return;
+		}
+
+		data.is_spec.remove(player);
+		// Prevent updates from happening until the actual
+		// respawn process is finished.
+		data.is_dead.insert(player, IsDead).unwrap();
+
+		data.channel.single_write(PlayerRespawn {
+			player,
+			prev_status: PlayerRespawnPrevStatus::Dead,
+		});
+	}
+}
+
+impl SystemInfo for Join {
+	type Dependencies = (PacketHandler, InitTraits);
+
+	fn name() -> &'static str {
+		concat!(module_path!(), "::", line!())
+	}
+
+	fn new() -> Self {
+		Self::default()
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	use protocol::GameType;
+
+	/// Puts everyone on the blue team
+	struct BlueOnly;
+
+	impl GameMode for BlueOnly {
+		fn assign_team(&mut self, _: Entity) -> Team {
+			Team(1)
+		}
+		fn spawn_pos(&mut self, _: Entity, _: Team) -> Position {
+			Position::default()
+		}
+		fn gametype(&self) -> GameType {
+			GameType::CTF
+		}
+		fn room(&self) -> String {
+			"test".to_owned()
+		}
+	}
+
+	#[test]
+	fn spectator_gets_a_team() {
+		let mut world = World::new();
+		let player = world.create_entity().build();
+		let mut gamemode = BlueOnly;
+
+		assert_eq!(
+			join_team(Some(&SPECTATOR_TEAM), &mut gamemode, player),
+			Some(Team(1))
+		);
+	}
+
+	#[test]
+	fn players_on_a_team_stay() {
+		let mut world = World::new();
+		let player = world.create_entity().build();
+		let mut gamemode = BlueOnly;
+
+		assert_eq!(join_team(Some(&Team(2)), &mut gamemode, player), None);
+	}
+}
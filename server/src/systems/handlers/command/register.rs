@@ -7,4 +7,6 @@ pub fn register<'a, 'b>(builder: Builder<'a, 'b>) -> Builder<'a, 'b> {
 		.with_handler::<Respawn>()
 		.with_handler::<Spectate>()
 		.with_handler::<Flag>()
+		.with_handler::<Upgrade>()
+		.with_handler::<Join>()
 }
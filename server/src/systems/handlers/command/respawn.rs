@@ -8,9 +8,10 @@ use component::channel::*;
 use component::event::*;
 use component::flag::*;
 use component::time::*;
+use consts::spectate::SPECTATOR_TEAM;
 
-use protocol::server::{Error, PlayerType};
-use protocol::ErrorType;
+use protocol::server::{CommandReply, Error, PlayerType};
+use protocol::{CommandReplyType, ErrorType};
 
 use utils::{EventHandler, EventHandlerTypeProvider};
 
@@ -28,10 +29,12 @@ pub struct RespawnData<'a> {
 	is_spec: WriteStorage<'a, IsSpectating>,
 	is_dead: WriteStorage<'a, IsDead>,
 	last_key: ReadStorage<'a, LastKeyTime>,
+	teams: ReadStorage<'a, Team>,
 
 	conns: Read<'a, Connections>,
 	channel: Write<'a, OnPlayerRespawn>,
 	this_frame: Read<'a, ThisFrame>,
+	gamemode: GameModeWriter<'a, GameMode>,
 }
 
 impl EventHandlerTypeProvider for Respawn {
@@ -58,6 +61,26 @@ impl<'a> EventHandler<'a> for Respawn {
 			Err(_) => return,
 		};
 
+		// Some game modes don't let players back in
+		// once they have died.
+		if !data.gamemode.get().allow_respawn(player) {
+			return;
+		}
+
+		// Spectators need to be on a team before
+		// they have anywhere to respawn.
+		if data.teams.get(player) == Some(&SPECTATOR_TEAM) {
+			data.conns.send_to(
+				conn,
+				CommandReply {
+					ty: CommandReplyType::ShowInPopup,
+					text: "Use /join to join a team first".to_owned(),
+				},
+			);
+
+			return;
+		}
+
 		let allowed = !check_allowed(
 			data.is_dead.get(player).is_some(),
 			data.is_spec.get(player).is_some(),
@@ -0,0 +1,156 @@
+use specs::*;
+use types::*;
+
+use component::event::*;
+use component::flag::ForcePlayerUpdate;
+use protocol::server::{Error, PlayerUpgrade};
+use protocol::{ErrorType, UpgradeType};
+
+use systems::PacketHandler;
+use utils::{EventHandler, EventHandlerTypeProvider};
+use SystemInfo;
+
+/// The highest level that any upgrade can reach.
+const MAX_UPGRADE_LEVEL: u8 = 5;
+
+/// Handles the `/upgrade` command.
+///
+/// This spends unused upgrade points on one of
+/// the four upgrade types, then sends the new
+/// upgrade levels back to the player with a
+/// `PlayerUpgrade` packet.
+#[derive(Default)]
+pub struct Upgrade;
+
+#[derive(SystemData)]
+pub struct UpgradeData<'a> {
+	pub conns: Read<'a, Connections>,
+	pub config: Read<'a, Config>,
+
+	pub upgrades: WriteStorage<'a, Upgrades>,
+	pub force_update: WriteStorage<'a, ForcePlayerUpdate>,
+}
+
+impl EventHandlerTypeProvider for Upgrade {
+	type Event = CommandEvent;
+}
+
+impl<'a> EventHandler<'a> for Upgrade {
+	type SystemData = UpgradeData<'a>;
+
+	fn on_event(&mut self, evt: &CommandEvent, data: &mut UpgradeData) {
+		let &(conn, ref packet) = evt;
+
+		let player = match data.conns.associated_player(conn) {
+			Some(p) => p,
+			None => return,
+		};
+
+		if packet.com != "upgrade" {
+			return;
+		}
+
+		let ty = match parse_upgrade_type(&packet.data) {
+			Some(ty) => ty,
+			None => return,
+		};
+
+		let upgrades = match data.upgrades.get_mut(player) {
+			Some(upgrades) => upgrades,
+			None => return,
+		};
+
+		let ref upgconf = data.config.upgrades;
+
+		{
+			let (level, info) = match ty {
+				UpgradeType::Speed => (&mut upgrades.speed, &upgconf.speed),
+				UpgradeType::Defense => (&mut upgrades.defense, &upgconf.defense),
+				UpgradeType::Energy => (&mut upgrades.energy, &upgconf.energy),
+				UpgradeType::Missile => (&mut upgrades.missile, &upgconf.missile),
+				_ => return,
+			};
+
+			// Fully upgraded already, there's nothing to spend points on
+			if *level >= MAX_UPGRADE_LEVEL {
+				return;
+			}
+
+			let cost = info.cost[*level as usize + 1].0;
+			if upgrades.unused < cost {
+				data.conns.send_to(
+					conn,
+					Error {
+						error: ErrorType::NotEnoughUpgrades,
+					},
+				);
+
+				return;
+			}
+
+			*level += 1;
+			upgrades.unused -= cost;
+		}
+
+		data.conns.send_to(
+			conn,
+			PlayerUpgrade {
+				upgrades: upgrades.unused,
+				ty,
+				speed: upgrades.speed,
+				defense: upgrades.defense,
+				energy: upgrades.energy,
+				missile: upgrades.missile,
+			},
+		);
+
+		// Other players need to know about the new speed
+		// right away, otherwise they'll see the plane
+		// moving slower than it is until the next update.
+		if ty == UpgradeType::Speed {
+			data.force_update.insert(player, ForcePlayerUpdate).unwrap();
+		}
+	}
+}
+
+impl SystemInfo for Upgrade {
+	type Dependencies = PacketHandler;
+
+	fn name() -> &'static str {
+		concat!(module_path!(), "::", line!())
+	}
+
+	fn new() -> Self {
+		Self::default()
+	}
+}
+
+fn parse_upgrade_type(s: &str) -> Option<UpgradeType> {
+	let ty = match s.parse::<u8>().ok()? {
+		1 => UpgradeType::Speed,
+		2 => UpgradeType::Defense,
+		3 => UpgradeType::Energy,
+		4 => UpgradeType::Missile,
+		_ => return None,
+	};
+
+	Some(ty)
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	#[test]
+	fn parse_valid_types() {
+		assert_eq!(parse_upgrade_type("1"), Some(UpgradeType::Speed));
+		assert_eq!(parse_upgrade_type("4"), Some(UpgradeType::Missile));
+	}
+
+	#[test]
+	fn parse_invalid_types() {
+		assert_eq!(parse_upgrade_type("0"), None);
+		assert_eq!(parse_upgrade_type("5"), None);
+		assert_eq!(parse_upgrade_type("speed"), None);
+	}
+}
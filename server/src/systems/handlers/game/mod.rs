@@ -7,6 +7,7 @@ pub mod on_despawn;
 pub mod on_join;
 pub mod on_leave;
 pub mod on_missile_fire;
+pub mod on_mob_despawn;
 pub mod on_player_hit;
 pub mod on_player_killed;
 pub mod on_player_powerup;
@@ -0,0 +1,50 @@
+use specs::*;
+
+use types::*;
+
+use SystemInfo;
+
+use systems::handlers::packet::LoginHandler;
+
+use component::channel::*;
+
+pub struct InitAccount {
+	reader: Option<OnPlayerJoinReader>,
+}
+
+#[derive(SystemData)]
+pub struct InitAccountData<'a> {
+	pub channel: Read<'a, OnPlayerJoin>,
+
+	pub account: WriteStorage<'a, Account>,
+}
+
+impl<'a> System<'a> for InitAccount {
+	type SystemData = InitAccountData<'a>;
+
+	fn setup(&mut self, res: &mut Resources) {
+		Self::SystemData::setup(res);
+
+		self.reader = Some(res.fetch_mut::<OnPlayerJoin>().register_reader());
+	}
+
+	fn run(&mut self, mut data: Self::SystemData) {
+		for evt in data.channel.read(self.reader.as_mut().unwrap()) {
+			if let Some(ref account) = evt.account {
+				data.account.insert(evt.id, account.clone()).unwrap();
+			}
+		}
+	}
+}
+
+impl SystemInfo for InitAccount {
+	type Dependencies = LoginHandler;
+
+	fn name() -> &'static str {
+		concat!(module_path!(), "::", line!())
+	}
+
+	fn new() -> Self {
+		Self { reader: None }
+	}
+}
@@ -31,8 +31,12 @@ impl<'a> System<'a> for InitConnection {
 
 	fn run(&mut self, mut data: Self::SystemData) {
 		for evt in data.channel.read(self.reader.as_mut().unwrap()) {
-			data.conns
-				.associate(evt.conn, evt.id, ConnectionType::Primary);
+			// Bots are given a connection id that doesn't
+			// correspond to an actual connection.
+			if data.conns.conns.contains_key(&evt.conn) {
+				data.conns
+					.associate(evt.conn, evt.id, ConnectionType::Primary);
+			}
 			data.associated
 				.insert(evt.id, AssociatedConnection(evt.conn))
 				.unwrap();
@@ -31,7 +31,13 @@ impl<'a> System<'a> for InitEarnings {
 
 	fn run(&mut self, mut data: Self::SystemData) {
 		for evt in data.channel.read(self.reader.as_mut().unwrap()) {
-			data.earnings.insert(evt.id, Earnings(Score(0))).unwrap();
+			let earnings = evt
+				.account
+				.as_ref()
+				.map(|acc| acc.stats.earnings)
+				.unwrap_or(0);
+
+			data.earnings.insert(evt.id, Earnings(Score(earnings))).unwrap();
 		}
 	}
 }
@@ -17,6 +17,7 @@ pub struct InitKillCountersData<'a> {
 
 	pub total_kills: WriteStorage<'a, TotalKills>,
 	pub total_deaths: WriteStorage<'a, TotalDeaths>,
+	pub interceptions: WriteStorage<'a, Interceptions>,
 }
 
 impl<'a> System<'a> for InitKillCounters {
@@ -32,6 +33,7 @@ impl<'a> System<'a> for InitKillCounters {
 		for evt in data.channel.read(self.reader.as_mut().unwrap()) {
 			data.total_kills.insert(evt.id, TotalKills(0)).unwrap();
 			data.total_deaths.insert(evt.id, TotalDeaths(0)).unwrap();
+			data.interceptions.insert(evt.id, Interceptions(0)).unwrap();
 		}
 	}
 }
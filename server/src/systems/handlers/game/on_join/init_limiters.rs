@@ -13,10 +13,12 @@ use std::time::Duration;
 
 const THROTTLE_LIMIT: usize = 2;
 const MUTE_LIMIT: usize = 15;
+const VOTEMUTE_LIMIT: usize = 3;
 
 lazy_static! {
 	static ref THROTTLE_PERIOD: Duration = Duration::from_secs(4);
 	static ref MUTE_PERIOD: Duration = Duration::from_secs(60);
+	static ref VOTEMUTE_PERIOD: Duration = Duration::from_secs(30);
 }
 
 pub struct InitLimiters {
@@ -29,6 +31,7 @@ pub struct InitLimitersData<'a> {
 
 	pub mute: WriteStorage<'a, ChatMuteLimiter>,
 	pub throttle: WriteStorage<'a, ChatThrottleLimiter>,
+	pub votemute: WriteStorage<'a, VoteMuteLimiter>,
 }
 
 impl<'a> System<'a> for InitLimiters {
@@ -55,6 +58,13 @@ impl<'a> System<'a> for InitLimiters {
 					ChatThrottleLimiter(RateLimiter::new(THROTTLE_LIMIT, *THROTTLE_PERIOD)),
 				)
 				.unwrap();
+
+			data.votemute
+				.insert(
+					evt.id,
+					VoteMuteLimiter(RateLimiter::new(VOTEMUTE_LIMIT, *VOTEMUTE_PERIOD)),
+				)
+				.unwrap();
 		}
 	}
 }
@@ -0,0 +1,64 @@
+use specs::*;
+
+use types::*;
+
+use SystemInfo;
+
+use systems::handlers::packet::LoginHandler;
+
+use component::channel::*;
+
+/// Give players the role that their account
+/// or IP address is configured with.
+pub struct InitRole {
+	reader: Option<OnPlayerJoinReader>,
+}
+
+#[derive(SystemData)]
+pub struct InitRoleData<'a> {
+	pub channel: Read<'a, OnPlayerJoin>,
+	pub config: Read<'a, Config>,
+	pub conns: Read<'a, Connections>,
+
+	pub role: WriteStorage<'a, Role>,
+}
+
+impl<'a> System<'a> for InitRole {
+	type SystemData = InitRoleData<'a>;
+
+	fn setup(&mut self, res: &mut Resources) {
+		Self::SystemData::setup(res);
+
+		self.reader = Some(res.fetch_mut::<OnPlayerJoin>().register_reader());
+	}
+
+	fn run(&mut self, mut data: Self::SystemData) {
+		for evt in data.channel.read(self.reader.as_mut().unwrap()) {
+			// Only use validated accounts here, the raw session
+			// token can be sent by anyone.
+			let account = evt.account.as_ref().map(|acc| acc.id);
+			let role = match data.conns.conns.get(&evt.conn) {
+				Some(conn) => data.config.roles.role_for(account, conn.info.addr),
+				None => Role::Player,
+			};
+
+			if role != Role::Player {
+				info!("Player {:?} joined with role {:?}", evt.id, role);
+			}
+
+			data.role.insert(evt.id, role).unwrap();
+		}
+	}
+}
+
+impl SystemInfo for InitRole {
+	type Dependencies = LoginHandler;
+
+	fn name() -> &'static str {
+		concat!(module_path!(), "::", line!())
+	}
+
+	fn new() -> Self {
+		Self { reader: None }
+	}
+}
@@ -7,6 +7,7 @@ use SystemInfo;
 
 use component::channel::*;
 use component::flag::*;
+use component::horizon::Horizon;
 use component::time::*;
 use protocol::PlayerStatus;
 
@@ -19,6 +20,7 @@ pub struct InitTraitsData<'a> {
 	pub channel: Read<'a, OnPlayerJoin>,
 	pub start_time: Read<'a, StartTime>,
 	pub this_frame: Read<'a, ThisFrame>,
+	pub config: Read<'a, Config>,
 
 	pub score: WriteStorage<'a, Score>,
 	pub level: WriteStorage<'a, Level>,
@@ -26,12 +28,14 @@ pub struct InitTraitsData<'a> {
 	pub plane: WriteStorage<'a, Plane>,
 	pub status: WriteStorage<'a, Status>,
 	pub session: WriteStorage<'a, Session>,
+	pub backup_token: WriteStorage<'a, BackupToken>,
 	pub flag: WriteStorage<'a, FlagCode>,
 	pub is_player: WriteStorage<'a, IsPlayer>,
 	pub pingdata: WriteStorage<'a, PingData>,
 	pub lastshot: WriteStorage<'a, LastShotTime>,
 	pub lastupdate: WriteStorage<'a, LastUpdate>,
 	pub last_key: WriteStorage<'a, LastKeyTime>,
+	pub horizon: WriteStorage<'a, Horizon>,
 }
 
 impl<'a> System<'a> for InitTraits {
@@ -48,6 +52,7 @@ impl<'a> System<'a> for InitTraits {
 			channel,
 			start_time,
 			this_frame,
+			config,
 
 			mut score,
 			mut level,
@@ -55,12 +60,14 @@ impl<'a> System<'a> for InitTraits {
 			mut plane,
 			mut status,
 			mut session,
+			mut backup_token,
 			mut flag,
 			mut lastupdate,
 			mut is_player,
 			mut pingdata,
 			mut lastshot,
 			mut last_key,
+			mut horizon,
 		} = data;
 
 		for evt in channel.read(self.reader.as_mut().unwrap()) {
@@ -70,6 +77,9 @@ impl<'a> System<'a> for InitTraits {
 			plane.insert(evt.id, evt.plane).unwrap();
 			status.insert(evt.id, PlayerStatus::Alive).unwrap();
 			session.insert(evt.id, evt.session.clone()).unwrap();
+			backup_token
+				.insert(evt.id, BackupToken::generate())
+				.unwrap();
 			flag.insert(evt.id, evt.flag).unwrap();
 
 			lastupdate
@@ -79,6 +89,15 @@ impl<'a> System<'a> for InitTraits {
 			pingdata.insert(evt.id, PingData::default()).unwrap();
 			lastshot.insert(evt.id, LastShotTime(start_time.0)).unwrap();
 			last_key.insert(evt.id, LastKeyTime(this_frame.0)).unwrap();
+			horizon
+				.insert(
+					evt.id,
+					Horizon {
+						x: config.view_radius,
+						y: config.view_radius,
+					},
+				)
+				.unwrap();
 		}
 	}
 }
@@ -6,6 +6,7 @@ use GameModeWriter;
 use SystemInfo;
 
 use component::channel::*;
+use consts::spectate::SPECTATOR_TEAM;
 
 pub struct InitTransform {
 	reader: Option<OnPlayerJoinReader>,
@@ -41,7 +42,13 @@ impl<'a> System<'a> for InitTransform {
 		} = data;
 
 		for evt in channel.read(self.reader.as_mut().unwrap()) {
-			let player_pos = gamemode.get_mut().spawn_pos(evt.id, evt.team);
+			// Spectators have nowhere to spawn until
+			// they join a team.
+			let player_pos = if evt.team == SPECTATOR_TEAM {
+				Position::default()
+			} else {
+				gamemode.get_mut().spawn_pos(evt.id, evt.team)
+			};
 
 			pos.insert(evt.id, player_pos).unwrap();
 			rot.insert(evt.id, Rotation::default()).unwrap();
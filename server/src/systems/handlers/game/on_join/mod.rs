@@ -1,3 +1,4 @@
+mod init_account;
 mod init_connection;
 mod init_earnings;
 mod init_join_time;
@@ -5,6 +6,7 @@ mod init_kill_count;
 mod init_last_repel_time;
 mod init_limiters;
 mod init_name;
+mod init_role;
 mod init_state;
 mod init_stealth_time;
 mod init_traits;
@@ -14,8 +16,10 @@ mod send_login;
 mod send_player_new;
 mod send_player_powerup;
 mod send_score;
+mod spectate_on_join;
 mod update_players_game;
 
+pub use self::init_account::InitAccount;
 pub use self::init_connection::InitConnection;
 pub use self::init_earnings::InitEarnings;
 pub use self::init_join_time::InitJoinTime;
@@ -23,6 +27,7 @@ pub use self::init_kill_count::InitKillCounters;
 pub use self::init_last_repel_time::InitLastRepelTime;
 pub use self::init_limiters::InitLimiters;
 pub use self::init_name::InitName;
+pub use self::init_role::InitRole;
 pub use self::init_state::InitState;
 pub use self::init_stealth_time::InitStealthTime;
 pub use self::init_traits::InitTraits;
@@ -32,9 +37,11 @@ pub use self::send_login::SendLogin;
 pub use self::send_player_new::SendPlayerNew;
 pub use self::send_player_powerup::SendPlayerPowerup;
 pub use self::send_score::SendScoreUpdate;
+pub use self::spectate_on_join::SpectateOnJoin;
 pub use self::update_players_game::UpdatePlayersGame;
 
 pub type AllJoinHandlers = (
+	InitAccount,
 	InitConnection,
 	InitEarnings,
 	InitJoinTime,
@@ -42,6 +49,7 @@ pub type AllJoinHandlers = (
 	InitLastRepelTime,
 	InitState,
 	InitName,
+	InitRole,
 	InitLimiters,
 	InitStealthTime,
 	InitTraits,
@@ -33,7 +33,7 @@ pub struct SendLoginData<'a> {
 	pub powerups: ReadStorage<'a, Powerups>,
 	pub name: ReadStorage<'a, Name>,
 	pub level: ReadStorage<'a, Level>,
-	pub session: ReadStorage<'a, Session>,
+	pub backup_token: ReadStorage<'a, BackupToken>,
 }
 
 impl SendLogin {
@@ -91,12 +91,16 @@ impl<'a> System<'a> for SendLogin {
 		for evt in data.channel.read(self.reader.as_mut().unwrap()) {
 			let player_data = Self::get_player_data(&data);
 
+			// Clients use the token when opening a backup
+			// connection, it is validated by BackupHandler
+			let token = data.backup_token.get(evt.id).unwrap().0.clone();
+
 			let packet = Login {
 				clock: data.clock.get(),
 				id: evt.id.into(),
 				room: data.gamemode.get().room(),
 				success: true,
-				token: "none".to_owned(),
+				token,
 				team: *data.team.get(evt.id).unwrap(),
 				ty: data.gamemode.get().gametype(),
 				players: player_data,
@@ -4,7 +4,9 @@ use types::*;
 use SystemInfo;
 
 use component::channel::*;
+use consts::spectate::SPECTATOR_TEAM;
 use protocol::server::PlayerNew;
+use protocol::PlayerStatus;
 use protocol::Upgrades as ProtocolUpgrades;
 
 /// Send a `PlayerNew` packet to all other players when
@@ -63,9 +65,18 @@ impl<'a> System<'a> for SendPlayerNew {
 				shield: powerups.shield(),
 			};
 
+			// Spectators don't have a plane yet. Marking them
+			// as dead keeps other clients from showing one at
+			// their placeholder position until they respawn.
+			let player_status = if evt.team == SPECTATOR_TEAM {
+				PlayerStatus::Dead
+			} else {
+				*status.get(evt.id).unwrap()
+			};
+
 			let player_new = PlayerNew {
 				id: evt.id.into(),
-				status: *status.get(evt.id).unwrap(),
+				status: player_status,
 				name: name.get(evt.id).unwrap().0.clone(),
 				ty: *plane.get(evt.id).unwrap(),
 				team: *team.get(evt.id).unwrap(),
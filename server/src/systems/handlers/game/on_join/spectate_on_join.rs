@@ -0,0 +1,71 @@
+use specs::*;
+
+use SystemInfo;
+
+use component::channel::OnPlayerSpectate;
+use component::event::{PlayerJoin, PlayerSpectate};
+use component::flag::IsPlayer;
+use consts::spectate::SPECTATOR_TEAM;
+
+use types::systemdata::IsAlive;
+use utils::event_handler::{EventHandler, EventHandlerTypeProvider};
+
+use super::AllJoinHandlers;
+
+/// Put players that joined without a team
+/// straight into spectate.
+#[derive(Default)]
+pub struct SpectateOnJoin;
+
+#[derive(SystemData)]
+pub struct SpectateOnJoinData<'a> {
+	channel: Write<'a, OnPlayerSpectate>,
+
+	entities: Entities<'a>,
+	is_player: ReadStorage<'a, IsPlayer>,
+	is_alive: IsAlive<'a>,
+}
+
+impl EventHandlerTypeProvider for SpectateOnJoin {
+	type Event = PlayerJoin;
+}
+
+impl<'a> EventHandler<'a> for SpectateOnJoin {
+	type SystemData = SpectateOnJoinData<'a>;
+
+	fn on_event(&mut self, evt: &PlayerJoin, data: &mut Self::SystemData) {
+		if evt.team != SPECTATOR_TEAM {
+			return;
+		}
+
+		let target = (
+			&*data.entities,
+			data.is_player.mask() & data.is_alive.mask(),
+		)
+			.join()
+			.map(|(ent, ..)| ent)
+			.find(|&ent| ent != evt.id);
+
+		// This goes through the same path as the
+		// spectate command, which takes care of
+		// removing the player's plane.
+		data.channel.single_write(PlayerSpectate {
+			player: evt.id,
+			target,
+			is_dead: false,
+			is_spec: false,
+		});
+	}
+}
+
+impl SystemInfo for SpectateOnJoin {
+	type Dependencies = AllJoinHandlers;
+
+	fn name() -> &'static str {
+		concat!(module_path!(), "::", line!())
+	}
+
+	fn new() -> Self {
+		Self::default()
+	}
+}
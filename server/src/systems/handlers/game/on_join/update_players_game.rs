@@ -5,6 +5,7 @@ use SystemInfo;
 
 use component::channel::*;
 use component::counter::PlayersGame;
+use consts::spectate::SPECTATOR_TEAM;
 use consts::NUM_PLAYERS;
 
 use std::sync::atomic::Ordering::Relaxed;
@@ -30,8 +31,11 @@ impl<'a> System<'a> for UpdatePlayersGame {
 	}
 
 	fn run(&mut self, mut data: Self::SystemData) {
-		for _ in data.channel.read(self.reader.as_mut().unwrap()) {
-			data.playersgame.0 += 1;
+		for evt in data.channel.read(self.reader.as_mut().unwrap()) {
+			// Spectators are counted once they join a team
+			if evt.team != SPECTATOR_TEAM {
+				data.playersgame.0 += 1;
+			}
 			NUM_PLAYERS.fetch_add(1, Relaxed);
 		}
 	}
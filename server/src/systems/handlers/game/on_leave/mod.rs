@@ -1,12 +1,14 @@
 mod create_despawn_event;
 mod free_name;
+mod save_account;
 mod update_players_game;
 
 pub use self::create_despawn_event::CreateDespawnEvent;
 pub use self::free_name::FreeName;
+pub use self::save_account::{SaveAccount, SaveAccountData};
 pub use self::update_players_game::UpdatePlayersGame;
 
-pub type AllLeaveHandlers = (CreateDespawnEvent, FreeName, UpdatePlayersGame);
+pub type AllLeaveHandlers = (CreateDespawnEvent, FreeName, SaveAccount, UpdatePlayersGame);
 
 use systems;
 
@@ -0,0 +1,96 @@
+use specs::*;
+use types::*;
+
+use component::counter::*;
+use component::event::PlayerLeave;
+
+use utils::{EventHandler, EventHandlerTypeProvider};
+use SystemInfo;
+
+/// Write the stats of a player back to the
+/// account store when they leave.
+#[derive(Default)]
+pub struct SaveAccount;
+
+#[derive(SystemData)]
+pub struct SaveAccountData<'a> {
+	accounts: Write<'a, Accounts>,
+
+	account: ReadStorage<'a, Account>,
+	level: ReadStorage<'a, Level>,
+	score: ReadStorage<'a, Score>,
+	earnings: ReadStorage<'a, Earnings>,
+	total_kills: ReadStorage<'a, TotalKills>,
+	total_deaths: ReadStorage<'a, TotalDeaths>,
+}
+
+impl<'a> SaveAccountData<'a> {
+	/// Add the stats from the current game to the
+	/// account of `player` and store it. Does nothing
+	/// for players without an account.
+	pub fn save(&mut self, player: Entity) {
+		let account = match self.account.get(player) {
+			Some(account) => account,
+			None => return,
+		};
+
+		let mut stats = account.stats;
+
+		stats.add_game(
+			self.score.get(player).map(|x| x.0).unwrap_or(0),
+			self.total_kills.get(player).map(|x| x.0).unwrap_or(0),
+			self.total_deaths.get(player).map(|x| x.0).unwrap_or(0),
+		);
+		if let Some(level) = self.level.get(player) {
+			stats.level = level.0;
+		}
+		if let Some(earnings) = self.earnings.get(player) {
+			stats.earnings = (earnings.0).0;
+		}
+
+		if let Err(e) = self.accounts.0.store(&account.id, &stats) {
+			error!("Failed to save account for player {:?}: {}", player, e);
+		}
+	}
+
+	/// Save the accounts of all players that are
+	/// still in the game and write them out.
+	pub fn save_all(&mut self, entities: &Entities<'a>) {
+		let players = (&**entities, &self.account)
+			.join()
+			.map(|(ent, _)| ent)
+			.collect::<Vec<_>>();
+
+		for player in players {
+			self.save(player);
+		}
+
+		if let Err(e) = self.accounts.0.flush() {
+			error!("Failed to save accounts: {}", e);
+		}
+	}
+}
+
+impl EventHandlerTypeProvider for SaveAccount {
+	type Event = PlayerLeave;
+}
+
+impl<'a> EventHandler<'a> for SaveAccount {
+	type SystemData = SaveAccountData<'a>;
+
+	fn on_event(&mut self, evt: &PlayerLeave, data: &mut Self::SystemData) {
+		data.save(evt.0);
+	}
+}
+
+impl SystemInfo for SaveAccount {
+	type Dependencies = super::KnownEventSources;
+
+	fn name() -> &'static str {
+		concat!(module_path!(), "::", line!())
+	}
+
+	fn new() -> Self {
+		Self::default()
+	}
+}
@@ -0,0 +1,115 @@
+use specs::*;
+use types::*;
+
+use component::event::{MobDespawnEvent, TimerEvent};
+use component::flag::IsPowerup;
+use component::time::MobSpawnTime;
+use consts::missile::ID_REUSE_TIME;
+use consts::timer::DELETE_ENTITY;
+use protocol::server::MobDespawn;
+
+use utils::{EventHandler, EventHandlerTypeProvider};
+use SystemInfo;
+
+use super::KnownEventSources;
+
+/// Remove an upgrade or powerup crate from the
+/// game, either because it was picked up or
+/// because it expired.
+///
+/// A mob can be picked up in the same frame that
+/// it expires, so only the first despawn event for
+/// each mob is acted on.
+#[derive(Default)]
+pub struct DespawnMob;
+
+#[derive(SystemData)]
+pub struct DespawnMobData<'a> {
+	conns: Read<'a, Connections>,
+	lazy: Read<'a, LazyUpdate>,
+	dispatch: ReadExpect<'a, FutureDispatcher>,
+
+	mob: WriteStorage<'a, Mob>,
+}
+
+impl EventHandlerTypeProvider for DespawnMob {
+	type Event = MobDespawnEvent;
+}
+
+impl<'a> EventHandler<'a> for DespawnMob {
+	type SystemData = DespawnMobData<'a>;
+
+	fn on_event(&mut self, evt: &MobDespawnEvent, data: &mut Self::SystemData) {
+		let mob = evt.mob;
+
+		// The mob component is removed right away so that
+		// it can't be despawned twice or picked up again.
+		if data.mob.remove(mob).is_none() {
+			return;
+		}
+
+		// The rest of the components are removed lazily,
+		// the entity itself is only deleted once its ID
+		// can safely be reused.
+		data.lazy.remove::<Position>(mob);
+		data.lazy.remove::<Team>(mob);
+		data.lazy.remove::<IsPowerup>(mob);
+		data.lazy.remove::<MobSpawnTime>(mob);
+
+		data.dispatch
+			.run_delayed(*ID_REUSE_TIME, move |inst| TimerEvent {
+				ty: *DELETE_ENTITY,
+				instant: inst,
+				data: Some(Box::new(mob)),
+			});
+
+		data.conns.send_to_all(MobDespawn {
+			id: mob.into(),
+			ty: evt.ty,
+		});
+	}
+}
+
+impl SystemInfo for DespawnMob {
+	type Dependencies = KnownEventSources;
+
+	fn name() -> &'static str {
+		concat!(module_path!(), "::", line!())
+	}
+
+	fn new() -> Self {
+		Self::default()
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	use std::sync::mpsc::channel;
+
+	#[test]
+	fn despawns_once() {
+		let (msg_send, msg_recv) = channel();
+
+		let mut world = World::new();
+		world.add_resource(Connections::new(msg_send));
+		world.add_resource(FutureDispatcher::new(channel().0));
+		DespawnMobData::setup(&mut world.res);
+
+		let mob = world.create_entity().with(Mob::Upgrade).build();
+		let evt = MobDespawnEvent {
+			mob,
+			ty: Mob::Upgrade,
+		};
+
+		// Picked up and expired in the same frame
+		let mut handler = DespawnMob::default();
+		let mut data = DespawnMobData::fetch(&world.res);
+		handler.on_event(&evt, &mut data);
+		handler.on_event(&evt, &mut data);
+
+		assert!(msg_recv.try_recv().is_ok());
+		assert!(msg_recv.try_recv().is_err());
+	}
+}
@@ -0,0 +1,11 @@
+mod despawn_mob;
+
+pub use self::despawn_mob::DespawnMob;
+
+use systems;
+
+pub type KnownEventSources = (
+	systems::upgrades::DespawnExpired,
+	systems::upgrades::Pickup,
+	systems::powerups::PickupPowerup,
+);
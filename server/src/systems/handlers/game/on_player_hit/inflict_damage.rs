@@ -30,6 +30,7 @@ pub struct InflictDamageData<'a> {
 	pub owner: ReadStorage<'a, PlayerRef>,
 	pub player_flag: ReadStorage<'a, IsPlayer>,
 	pub powerups: ReadStorage<'a, Powerups>,
+	pub team: ReadStorage<'a, Team>,
 
 	pub mob: ReadStorage<'a, Mob>,
 	pub pos: ReadStorage<'a, Position>,
@@ -57,19 +58,32 @@ impl<'a> EventHandler<'a> for InflictDamage {
 		let mob = try_get!(evt.missile, data.mob);
 		let pos = try_get!(evt.missile, data.pos);
 		let owner = try_get!(evt.missile, data.owner);
+		let team = try_get!(evt.player, data.team);
+		let missile_team = try_get!(evt.missile, data.team);
 
 		let ref planeconf = data.config.planes[*plane];
 		let ref mobconf = data.config.mobs[*mob].missile.unwrap();
 		let ref upgconf = data.config.upgrades;
+		let ref ffconf = data.config.friendly_fire;
 
 		// No damage can be done if the player is shielded
 		if powerups.shield() {
 			return;
 		}
 
-		*health -= mobconf.damage * planeconf.damage_factor
+		let mut damage = mobconf.damage * planeconf.damage_factor
 			/ upgconf.defense.factor[upgrades.defense as usize];
 
+		if team == missile_team {
+			match ffconf.mode {
+				FriendlyFireMode::Off => return,
+				FriendlyFireMode::Reduced => damage = damage * ffconf.reduced_factor,
+				FriendlyFireMode::Full => (),
+			}
+		}
+
+		*health -= damage;
+
 		if health.inner() <= 0.0 {
 			data.kill_channel.single_write(PlayerKilled {
 				missile: evt.missile,
@@ -34,16 +34,20 @@ impl<'a> EventHandler<'a> for DisplayMessage {
 	type SystemData = DisplayMessageData<'a>;
 
 	fn on_event(&mut self, evt: &PlayerKilled, data: &mut Self::SystemData) {
+		// A player that is their own killer died to
+		// something other than another player.
+		let killer = if evt.player == evt.killer {
+			None
+		} else {
+			Some(evt.killer.into())
+		};
+
 		let packet = PlayerKill {
 			id: evt.player.into(),
-			killer: Some(evt.killer.into()),
+			killer,
 			pos: evt.pos,
 		};
 
-		if evt.player == evt.killer {
-			warn!("Player {:?} killed themselves!", evt.player);
-		}
-
 		data.conns.send_to_visible(evt.pos, packet);
 
 		data.timerevent.single_write(TimerEvent {
@@ -21,7 +21,9 @@ pub struct UpdateScoreData<'a> {
 	pub channel: Read<'a, OnPlayerKilled>,
 	pub conns: Read<'a, Connections>,
 	pub thisframe: Read<'a, ThisFrame>,
+	pub config: Read<'a, Config>,
 
+	pub teams: ReadStorage<'a, Team>,
 	pub score: WriteStorage<'a, Score>,
 	pub powerups: WriteStorage<'a, Powerups>,
 	pub upgrades: WriteStorage<'a, Upgrades>,
@@ -66,7 +68,28 @@ impl<'a> EventHandler<'a> for UpdateScore {
 			return;
 		}
 
-		{
+		// Players that die without anyone killing them
+		// (e.g. outside of the safe zone in BTR) are
+		// recorded as their own killer. This only counts
+		// as a death.
+		if evt.player == evt.killer {
+			try_get!(evt.player, mut data.total_deaths).0 += 1;
+
+			Self::send_update(evt.player, &data);
+			return;
+		}
+
+		let team_kill = data.teams.get(evt.player) == data.teams.get(evt.killer);
+
+		if team_kill {
+			// Killing a teammate costs score instead of
+			// transferring it from the victim.
+			let penalty = data.config.friendly_fire.team_kill_penalty;
+			let score = try_get!(evt.killer, mut data.score);
+
+			score.0 -= score.0.min(penalty);
+			try_get!(evt.player, mut data.total_deaths).0 += 1;
+		} else {
 			let kills = try_get!(evt.killer, mut data.total_kills);
 			let deaths = try_get!(evt.player, mut data.total_deaths);
 
@@ -17,4 +17,5 @@ pub type AllPlayerPowerupSystems = (TriggerUpdate);
 pub type KnownEventSources = (
 	systems::admin::GivePowerup,
 	systems::handlers::game::on_player_respawn::GiveShield,
+	systems::powerups::PickupPowerup,
 );
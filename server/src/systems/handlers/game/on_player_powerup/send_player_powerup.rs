@@ -4,13 +4,12 @@ use SystemInfo;
 
 use component::event::PlayerPowerup;
 use protocol::server::PlayerPowerup as ServerPlayerPowerup;
-use protocol::PowerupType;
 
 use types::Connections;
 use utils::event_handler::{EventHandler, EventHandlerTypeProvider};
 
-/// Add the initial 2s shield when a player joins
-/// and send that packet to all visible players.
+/// Let a player know that they have been given
+/// a powerup and how long it lasts.
 #[derive(Default)]
 pub struct SendPlayerPowerup;
 
@@ -33,7 +32,7 @@ impl<'a> EventHandler<'a> for SendPlayerPowerup {
 			evt.player,
 			ServerPlayerPowerup {
 				duration: duration as u32,
-				ty: PowerupType::Shield,
+				ty: evt.ty,
 			},
 		);
 	}
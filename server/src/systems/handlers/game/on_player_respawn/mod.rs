@@ -19,4 +19,7 @@ pub type AllRespawnHandlers = (
 
 use systems;
 
-pub type KnownEventSources = (systems::handlers::command::Respawn);
+pub type KnownEventSources = (
+	systems::handlers::command::Respawn,
+	systems::handlers::command::Join,
+);
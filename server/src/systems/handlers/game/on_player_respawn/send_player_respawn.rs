@@ -28,6 +28,7 @@ pub struct SendPlayerRespawnData<'a> {
 	is_spec: ReadStorage<'a, IsSpectating>,
 	pos: ReadStorage<'a, Position>,
 	rot: ReadStorage<'a, Rotation>,
+	upgrades: ReadStorage<'a, Upgrades>,
 }
 
 impl EventHandlerTypeProvider for SendPlayerRespawn {
@@ -49,6 +50,7 @@ impl<'a> EventHandler<'a> for SendPlayerRespawn {
 		let player = evt.player;
 		let pos = *try_get!(player, data.pos);
 		let rot = *try_get!(player, data.rot);
+		let upgrades = *try_get!(player, data.upgrades);
 
 		info!("Player {:?} respawned!", player);
 		data.conns.send_to_visible(
@@ -57,7 +59,10 @@ impl<'a> EventHandler<'a> for SendPlayerRespawn {
 				id: player.into(),
 				pos: pos,
 				rot: rot,
-				upgrades: ProtocolUpgrades::default(),
+				upgrades: ProtocolUpgrades {
+					speed: upgrades.speed,
+					..Default::default()
+				},
 			},
 		);
 	}
@@ -26,4 +26,8 @@ pub type AllSpectateEventHandlers = (
 
 use systems;
 
-pub type KnownEventSources = (systems::handlers::command::Spectate);
+pub type KnownEventSources = (
+	systems::handlers::command::Spectate,
+	systems::afk::CheckAfk,
+	systems::handlers::game::on_join::SpectateOnJoin,
+);
@@ -18,6 +18,7 @@ pub fn register<'a, 'b>(builder: Builder<'a, 'b>) -> Builder<'a, 'b> {
 		.with_handler::<on_player_killed::CreateDespawnEvent>()
 		.with_handler::<on_player_killed::DespawnMissile>()
 		// On player joined
+		.with::<on_join::InitAccount>()
 		.with::<on_join::InitConnection>()
 		.with::<on_join::InitKillCounters>()
 		.with::<on_join::InitJoinTime>()
@@ -25,6 +26,7 @@ pub fn register<'a, 'b>(builder: Builder<'a, 'b>) -> Builder<'a, 'b> {
 		.with::<on_join::InitTraits>()
 		.with::<on_join::InitState>()
 		.with::<on_join::InitName>()
+		.with::<on_join::InitRole>()
 		.with::<on_join::InitLimiters>()
 		.with::<on_join::InitTransform>()
 		.with::<on_join::InitStealthTime>()
@@ -35,10 +37,14 @@ pub fn register<'a, 'b>(builder: Builder<'a, 'b>) -> Builder<'a, 'b> {
 		.with::<on_join::SendScoreUpdate>()
 		.with::<on_join::UpdatePlayersGame>()
 		.with_handler::<on_join::SendPlayerPowerup>()
+		.with_handler::<on_join::SpectateOnJoin>()
 		// On player leave
 		.with::<on_leave::FreeName>()
 		.with::<on_leave::UpdatePlayersGame>()
 		.with_handler::<on_leave::CreateDespawnEvent>()
+		.with_handler::<on_leave::SaveAccount>()
+		// On mob despawn
+		.with_handler::<on_mob_despawn::DespawnMob>()
 		// On missile fire
 		.with_handler::<on_missile_fire::SendPlayerFire>()
 		.with_handler::<on_missile_fire::SetLastShot>()
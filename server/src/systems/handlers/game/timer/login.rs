@@ -10,6 +10,7 @@ use component::channel::*;
 use component::collection::PlayerNames;
 use component::event::PlayerJoin;
 use component::time::*;
+use consts::spectate::SPECTATOR_TEAM;
 use consts::timer::*;
 use types::*;
 
@@ -27,6 +28,8 @@ pub struct LoginSystemData<'a> {
 	pub startime: Read<'a, StartTime>,
 	pub player_join: Write<'a, OnPlayerJoin>,
 	pub config: Read<'a, Config>,
+	pub accounts: Read<'a, Accounts>,
+	pub sessions: Read<'a, Sessions>,
 	pub gamemode: GameModeWriter<'a, GameMode>,
 }
 
@@ -66,7 +69,30 @@ impl LoginHandler {
 			Err(_) => None,
 		};
 
-		let team = data.gamemode.get_mut().assign_team(entity);
+		// Players with a valid session token get an account,
+		// either the existing one or a fresh one.
+		let account = data.sessions.0.validate(&login.session).map(|id| Account {
+			id,
+			stats: data.accounts.0.load(&id).unwrap_or_default(),
+		});
+		let level = account
+			.as_ref()
+			.map(|acc| Level(acc.stats.level))
+			.unwrap_or(Level(0));
+
+		// Spectators only get a team once they ask for one,
+		// so that they don't take up a slot on a team.
+		let spectate = data
+			.conns
+			.conns
+			.get(&conn)
+			.map(|c| c.info.spectate)
+			.unwrap_or(false);
+		let team = if data.config.spectator_join && spectate {
+			SPECTATOR_TEAM
+		} else {
+			data.gamemode.get_mut().assign_team(entity)
+		};
 		let plane = data.gamemode.get_mut().assign_plane(entity, team);
 
 		let mut name = login.name;
@@ -85,13 +111,14 @@ impl LoginHandler {
 
 		data.player_join.single_write(PlayerJoin {
 			id: entity,
-			level: Level(0),
+			level,
 			name: Name(name),
 			session: Session(session),
 			flag: flag,
 			team,
 			plane,
 			conn,
+			account,
 		});
 	}
 }
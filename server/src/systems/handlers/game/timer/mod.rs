@@ -3,6 +3,7 @@ mod delete_entity;
 mod login;
 mod login_fail;
 mod player_respawn;
+mod save_accounts;
 mod register;
 mod unthrottle;
 
@@ -11,6 +12,7 @@ pub use self::delete_entity::DeleteEntity;
 pub use self::login::LoginHandler;
 pub use self::login_fail::LoginFailed;
 pub use self::player_respawn::PlayerRespawnSystem as PlayerRespawn;
+pub use self::save_accounts::SaveAccounts;
 pub use self::unthrottle::UnthrottlePlayer;
 
 pub use self::register::register;
@@ -4,6 +4,7 @@ use component::channel::*;
 use component::event::*;
 use component::flag::{IsDead, IsSpectating};
 use consts::timer::*;
+use types::{GameMode, GameModeWriter};
 
 use utils::{EventHandler, EventHandlerTypeProvider};
 
@@ -19,6 +20,7 @@ pub struct PlayerRespawnSystemData<'a> {
 	entities: Entities<'a>,
 	is_dead: WriteStorage<'a, IsDead>,
 	is_spec: ReadStorage<'a, IsSpectating>,
+	gamemode: GameModeWriter<'a, GameMode>,
 }
 
 impl EventHandlerTypeProvider for PlayerRespawnSystem {
@@ -54,6 +56,10 @@ impl<'a> EventHandler<'a> for PlayerRespawnSystem {
 			return;
 		}
 
+		if !data.gamemode.get().allow_respawn(player) {
+			return;
+		}
+
 		data.respawn_channel.single_write(PlayerRespawn {
 			player,
 			prev_status: PlayerRespawnPrevStatus::Dead,
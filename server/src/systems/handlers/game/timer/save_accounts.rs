@@ -0,0 +1,49 @@
+use specs::*;
+use types::*;
+
+use component::event::TimerEvent;
+use consts::timer::SAVE_ACCOUNTS;
+
+use utils::{EventHandler, EventHandlerTypeProvider};
+use SystemInfo;
+
+/// Periodically write out the accounts of players
+/// that have left, instead of rewriting the account
+/// store every time someone leaves.
+#[derive(Default)]
+pub struct SaveAccounts;
+
+#[derive(SystemData)]
+pub struct SaveAccountsData<'a> {
+	accounts: Write<'a, Accounts>,
+}
+
+impl EventHandlerTypeProvider for SaveAccounts {
+	type Event = TimerEvent;
+}
+
+impl<'a> EventHandler<'a> for SaveAccounts {
+	type SystemData = SaveAccountsData<'a>;
+
+	fn on_event(&mut self, evt: &TimerEvent, data: &mut Self::SystemData) {
+		if evt.ty != *SAVE_ACCOUNTS {
+			return;
+		}
+
+		if let Err(e) = data.accounts.0.flush() {
+			error!("Failed to save accounts: {}", e);
+		}
+	}
+}
+
+impl SystemInfo for SaveAccounts {
+	type Dependencies = ();
+
+	fn name() -> &'static str {
+		concat!(module_path!(), "::", line!())
+	}
+
+	fn new() -> Self {
+		Self::default()
+	}
+}
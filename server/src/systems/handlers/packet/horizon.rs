@@ -0,0 +1,52 @@
+use specs::*;
+use types::*;
+
+use component::event::HorizonEvent;
+use component::horizon::Horizon;
+use utils::{EventHandler, EventHandlerTypeProvider};
+
+use systems::handlers::game::on_join::InitTraits;
+use SystemInfo;
+
+/// Update the horizon of a player when they
+/// send a `Horizon` packet.
+#[derive(Default)]
+pub struct HorizonHandler;
+
+#[derive(SystemData)]
+pub struct HorizonHandlerData<'a> {
+	conns: Read<'a, Connections>,
+	config: Read<'a, Config>,
+
+	horizon: WriteStorage<'a, Horizon>,
+}
+
+impl EventHandlerTypeProvider for HorizonHandler {
+	type Event = HorizonEvent;
+}
+
+impl<'a> EventHandler<'a> for HorizonHandler {
+	type SystemData = HorizonHandlerData<'a>;
+
+	fn on_event(&mut self, evt: &HorizonEvent, data: &mut Self::SystemData) {
+		let player = match data.conns.associated_player(evt.0) {
+			Some(player) => player,
+			None => return,
+		};
+
+		let horizon = try_get!(player, mut data.horizon);
+		*horizon = Horizon::clamped(evt.1.horizon_x as f32, evt.1.horizon_y as f32, &data.config);
+	}
+}
+
+impl SystemInfo for HorizonHandler {
+	type Dependencies = InitTraits;
+
+	fn name() -> &'static str {
+		concat!(module_path!(), "::", line!())
+	}
+
+	fn new() -> Self {
+		Self::default()
+	}
+}
@@ -1,10 +1,12 @@
 pub use handlers::*;
 
 mod chat_event;
+mod horizon;
 mod register;
 mod whisper;
 
 pub use self::chat_event::ChatEventHandler;
+pub use self::horizon::HorizonHandler;
 pub use self::whisper::WhisperHandler;
 
 pub use self::register::register;
@@ -8,6 +8,7 @@ pub fn register<'a, 'b>(builder: Builder<'a, 'b>) -> Builder<'a, 'b> {
 		.with::<OnOpenHandler>()
 		.with::<OnCloseHandler>()
 		.with::<LoginHandler>()
+		.with::<BackupHandler>()
 		.with::<KeyHandler>()
 		.with::<ChatHandler>()
 		.with::<SayHandler>()
@@ -18,4 +19,5 @@ pub fn register<'a, 'b>(builder: Builder<'a, 'b>) -> Builder<'a, 'b> {
 		.with::<WhisperHandler>()
 		.with::<ChatEventHandler>()
 		.with_handler::<TeamChatHandler>()
+		.with_handler::<HorizonHandler>()
 }
@@ -7,16 +7,22 @@ mod position_update;
 mod register;
 mod run_futures;
 mod timer_handler;
+mod update_level;
 
 pub mod admin;
+pub mod afk;
+pub mod anticheat;
+pub mod bots;
 pub mod collision;
 pub mod handlers;
 pub mod limiting;
 pub mod missile;
+pub mod moderation;
 pub mod notify;
 pub mod powerups;
 pub mod specials;
 pub mod upgrades;
+pub mod votemute;
 
 pub use self::disconnect::Disconnect;
 pub use self::energy_regen::EnergyRegenSystem;
@@ -25,5 +31,6 @@ pub use self::packet_handler::PacketHandler;
 pub use self::poll_complete::PollComplete;
 pub use self::position_update::PositionUpdate;
 pub use self::timer_handler::TimerHandler;
+pub use self::update_level::UpdateLevel;
 
 pub use self::register::register;
@@ -0,0 +1,235 @@
+use specs::*;
+use types::*;
+
+use std::net::IpAddr;
+use std::time::{Duration, SystemTime};
+
+use component::event::CommandEvent;
+use component::flag::{IsKicked, IsPlayer};
+use systems::PacketHandler;
+use SystemInfo;
+
+use utils::{EventHandler, EventHandlerTypeProvider};
+
+use super::*;
+
+/// Ban the IP address of a player and kick them.
+///
+/// Usage: `ban <id> [minutes] [reason]`. If no
+/// duration is given then the ban is permanent.
+#[derive(Default)]
+pub struct BanPlayer;
+
+/// Remove the ban on an IP address.
+///
+/// Usage: `unban <ip>`
+#[derive(Default)]
+pub struct Unban;
+
+#[derive(SystemData)]
+pub struct BanPlayerData<'a> {
+	entities: Entities<'a>,
+	conns: Read<'a, Connections>,
+	bans: Write<'a, BanList>,
+
+	roles: ReadStorage<'a, Role>,
+	names: ReadStorage<'a, Name>,
+	is_player: ReadStorage<'a, IsPlayer>,
+	is_kicked: WriteStorage<'a, IsKicked>,
+}
+
+#[derive(SystemData)]
+pub struct UnbanData<'a> {
+	conns: Read<'a, Connections>,
+	bans: Write<'a, BanList>,
+
+	roles: ReadStorage<'a, Role>,
+}
+
+fn parse_duration<'a>(arg: Option<&'a str>) -> Result<Option<Duration>, ModerationError<'a>> {
+	match arg {
+		Some(arg) => arg
+			.parse::<u64>()
+			.map(|mins| Some(Duration::from_secs(mins * 60)))
+			.map_err(|_| ModerationError::InvalidDuration(arg)),
+		None => Ok(None),
+	}
+}
+
+impl BanPlayer {
+	fn do_command<'a, 'b>(
+		evt: &'b CommandEvent,
+		data: &mut BanPlayerData<'a>,
+	) -> Result<(), ModerationError<'b>> {
+		let &(conn, ref packet) = evt;
+
+		let player = match data.conns.associated_player(conn) {
+			Some(p) => p,
+			None => return Ok(()),
+		};
+
+		if get_role(&data.roles, player) < Role::Moderator {
+			return Err(ModerationError::PermissionDenied);
+		}
+
+		let mut args = packet.data.splitn(3, ' ');
+
+		let target = get_target(
+			player,
+			args.next(),
+			&data.entities,
+			&data.is_player,
+			&data.roles,
+		)?;
+		let duration = parse_duration(args.next())?;
+		let reason = args.next().unwrap_or("").to_owned();
+
+		// Prefer the address of the primary connection
+		// but fall back to any connection the player
+		// has, in case they are reconnecting.
+		let addr = data
+			.conns
+			.iter()
+			.filter(|c| c.player == Some(target))
+			.max_by_key(|c| c.ty == ConnectionType::Primary)
+			.map(|c| c.info.addr)
+			.ok_or(ModerationError::NotConnected(target.id() as u16))?;
+
+		data.bans
+			.ban(Ban {
+				addr,
+				expiry: duration.map(|d| SystemTime::now() + d),
+				reason,
+			})
+			.map_err(|e| ModerationError::Io(e.to_string()))?;
+
+		kick_player(&data.conns, &mut data.is_kicked, target);
+
+		info!(
+			"Player {:?} with addr {} was banned by {:?} for {:?}",
+			target, addr, player, duration
+		);
+
+		if let Some(name) = data.names.get(target) {
+			reply(&data.conns, conn, format!("Banned {} ({})", name.0, addr));
+		}
+
+		Ok(())
+	}
+}
+
+impl Unban {
+	fn do_command<'a, 'b>(
+		evt: &'b CommandEvent,
+		data: &mut UnbanData<'a>,
+	) -> Result<(), ModerationError<'b>> {
+		let &(conn, ref packet) = evt;
+
+		let player = match data.conns.associated_player(conn) {
+			Some(p) => p,
+			None => return Ok(()),
+		};
+
+		if get_role(&data.roles, player) < Role::Moderator {
+			return Err(ModerationError::PermissionDenied);
+		}
+
+		let arg = packet
+			.data
+			.split_whitespace()
+			.next()
+			.ok_or(ModerationError::MissingArguments)?;
+		let addr: IpAddr = arg
+			.parse()
+			.map_err(|_| ModerationError::InvalidAddress(arg))?;
+
+		let removed = data
+			.bans
+			.unban(addr)
+			.map_err(|e| ModerationError::Io(e.to_string()))?;
+
+		if !removed {
+			return Err(ModerationError::NotBanned(arg));
+		}
+
+		info!("Addr {} was unbanned by {:?}", addr, player);
+		reply(&data.conns, conn, format!("Unbanned {}", addr));
+
+		Ok(())
+	}
+}
+
+impl EventHandlerTypeProvider for BanPlayer {
+	type Event = CommandEvent;
+}
+
+impl<'a> EventHandler<'a> for BanPlayer {
+	type SystemData = BanPlayerData<'a>;
+
+	fn on_event(&mut self, evt: &CommandEvent, data: &mut Self::SystemData) {
+		if evt.1.com != "ban" {
+			return;
+		}
+
+		if let Err(e) = Self::do_command(evt, data) {
+			reply_err(&data.conns, evt.0, e);
+		}
+	}
+}
+
+impl EventHandlerTypeProvider for Unban {
+	type Event = CommandEvent;
+}
+
+impl<'a> EventHandler<'a> for Unban {
+	type SystemData = UnbanData<'a>;
+
+	fn on_event(&mut self, evt: &CommandEvent, data: &mut Self::SystemData) {
+		if evt.1.com != "unban" {
+			return;
+		}
+
+		if let Err(e) = Self::do_command(evt, data) {
+			reply_err(&data.conns, evt.0, e);
+		}
+	}
+}
+
+impl SystemInfo for BanPlayer {
+	type Dependencies = PacketHandler;
+
+	fn name() -> &'static str {
+		concat!(module_path!(), "::", line!())
+	}
+
+	fn new() -> Self {
+		Self::default()
+	}
+}
+
+impl SystemInfo for Unban {
+	type Dependencies = PacketHandler;
+
+	fn name() -> &'static str {
+		concat!(module_path!(), "::", line!())
+	}
+
+	fn new() -> Self {
+		Self::default()
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	#[test]
+	fn parse_ban_duration() {
+		assert_eq!(parse_duration(None).unwrap(), None);
+		assert_eq!(
+			parse_duration(Some("30")).unwrap(),
+			Some(Duration::from_secs(1800))
+		);
+		assert!(parse_duration(Some("forever")).is_err());
+	}
+}
@@ -0,0 +1,94 @@
+use specs::*;
+use types::*;
+
+use component::event::CommandEvent;
+use component::flag::{IsKicked, IsPlayer};
+use systems::PacketHandler;
+use SystemInfo;
+
+use utils::{EventHandler, EventHandlerTypeProvider};
+
+use super::*;
+
+/// Disconnect a player from the server.
+///
+/// Usage: `kick <id>`
+#[derive(Default)]
+pub struct Kick;
+
+#[derive(SystemData)]
+pub struct KickData<'a> {
+	entities: Entities<'a>,
+	conns: Read<'a, Connections>,
+
+	roles: ReadStorage<'a, Role>,
+	names: ReadStorage<'a, Name>,
+	is_player: ReadStorage<'a, IsPlayer>,
+	is_kicked: WriteStorage<'a, IsKicked>,
+}
+
+impl Kick {
+	fn do_command<'a, 'b>(
+		evt: &'b CommandEvent,
+		data: &mut KickData<'a>,
+	) -> Result<(), ModerationError<'b>> {
+		let &(conn, ref packet) = evt;
+
+		let player = match data.conns.associated_player(conn) {
+			Some(p) => p,
+			None => return Ok(()),
+		};
+
+		if get_role(&data.roles, player) < Role::Moderator {
+			return Err(ModerationError::PermissionDenied);
+		}
+
+		let target = get_target(
+			player,
+			packet.data.split_whitespace().next(),
+			&data.entities,
+			&data.is_player,
+			&data.roles,
+		)?;
+
+		kick_player(&data.conns, &mut data.is_kicked, target);
+
+		info!("Player {:?} was kicked by {:?}", target, player);
+
+		if let Some(name) = data.names.get(target) {
+			reply(&data.conns, conn, format!("Kicked {}", name.0));
+		}
+
+		Ok(())
+	}
+}
+
+impl EventHandlerTypeProvider for Kick {
+	type Event = CommandEvent;
+}
+
+impl<'a> EventHandler<'a> for Kick {
+	type SystemData = KickData<'a>;
+
+	fn on_event(&mut self, evt: &CommandEvent, data: &mut Self::SystemData) {
+		if evt.1.com != "kick" {
+			return;
+		}
+
+		if let Err(e) = Self::do_command(evt, data) {
+			reply_err(&data.conns, evt.0, e);
+		}
+	}
+}
+
+impl SystemInfo for Kick {
+	type Dependencies = PacketHandler;
+
+	fn name() -> &'static str {
+		concat!(module_path!(), "::", line!())
+	}
+
+	fn new() -> Self {
+		Self::default()
+	}
+}
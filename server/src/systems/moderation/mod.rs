@@ -0,0 +1,112 @@
+//! Commands for moderators to deal with
+//! misbehaving players.
+//!
+//! All commands take the id of the target player
+//! as their first argument and can only be used
+//! on players with a lower role than the player
+//! issuing the command.
+//!
+//! Players who keep killing their teammates are
+//! also kicked or banned automatically.
+
+mod register;
+
+mod ban;
+mod kick;
+mod mute;
+mod team_kills;
+
+pub use self::register::register;
+
+pub use self::ban::{BanPlayer, Unban};
+pub use self::kick::Kick;
+pub use self::mute::{Mute, Unmute};
+pub use self::team_kills::PunishTeamKills;
+
+use specs::*;
+use types::*;
+
+use component::flag::{IsKicked, IsPlayer};
+use protocol::server::CommandReply;
+use protocol::CommandReplyType;
+
+use serde_json;
+
+#[derive(Debug, Serialize)]
+pub enum ModerationError<'a> {
+	PermissionDenied,
+	MissingArguments,
+	InvalidPlayerId(&'a str),
+	NoSuchPlayer(u16),
+	InvalidDuration(&'a str),
+	InvalidAddress(&'a str),
+	NotBanned(&'a str),
+	NotConnected(u16),
+	Io(String),
+}
+
+/// Parse the target player out of the command
+/// arguments and check that `source` is allowed
+/// to moderate them.
+fn get_target<'a>(
+	source: Entity,
+	arg: Option<&'a str>,
+	entities: &EntitiesRes,
+	is_player: &ReadStorage<IsPlayer>,
+	roles: &ReadStorage<Role>,
+) -> Result<Entity, ModerationError<'a>> {
+	let arg = arg.ok_or(ModerationError::MissingArguments)?;
+	let id: u16 = arg
+		.parse()
+		.map_err(|_| ModerationError::InvalidPlayerId(arg))?;
+
+	let target = entities.entity(id as u32);
+	if !entities.is_alive(target) || is_player.get(target).is_none() {
+		return Err(ModerationError::NoSuchPlayer(id));
+	}
+
+	if get_role(roles, target) >= get_role(roles, source) {
+		return Err(ModerationError::PermissionDenied);
+	}
+
+	Ok(target)
+}
+
+fn get_role(roles: &ReadStorage<Role>, player: Entity) -> Role {
+	roles.get(player).cloned().unwrap_or_default()
+}
+
+/// Close all connections of a player and mark
+/// them so that they are removed immediately
+/// instead of waiting for a backup connection.
+pub(crate) fn kick_player(
+	conns: &Connections,
+	is_kicked: &mut WriteStorage<IsKicked>,
+	player: Entity,
+) {
+	is_kicked.insert(player, IsKicked).unwrap();
+
+	for conn in conns.iter().filter(|c| c.player == Some(player)) {
+		conns.close(conn.id);
+	}
+}
+
+fn reply(conns: &Connections, conn: ConnectionId, text: String) {
+	conns.send_to(
+		conn,
+		CommandReply {
+			ty: CommandReplyType::ShowInConsole,
+			text,
+		},
+	);
+}
+
+fn reply_err(conns: &Connections, conn: ConnectionId, e: ModerationError) {
+	conns.send_to(
+		conn,
+		CommandReply {
+			ty: CommandReplyType::ShowInPopup,
+			text: serde_json::to_string_pretty(&e).unwrap(),
+		},
+	);
+}
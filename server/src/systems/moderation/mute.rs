@@ -0,0 +1,140 @@
+use specs::*;
+use types::*;
+
+use component::event::CommandEvent;
+use component::flag::{IsChatMuted, IsPlayer};
+use systems::PacketHandler;
+use SystemInfo;
+
+use utils::{EventHandler, EventHandlerTypeProvider};
+
+use super::*;
+
+/// Prevent a player from chatting.
+///
+/// Usage: `mute <id>`
+#[derive(Default)]
+pub struct Mute;
+
+/// Allow a muted player to chat again. This
+/// also works for players that were votemuted.
+///
+/// Usage: `unmute <id>`
+#[derive(Default)]
+pub struct Unmute;
+
+#[derive(SystemData)]
+pub struct MuteData<'a> {
+	entities: Entities<'a>,
+	conns: Read<'a, Connections>,
+
+	roles: ReadStorage<'a, Role>,
+	names: ReadStorage<'a, Name>,
+	is_player: ReadStorage<'a, IsPlayer>,
+	is_muted: WriteStorage<'a, IsChatMuted>,
+}
+
+fn set_muted<'a, 'b>(
+	evt: &'b CommandEvent,
+	data: &mut MuteData<'a>,
+	muted: bool,
+) -> Result<(), ModerationError<'b>> {
+	let &(conn, ref packet) = evt;
+
+	let player = match data.conns.associated_player(conn) {
+		Some(p) => p,
+		None => return Ok(()),
+	};
+
+	if get_role(&data.roles, player) < Role::Moderator {
+		return Err(ModerationError::PermissionDenied);
+	}
+
+	let target = get_target(
+		player,
+		packet.data.split_whitespace().next(),
+		&data.entities,
+		&data.is_player,
+		&data.roles,
+	)?;
+
+	let name = data
+		.names
+		.get(target)
+		.map(|x| x.0.clone())
+		.unwrap_or_default();
+
+	if muted {
+		data.is_muted.insert(target, IsChatMuted).unwrap();
+
+		info!("Player {:?} was muted by {:?}", target, player);
+		reply(&data.conns, conn, format!("Muted {}", name));
+	} else {
+		data.is_muted.remove(target);
+
+		info!("Player {:?} was unmuted by {:?}", target, player);
+		reply(&data.conns, conn, format!("Unmuted {}", name));
+	}
+
+	Ok(())
+}
+
+impl EventHandlerTypeProvider for Mute {
+	type Event = CommandEvent;
+}
+
+impl<'a> EventHandler<'a> for Mute {
+	type SystemData = MuteData<'a>;
+
+	fn on_event(&mut self, evt: &CommandEvent, data: &mut Self::SystemData) {
+		if evt.1.com != "mute" {
+			return;
+		}
+
+		if let Err(e) = set_muted(evt, data, true) {
+			reply_err(&data.conns, evt.0, e);
+		}
+	}
+}
+
+impl EventHandlerTypeProvider for Unmute {
+	type Event = CommandEvent;
+}
+
+impl<'a> EventHandler<'a> for Unmute {
+	type SystemData = MuteData<'a>;
+
+	fn on_event(&mut self, evt: &CommandEvent, data: &mut Self::SystemData) {
+		if evt.1.com != "unmute" {
+			return;
+		}
+
+		if let Err(e) = set_muted(evt, data, false) {
+			reply_err(&data.conns, evt.0, e);
+		}
+	}
+}
+
+impl SystemInfo for Mute {
+	type Dependencies = PacketHandler;
+
+	fn name() -> &'static str {
+		concat!(module_path!(), "::", line!())
+	}
+
+	fn new() -> Self {
+		Self::default()
+	}
+}
+
+impl SystemInfo for Unmute {
+	type Dependencies = PacketHandler;
+
+	fn name() -> &'static str {
+		concat!(module_path!(), "::", line!())
+	}
+
+	fn new() -> Self {
+		Self::default()
+	}
+}
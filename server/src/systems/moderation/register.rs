@@ -0,0 +1,13 @@
+use dispatch::Builder;
+
+use super::*;
+
+pub fn register<'a, 'b>(builder: Builder<'a, 'b>) -> Builder<'a, 'b> {
+	builder
+		.with_handler::<Kick>()
+		.with_handler::<BanPlayer>()
+		.with_handler::<Unban>()
+		.with_handler::<Mute>()
+		.with_handler::<Unmute>()
+		.with_handler::<PunishTeamKills>()
+}
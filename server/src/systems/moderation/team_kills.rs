@@ -0,0 +1,158 @@
+use specs::*;
+use types::*;
+
+use std::time::SystemTime;
+
+use component::event::PlayerKilled;
+use component::flag::IsKicked;
+use component::time::ThisFrame;
+use systems::handlers::game::on_player_killed::UpdateScore;
+use SystemInfo;
+
+use utils::{EventHandler, EventHandlerTypeProvider};
+
+use super::*;
+
+/// Kick or ban players once they have killed
+/// more teammates than the friendly fire config
+/// allows.
+///
+/// Team kills are counted per IP address in the
+/// [`TeamKillRecord`], so players that get kicked
+/// and join again still end up banned if they keep
+/// killing teammates within the config's
+/// `team_kill_window`.
+///
+/// Moderators and admins are exempt.
+#[derive(Default)]
+pub struct PunishTeamKills;
+
+#[derive(SystemData)]
+pub struct PunishTeamKillsData<'a> {
+	conns: Read<'a, Connections>,
+	config: Read<'a, Config>,
+	this_frame: Read<'a, ThisFrame>,
+	bans: Write<'a, BanList>,
+	record: Write<'a, TeamKillRecord>,
+
+	teams: ReadStorage<'a, Team>,
+	roles: ReadStorage<'a, Role>,
+	names: ReadStorage<'a, Name>,
+	is_kicked: WriteStorage<'a, IsKicked>,
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+enum Punishment {
+	Kick,
+	Ban,
+}
+
+fn punishment(config: &FriendlyFireConfig, count: u32) -> Option<Punishment> {
+	let reached = |threshold: Option<u32>| threshold.map(|t| count >= t).unwrap_or(false);
+
+	if reached(config.ban_team_kills) {
+		Some(Punishment::Ban)
+	} else if reached(config.kick_team_kills) {
+		Some(Punishment::Kick)
+	} else {
+		None
+	}
+}
+
+impl EventHandlerTypeProvider for PunishTeamKills {
+	type Event = PlayerKilled;
+}
+
+impl<'a> EventHandler<'a> for PunishTeamKills {
+	type SystemData = PunishTeamKillsData<'a>;
+
+	fn on_event(&mut self, evt: &PlayerKilled, data: &mut Self::SystemData) {
+		let ref config = data.config.friendly_fire;
+		let killer = evt.killer;
+
+		if killer == evt.player || data.teams.get(killer) != data.teams.get(evt.player) {
+			return;
+		}
+		if data.is_kicked.get(killer).is_some() {
+			return;
+		}
+
+		let addr = match data.conns.iter().find(|c| c.player == Some(killer)) {
+			Some(conn) => conn.info.addr,
+			None => return,
+		};
+		let count = data
+			.record
+			.add(addr, data.this_frame.0, config.team_kill_window);
+		let name = data.names.get(killer).map(|x| &*x.0).unwrap_or("<unknown>");
+
+		info!("Player {:?} ({}) has {} team kills", killer, name, count);
+
+		if get_role(&data.roles, killer) > Role::Player {
+			return;
+		}
+
+		match punishment(config, count) {
+			Some(Punishment::Ban) => {
+				let ban = Ban {
+					addr,
+					expiry: config.ban_duration.map(|d| SystemTime::now() + d),
+					reason: format!("Killed {} teammates", count),
+				};
+
+				if let Err(e) = data.bans.ban(ban) {
+					error!("Failed to save ban for {}: {}", addr, e);
+				}
+				data.record.clear(addr);
+
+				info!(
+					"Player {:?} ({}) with addr {} was banned",
+					killer, name, addr
+				);
+			}
+			Some(Punishment::Kick) => {
+				info!("Player {:?} ({}) was kicked", killer, name);
+			}
+			None => return,
+		}
+
+		kick_player(&data.conns, &mut data.is_kicked, killer);
+	}
+}
+
+impl SystemInfo for PunishTeamKills {
+	type Dependencies = UpdateScore;
+
+	fn name() -> &'static str {
+		concat!(module_path!(), "::", line!())
+	}
+
+	fn new() -> Self {
+		Self::default()
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	use std::time::Instant;
+
+	#[test]
+	fn rejoining_keeps_team_kills() {
+		let config = FriendlyFireConfig::default();
+		let addr = "10.0.0.1".parse().unwrap();
+		let now = Instant::now();
+		let mut record = TeamKillRecord::default();
+
+		let mut punish = || punishment(&config, record.add(addr, now, config.team_kill_window));
+
+		assert_eq!(punish(), None);
+		assert_eq!(punish(), None);
+		assert_eq!(punish(), Some(Punishment::Kick));
+		// The player joins again after being kicked,
+		// which doesn't affect the record.
+		assert_eq!(punish(), Some(Punishment::Kick));
+		assert_eq!(punish(), Some(Punishment::Ban));
+	}
+}
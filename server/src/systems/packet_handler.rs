@@ -5,10 +5,12 @@ use specs::*;
 
 use std::any::Any;
 use std::mem;
+use std::sync::atomic::Ordering;
 use std::sync::mpsc::{channel, Receiver};
 
 use component::channel::*;
 use component::event::*;
+use consts::PACKETS_RECEIVED;
 use dispatch::*;
 use types::event::*;
 use types::*;
@@ -92,6 +94,8 @@ impl<'a> System<'a> for PacketHandler {
 					sysdata.onclose.single_write(conn);
 				}
 				ConnectionEvent::Message(msg) => {
+					PACKETS_RECEIVED.fetch_add(1, Ordering::Relaxed);
+
 					match protocol.deserialize(&msg.msg) {
 						Ok(packet) => Self::dispatch(&mut sysdata, msg.conn, packet),
 						Err(_) => sysdata.onbinary.single_write((msg.conn, msg.msg.clone())),
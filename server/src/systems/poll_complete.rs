@@ -6,13 +6,19 @@ use types::connection::{Message, MessageBody, MessageInfo};
 use types::*;
 
 use component::collision::PlaneGrid;
+use component::horizon::Horizon;
+use component::time::ThisFrame;
+use replay::ReplayRecorder;
 
 use ws::CloseCode;
 
 use protocol::Protocol;
 use protocol_v5::ProtocolV5;
 
+use consts::PACKETS_SENT;
+
 use std::mem;
+use std::sync::atomic::Ordering;
 use std::sync::mpsc::{channel, Receiver};
 
 pub struct PollComplete {
@@ -24,10 +30,14 @@ pub struct PollCompleteData<'a> {
 	conns: Read<'a, Connections>,
 	config: Read<'a, Config>,
 	grid: Read<'a, PlaneGrid>,
+	this_frame: Read<'a, ThisFrame>,
+	recorder: Write<'a, ReplayRecorder>,
 	entities: Entities<'a>,
 
 	associated: ReadStorage<'a, AssociatedConnection>,
 	teams: ReadStorage<'a, Team>,
+	pos: ReadStorage<'a, Position>,
+	horizon: ReadStorage<'a, Horizon>,
 }
 
 impl PollComplete {
@@ -46,7 +56,10 @@ impl PollComplete {
 
 		match conns.conns.get(&id).map(|ref x| x.sink.clone()) {
 			Some(mut conn) => match msg {
-				Some(msg) => Connections::send_sink(&mut conn, msg.into()),
+				Some(msg) => {
+					PACKETS_SENT.fetch_add(1, Ordering::Relaxed);
+					Connections::send_sink(&mut conn, msg.into())
+				}
 				None => conn.close(CloseCode::Normal).unwrap(),
 			},
 			// The connection probably closed,
@@ -58,20 +71,51 @@ impl PollComplete {
 			),
 		}
 	}
+
+	/// Whether a position is within the horizon
+	/// of the given player.
+	fn is_visible<'a>(
+		pos: Position,
+		player: Entity,
+		player_pos: &ReadStorage<'a, Position>,
+		horizon: &ReadStorage<'a, Horizon>,
+	) -> bool {
+		match (player_pos.get(player), horizon.get(player)) {
+			(Some(&player_pos), Some(horizon)) => {
+				let dist = pos - player_pos;
+
+				dist.x.inner().abs() <= horizon.x.inner()
+					&& dist.y.inner().abs() <= horizon.y.inner()
+			}
+			_ => true,
+		}
+	}
 }
 
 impl<'a> System<'a> for PollComplete {
 	type SystemData = PollCompleteData<'a>;
 
 	fn run(&mut self, data: Self::SystemData) {
+		let mut recorder = data.recorder;
+		let this_frame = data.this_frame.0;
 		let conns = data.conns;
 		let config = data.config;
 		let associated = data.associated;
 		let grid = data.grid;
 		let teams = data.teams;
+		let player_pos = data.pos;
+		let horizon = data.horizon;
 		let entities = &*data.entities;
 		let protocol = ProtocolV5 {};
 
+		// Horizons are clamped to the config maximums, so
+		// nobody outside of this radius can see anything.
+		let view_radius = config
+			.max_horizon_x
+			.inner()
+			.max(config.max_horizon_y.inner())
+			.max(config.view_radius.inner());
+
 		let start = Instant::now();
 		while let Ok(msg) = self.channel.try_recv() {
 			let data: Option<Vec<u8>> = match msg.msg {
@@ -82,8 +126,27 @@ impl<'a> System<'a> for PollComplete {
 				MessageBody::Close => None,
 			};
 
+			// Only packets that are sent to everyone (or
+			// everyone that can see something) make it into
+			// the replay. Messages to individual connections
+			// or teams would leak private information.
+			let record = match msg.info {
+				MessageInfo::Broadcast(_) | MessageInfo::ToVisible(_) => true,
+				MessageInfo::ToConnection(_) | MessageInfo::ToTeam(_) => false,
+			};
+			if record && recorder.is_enabled() {
+				if let Some(ref data) = data {
+					recorder.record(this_frame, data);
+				}
+			}
+
 			match msg.info {
 				MessageInfo::ToConnection(id) => Self::send_to_connection(&conns, id, data),
+				MessageInfo::Broadcast(recipients) => {
+					for id in recipients {
+						Self::send_to_connection(&conns, id, data.clone());
+					}
+				}
 				MessageInfo::ToTeam(player) => {
 					let player_team = *teams.get(player).unwrap();
 
@@ -99,11 +162,12 @@ impl<'a> System<'a> for PollComplete {
 					grid.0
 						.rough_collide(HitCircle {
 							pos: pos,
-							rad: config.view_radius,
+							rad: Distance::new(view_radius),
 							layer: 0,
 							ent: ent,
 						})
 						.into_iter()
+						.filter(|x| Self::is_visible(pos, *x, &player_pos, &horizon))
 						.filter_map(|x| associated.get(x))
 						.for_each(|associated| {
 							Self::send_to_connection(&conns, associated.0, data.clone())
@@ -112,6 +176,8 @@ impl<'a> System<'a> for PollComplete {
 			}
 		}
 
+		recorder.flush();
+
 		let time = Instant::now() - start;
 		trace!(
 			"System {} took {}.{:3} ms",
@@ -12,6 +12,7 @@ use std::marker::PhantomData;
 use std::time::Duration;
 
 use component::flag::{ForcePlayerUpdate, IsPlayer};
+use component::prediction::ClientPrediction;
 use component::time::*;
 use protocol::server::PlayerUpdate;
 use protocol::Upgrades as ServerUpgrades;
@@ -49,12 +50,14 @@ pub struct PositionUpdateData<'a> {
 	powerups: ReadStorage<'a, Powerups>,
 	planes: ReadStorage<'a, Plane>,
 	force_update: WriteStorage<'a, ForcePlayerUpdate>,
+	prediction: WriteStorage<'a, ClientPrediction>,
 	is_player: ReadStorage<'a, IsPlayer>,
 
 	lastframe: Read<'a, LastFrame>,
 	thisframe: Read<'a, ThisFrame>,
 	entities: Entities<'a>,
 	conns: Read<'a, Connections>,
+	map: Read<'a, Map>,
 	is_alive: IsAlive<'a>,
 	clock: ReadClock<'a>,
 }
@@ -64,8 +67,20 @@ impl PositionUpdate {
 		Self::default()
 	}
 
-	fn step_players<'a>(data: &mut PositionUpdateData<'a>, config: &Read<'a, Config>) {
+	/// Length of this frame and the bounds that
+	/// planes are kept within.
+	fn frame_params<'a>(data: &PositionUpdateData<'a>) -> (Time, Position) {
 		let delta = Time::from(data.thisframe.0 - data.lastframe.0);
+		let bound = Position::new(
+			data.map.bounds.x - Distance::new(32.0),
+			data.map.bounds.y - Distance::new(32.0),
+		);
+
+		(delta, bound)
+	}
+
+	fn step_players<'a>(data: &mut PositionUpdateData<'a>, config: &Read<'a, Config>) {
+		let (delta, bound) = Self::frame_params(data);
 
 		let PositionUpdateData {
 			entities,
@@ -96,98 +111,98 @@ impl PositionUpdate {
 				let powerups = powerups.get(ent);
 				(pos, rot, vel, keystate, upgrades, powerups, plane)
 			})
-			.for_each(|(pos, rot, vel, keystate, upgrades, powerups, plane, ..)| {
-				let mut movement_angle = None;
-				let info = &config.planes[*plane];
-				let boost_factor = if keystate.boost(&plane) {
-					info.boost_factor
-				} else {
-					1.0
+			.for_each(|(pos, rot, vel, keystate, upgrades, powerups, plane)| {
+				let ctx = PlaneStepCtx {
+					keystate,
+					upgrades,
+					powerups,
+					plane,
+					config,
+					delta,
+					bound,
 				};
 
-				if keystate.strafe(plane) {
-					if keystate.left {
-						movement_angle = Some(*rot - FRAC_PI_2);
-					}
-					if keystate.right {
-						movement_angle = Some(*rot + FRAC_PI_2);
-					}
-				} else {
-					if keystate.left {
-						*rot += -delta * info.turn_factor;
-					}
-					if keystate.right {
-						*rot += delta * info.turn_factor;
-					}
-				}
-
-				if keystate.up {
-					if let Some(angle) = movement_angle {
-						if keystate.right {
-							movement_angle = Some(angle + PI * (-0.25));
-						} else if keystate.left {
-							movement_angle = Some(angle + PI * (0.25));
-						}
-					} else {
-						movement_angle = Some(*rot);
-					}
-				} else if keystate.down {
-					if let Some(angle) = movement_angle {
-						if keystate.right {
-							movement_angle = Some(angle + PI * (0.25));
-						} else if keystate.left {
-							movement_angle = Some(angle + PI * (-0.25));
-						}
-					} else {
-						movement_angle = Some(*rot + PI);
-					}
-				}
-
-				if let Some(angle) = movement_angle {
-					let mult = info.accel_factor * delta * boost_factor;
-					*vel += Velocity::new(mult * angle.sin(), mult * -angle.cos());
-				}
-
-				let oldspeed = *vel;
-				let speed_len = vel.length();
-				let mut max_speed = info.max_speed * boost_factor;
-				let min_speed = info.min_speed;
-
-				// Need to fill out config more
-				if upgrades.speed != 0 {
-					max_speed *= config.upgrades.speed.factor[upgrades.speed as usize]
-				}
+				step_plane(pos, rot, vel, &ctx)
+			});
+	}
 
-				if powerups.inferno() {
-					max_speed *= info.inferno_factor;
-				}
+	/// Simulate planes the same way that clients do
+	/// and send an update for every plane where the
+	/// client has drifted too far from the server.
+	fn check_predictions<'a>(
+		&mut self,
+		data: &mut PositionUpdateData<'a>,
+		config: &Read<'a, Config>,
+	) {
+		let (delta, bound) = Self::frame_params(data);
+		let threshold = config.max_prediction_error;
 
-				if keystate.flagspeed {
-					max_speed = info.flag_speed;
-				}
+		let PositionUpdateData {
+			entities,
+			prediction,
+			pos,
+			keystate,
+			upgrades,
+			powerups,
+			planes,
+			is_alive,
+			is_player,
+			..
+		} = data;
 
-				if speed_len > max_speed {
-					*vel *= max_speed / speed_len;
-				} else {
-					if vel.x.abs() > min_speed || vel.y.abs() > min_speed {
-						*vel *= 1.0 - (info.brake_factor * delta).inner();
-					} else {
-						*vel = Velocity::default()
-					}
-				}
+		for (ent, predicted, actual, keystate, upgrades, plane, ..) in (
+			&*entities,
+			prediction,
+			&*pos,
+			&*keystate,
+			&*upgrades,
+			&*planes,
+			is_alive.mask() & is_player.mask(),
+		)
+			.join()
+		{
+			let ctx = PlaneStepCtx {
+				keystate,
+				upgrades,
+				powerups: powerups.get(ent),
+				plane,
+				config,
+				delta,
+				bound,
+			};
+
+			step_plane(
+				&mut predicted.pos,
+				&mut predicted.rot,
+				&mut predicted.vel,
+				&ctx,
+			);
+
+			if (predicted.pos - *actual).length() > threshold {
+				self.dirty.add(ent.id());
+			}
+		}
+	}
 
-				*pos += oldspeed * delta + (*vel - oldspeed) * delta * 0.5;
-				*rot = (*rot % PIx2 + PIx2) % PIx2;
+	/// Clients that were sent an update this frame
+	/// are back in sync with the server.
+	fn reset_predictions<'a>(
+		data: &mut PositionUpdateData<'a>,
+		lastupdate: &WriteStorage<'a, LastUpdate>,
+	) {
+		let thisframe = data.thisframe.0;
 
-				let bound = Position::new(Distance::new(16352.0), Distance::new(8160.0));
+		for (ent, &pos, &rot, &vel, last) in
+			(&*data.entities, &data.pos, &data.rot, &data.vel, lastupdate).join()
+		{
+			if last.0 != thisframe {
+				continue;
+			}
 
-				if pos.x.abs() > bound.x {
-					pos.x = pos.x.signum() * bound.x
-				}
-				if pos.y.abs() > bound.y {
-					pos.y = pos.y.signum() * bound.y
-				}
-			});
+			data.prediction
+				.insert(ent, ClientPrediction { pos, rot, vel })
+				.unwrap();
+		}
 	}
 
 	fn send_updates<'a>(
@@ -314,6 +329,121 @@ impl PositionUpdate {
 	}
 }
 
+/// Everything that goes into advancing a plane
+/// besides its current position and velocity.
+#[derive(Copy, Clone)]
+struct PlaneStepCtx<'c> {
+	keystate: &'c KeyState,
+	upgrades: &'c Upgrades,
+	powerups: Option<&'c Powerups>,
+	plane: &'c Plane,
+	config: &'c Config,
+	delta: Time,
+	bound: Position,
+}
+
+/// Advance a plane by one frame, based on the
+/// keys that are pressed.
+///
+/// This is the same simulation that clients
+/// run to predict the movement of planes.
+fn step_plane(pos: &mut Position, rot: &mut Rotation, vel: &mut Velocity, ctx: &PlaneStepCtx) {
+	let PlaneStepCtx {
+		keystate,
+		upgrades,
+		powerups,
+		plane,
+		config,
+		delta,
+		bound,
+	} = *ctx;
+	let mut movement_angle = None;
+	let info = &config.planes[*plane];
+	let boost_factor = if keystate.boost(&plane) {
+		info.boost_factor
+	} else {
+		1.0
+	};
+
+	if keystate.strafe(plane) {
+		if keystate.left {
+			movement_angle = Some(*rot - FRAC_PI_2);
+		}
+		if keystate.right {
+			movement_angle = Some(*rot + FRAC_PI_2);
+		}
+	} else {
+		if keystate.left {
+			*rot += -delta * info.turn_factor;
+		}
+		if keystate.right {
+			*rot += delta * info.turn_factor;
+		}
+	}
+
+	if keystate.up {
+		if let Some(angle) = movement_angle {
+			if keystate.right {
+				movement_angle = Some(angle + PI * (-0.25));
+			} else if keystate.left {
+				movement_angle = Some(angle + PI * (0.25));
+			}
+		} else {
+			movement_angle = Some(*rot);
+		}
+	} else if keystate.down {
+		if let Some(angle) = movement_angle {
+			if keystate.right {
+				movement_angle = Some(angle + PI * (0.25));
+			} else if keystate.left {
+				movement_angle = Some(angle + PI * (-0.25));
+			}
+		} else {
+			movement_angle = Some(*rot + PI);
+		}
+	}
+
+	if let Some(angle) = movement_angle {
+		let mult = info.accel_factor * delta * boost_factor;
+		*vel += Velocity::new(mult * angle.sin(), mult * -angle.cos());
+	}
+
+	let oldspeed = *vel;
+	let speed_len = vel.length();
+	let mut max_speed = info.max_speed * boost_factor;
+	let min_speed = info.min_speed;
+
+	max_speed *= config.upgrades.speed.factor[upgrades.speed as usize];
+
+	if powerups.inferno() {
+		max_speed *= info.inferno_factor;
+	}
+
+	if keystate.flagspeed {
+		max_speed = info.flag_speed;
+	}
+
+	if speed_len > max_speed {
+		*vel *= max_speed / speed_len;
+	} else {
+		if vel.x.abs() > min_speed || vel.y.abs() > min_speed {
+			*vel *= 1.0 - (info.brake_factor * delta).inner();
+		} else {
+			*vel = Velocity::default()
+		}
+	}
+
+	*pos += oldspeed * delta + (*vel - oldspeed) * delta * 0.5;
+	*rot = (*rot % PIx2 + PIx2) % PIx2;
+
+	if pos.x.abs() > bound.x {
+		pos.x = pos.x.signum() * bound.x
+	}
+	if pos.y.abs() > bound.y {
+		pos.y = pos.y.signum() * bound.y
+	}
+}
+
 impl<'a> System<'a> for PositionUpdate {
 	type SystemData = (
 		PositionUpdateData<'a>,
@@ -340,8 +470,10 @@ impl<'a> System<'a> for PositionUpdate {
 		}
 
 		Self::step_players(&mut data, &config);
+		self.check_predictions(&mut data, &config);
 		self.send_updates(&mut data, &mut lastupdate);
 		self.send_outdated(&mut data, &mut lastupdate);
+		Self::reset_predictions(&mut data, &lastupdate);
 
 		data.force_update.clear();
 	}
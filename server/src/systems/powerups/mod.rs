@@ -1,7 +1,13 @@
 mod check_expired;
+mod pickup_powerup;
+mod send_on_join;
+mod spawn_powerups;
 
 mod register;
 
 pub use self::check_expired::CheckExpired;
+pub use self::pickup_powerup::PickupPowerup;
+pub use self::send_on_join::SendPowerupsOnJoin;
+pub use self::spawn_powerups::SpawnPowerups;
 
 pub use self::register::register;
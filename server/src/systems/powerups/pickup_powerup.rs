@@ -0,0 +1,85 @@
+use specs::*;
+
+use SystemInfo;
+
+use types::*;
+
+use component::channel::*;
+use component::event::*;
+use component::flag::*;
+use protocol::PowerupType;
+
+use systems::collision::{pickups, PlayerPowerupCollisionSystem};
+
+/// Give the powerup in a crate to the player that
+/// ran into it and remove the crate.
+#[derive(Default)]
+pub struct PickupPowerup {
+	reader: Option<OnPlayerPowerupCollisionReader>,
+}
+
+#[derive(SystemData)]
+pub struct PickupPowerupData<'a> {
+	channel: Read<'a, OnPlayerPowerupCollision>,
+	powerup_channel: Write<'a, OnPlayerPowerup>,
+	despawn_channel: Write<'a, OnMobDespawn>,
+	config: Read<'a, Config>,
+	entities: Entities<'a>,
+
+	mob: ReadStorage<'a, Mob>,
+	is_player: ReadStorage<'a, IsPlayer>,
+}
+
+impl<'a> System<'a> for PickupPowerup {
+	type SystemData = PickupPowerupData<'a>;
+
+	fn setup(&mut self, res: &mut Resources) {
+		Self::SystemData::setup(res);
+
+		self.reader = Some(
+			res.fetch_mut::<OnPlayerPowerupCollision>()
+				.register_reader(),
+		);
+	}
+
+	fn run(&mut self, mut data: Self::SystemData) {
+		let collisions = data
+			.channel
+			.read(self.reader.as_mut().unwrap())
+			.map(|evt| &evt.0);
+
+		for (player, powerup) in pickups(collisions, &data.entities, &data.is_player) {
+			let mob = match data.mob.get(powerup.ent) {
+				Some(&mob) => mob,
+				None => continue,
+			};
+			let (ty, duration) = match mob {
+				Mob::Shield => (PowerupType::Shield, data.config.shield_duration),
+				Mob::Inferno => (PowerupType::Inferno, data.config.inferno_duration),
+				_ => continue,
+			};
+
+			data.powerup_channel.single_write(PlayerPowerup {
+				player: player.ent,
+				duration,
+				ty,
+			});
+			data.despawn_channel.single_write(MobDespawnEvent {
+				mob: powerup.ent,
+				ty: mob,
+			});
+		}
+	}
+}
+
+impl SystemInfo for PickupPowerup {
+	type Dependencies = PlayerPowerupCollisionSystem;
+
+	fn name() -> &'static str {
+		concat!(module_path!(), "::", line!())
+	}
+
+	fn new() -> Self {
+		Self::default()
+	}
+}
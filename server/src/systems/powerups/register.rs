@@ -2,5 +2,9 @@ use super::*;
 use dispatch::Builder;
 
 pub fn register<'a, 'b>(builder: Builder<'a, 'b>) -> Builder<'a, 'b> {
-	builder.with::<CheckExpired>()
+	builder
+		.with::<CheckExpired>()
+		.with::<SpawnPowerups>()
+		.with::<PickupPowerup>()
+		.with_handler::<SendPowerupsOnJoin>()
 }
@@ -0,0 +1,62 @@
+use specs::*;
+use types::*;
+
+use component::event::PlayerJoin;
+use component::flag::IsPowerup;
+use protocol::server::MobUpdateStationary;
+
+use systems::handlers::game::on_join::AllJoinHandlers;
+use utils::{EventHandler, EventHandlerTypeProvider};
+use SystemInfo;
+
+/// Let newly joined players know about all the
+/// powerup crates that are already on the map.
+#[derive(Default)]
+pub struct SendPowerupsOnJoin;
+
+#[derive(SystemData)]
+pub struct SendPowerupsOnJoinData<'a> {
+	conns: Read<'a, Connections>,
+
+	entities: Entities<'a>,
+	pos: ReadStorage<'a, Position>,
+	mob: ReadStorage<'a, Mob>,
+	is_powerup: ReadStorage<'a, IsPowerup>,
+}
+
+impl EventHandlerTypeProvider for SendPowerupsOnJoin {
+	type Event = PlayerJoin;
+}
+
+impl<'a> EventHandler<'a> for SendPowerupsOnJoin {
+	type SystemData = SendPowerupsOnJoinData<'a>;
+
+	fn on_event(&mut self, evt: &PlayerJoin, data: &mut Self::SystemData) {
+		let conns = &data.conns;
+
+		(&*data.entities, &data.pos, &data.mob, data.is_powerup.mask())
+			.join()
+			.for_each(|(ent, &pos, &mob, ..)| {
+				conns.send_to_player(
+					evt.id,
+					MobUpdateStationary {
+						id: ent.into(),
+						ty: mob,
+						pos,
+					},
+				);
+			});
+	}
+}
+
+impl SystemInfo for SendPowerupsOnJoin {
+	type Dependencies = AllJoinHandlers;
+
+	fn name() -> &'static str {
+		concat!(module_path!(), "::", line!())
+	}
+
+	fn new() -> Self {
+		Self::default()
+	}
+}
@@ -0,0 +1,118 @@
+use specs::*;
+use types::*;
+
+use component::flag::IsPowerup;
+use component::time::{MobSpawnTime, ThisFrame};
+use consts::powerup::POWERUP_TEAM;
+use protocol::server::MobUpdateStationary;
+use protocol::PowerupType;
+use SystemInfo;
+
+use std::time::Instant;
+
+struct SpawnPoint {
+	spawn: PowerupSpawn,
+	/// The crate currently waiting to be picked up
+	current: Option<Entity>,
+	/// When the next crate should appear
+	next: Instant,
+}
+
+/// Place powerup crates at the spawn points given
+/// by the map and game mode, and put a new one
+/// down a while after each gets picked up.
+#[derive(Default)]
+pub struct SpawnPowerups {
+	points: Option<Vec<SpawnPoint>>,
+}
+
+#[derive(SystemData)]
+pub struct SpawnPowerupsData<'a> {
+	conns: Read<'a, Connections>,
+	this_frame: Read<'a, ThisFrame>,
+	map: Read<'a, Map>,
+	gamemode: GameModeWriter<'a, GameMode>,
+
+	entities: Entities<'a>,
+	pos: WriteStorage<'a, Position>,
+	mob: WriteStorage<'a, Mob>,
+	team: WriteStorage<'a, Team>,
+	is_powerup: WriteStorage<'a, IsPowerup>,
+	spawn_time: WriteStorage<'a, MobSpawnTime>,
+}
+
+impl<'a> System<'a> for SpawnPowerups {
+	type SystemData = SpawnPowerupsData<'a>;
+
+	fn run(&mut self, mut data: Self::SystemData) {
+		let now = data.this_frame.0;
+
+		// The game mode isn't available during setup so
+		// the spawn points are collected on the first run.
+		if self.points.is_none() {
+			let points = data
+				.map
+				.powerups
+				.iter()
+				.cloned()
+				.chain(data.gamemode.get().powerup_spawns())
+				.map(|spawn| SpawnPoint {
+					spawn,
+					current: None,
+					next: now,
+				})
+				.collect();
+
+			self.points = Some(points);
+		}
+
+		for point in self.points.as_mut().unwrap().iter_mut() {
+			if let Some(ent) = point.current {
+				// Still waiting to be picked up
+				if data.mob.get(ent).is_some() {
+					continue;
+				}
+
+				point.current = None;
+				point.next = now + point.spawn.next_delay();
+			}
+
+			if now < point.next {
+				continue;
+			}
+
+			let pos = point.spawn.pos;
+			let mob = match point.spawn.ty {
+				PowerupType::Shield => Mob::Shield,
+				PowerupType::Inferno => Mob::Inferno,
+			};
+
+			let ent = data.entities.create();
+			data.pos.insert(ent, pos).unwrap();
+			data.mob.insert(ent, mob).unwrap();
+			data.team.insert(ent, POWERUP_TEAM).unwrap();
+			data.is_powerup.insert(ent, IsPowerup).unwrap();
+			data.spawn_time.insert(ent, MobSpawnTime(now)).unwrap();
+
+			point.current = Some(ent);
+
+			data.conns.send_to_all(MobUpdateStationary {
+				id: ent.into(),
+				ty: mob,
+				pos,
+			});
+		}
+	}
+}
+
+impl SystemInfo for SpawnPowerups {
+	type Dependencies = ();
+
+	fn name() -> &'static str {
+		concat!(module_path!(), "::", line!())
+	}
+
+	fn new() -> Self {
+		Self::default()
+	}
+}
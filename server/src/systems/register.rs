@@ -3,6 +3,7 @@ use systems::missile;
 use systems::*;
 
 use dispatch::Builder;
+use status::UpdateStatus;
 
 pub fn register<'a, 'b>(disp: Builder<'a, 'b>) -> Builder<'a, 'b> {
 	disp.with::<run_futures::RunTimedFutures>()
@@ -16,6 +17,7 @@ pub fn register<'a, 'b>(disp: Builder<'a, 'b>) -> Builder<'a, 'b> {
 		.with::<EnergyRegenSystem>()
 		.with::<HealthRegenSystem>()
 		.with::<Disconnect>()
+		.with::<UpdateLevel>()
 		// Collision handling
 		.with_registrar(collision::register)
 		// Specials
@@ -26,6 +28,18 @@ pub fn register<'a, 'b>(disp: Builder<'a, 'b>) -> Builder<'a, 'b> {
 		.with_registrar(upgrades::register)
 		// Admin/Debug Commands
 		.with_registrar(admin::register)
+		// Moderator Commands
+		.with_registrar(moderation::register)
+		// Anti-cheat
+		.with_registrar(anticheat::register)
 		// Powerups
 		.with_registrar(powerups::register)
+		// Bots
+		.with_registrar(bots::register)
+		// AFK detection
+		.with_registrar(afk::register)
+		// Votemutes
+		.with_registrar(votemute::register)
+		// Status page
+		.with::<UpdateStatus>()
 }
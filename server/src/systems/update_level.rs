@@ -0,0 +1,66 @@
+use specs::*;
+use types::account::level_for_earnings;
+use types::*;
+
+use component::counter::Earnings;
+use dispatch::SystemInfo;
+
+use protocol::server::PlayerLevel;
+use protocol::PlayerLevelType;
+
+/// Level up players with an account once they
+/// have enough earnings for the next level.
+pub struct UpdateLevel;
+
+#[derive(SystemData)]
+pub struct UpdateLevelData<'a> {
+	conns: Read<'a, Connections>,
+
+	entities: Entities<'a>,
+	account: WriteStorage<'a, Account>,
+	level: WriteStorage<'a, Level>,
+	earnings: ReadStorage<'a, Earnings>,
+}
+
+impl<'a> System<'a> for UpdateLevel {
+	type SystemData = UpdateLevelData<'a>;
+
+	fn run(&mut self, mut data: Self::SystemData) {
+		let conns = &data.conns;
+
+		(
+			&*data.entities,
+			&mut data.account,
+			&mut data.level,
+			&data.earnings,
+		)
+			.join()
+			.for_each(|(ent, account, level, earnings)| {
+				let new_level = level_for_earnings((earnings.0).0);
+				if new_level <= level.0 {
+					return;
+				}
+
+				*level = Level(new_level);
+				account.stats.level = new_level;
+
+				conns.send_to_all(PlayerLevel {
+					id: ent.into(),
+					ty: PlayerLevelType::LevelUp,
+					level: *level,
+				});
+			});
+	}
+}
+
+impl SystemInfo for UpdateLevel {
+	type Dependencies = ();
+
+	fn name() -> &'static str {
+		concat!(module_path!(), "::", line!())
+	}
+
+	fn new() -> Self {
+		UpdateLevel
+	}
+}
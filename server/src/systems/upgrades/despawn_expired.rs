@@ -0,0 +1,67 @@
+use specs::*;
+use types::*;
+
+use component::channel::OnMobDespawn;
+use component::event::MobDespawnEvent;
+use component::time::{MobSpawnTime, ThisFrame};
+use SystemInfo;
+
+/// Despawn upgrades that have been lying around
+/// for longer than their lifetime.
+#[derive(Default)]
+pub struct DespawnExpired;
+
+#[derive(SystemData)]
+pub struct DespawnExpiredData<'a> {
+	channel: Write<'a, OnMobDespawn>,
+	config: Read<'a, Config>,
+	this_frame: Read<'a, ThisFrame>,
+
+	entities: Entities<'a>,
+	mob: ReadStorage<'a, Mob>,
+	spawn_time: ReadStorage<'a, MobSpawnTime>,
+}
+
+impl<'a> System<'a> for DespawnExpired {
+	type SystemData = DespawnExpiredData<'a>;
+
+	fn run(&mut self, mut data: Self::SystemData) {
+		// Configs loaded from a file might not have
+		// an entry for upgrades at all.
+		let lifetime = match data
+			.config
+			.mobs
+			.0
+			.get(&Mob::Upgrade)
+			.and_then(|info| info.lifetime)
+		{
+			Some(lifetime) => lifetime,
+			None => return,
+		};
+		let now = data.this_frame.0;
+
+		let expired = (&*data.entities, &data.mob, &data.spawn_time)
+			.join()
+			.filter(|(_, &mob, _)| mob == Mob::Upgrade)
+			.filter(|(_, _, spawn_time)| now - spawn_time.0 >= lifetime)
+			.map(|(mob, ..)| MobDespawnEvent {
+				mob,
+				ty: Mob::Upgrade,
+			})
+			.collect::<Vec<_>>();
+
+		data.channel.iter_write(expired);
+	}
+}
+
+impl SystemInfo for DespawnExpired {
+	type Dependencies = ();
+
+	fn name() -> &'static str {
+		concat!(module_path!(), "::", line!())
+	}
+
+	fn new() -> Self {
+		Self::default()
+	}
+}
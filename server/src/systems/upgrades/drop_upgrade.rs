@@ -0,0 +1,68 @@
+use specs::*;
+use types::*;
+
+use component::channel::OnUpgradeSpawn;
+use component::event::{PlayerKilled, UpgradeSpawnEvent};
+
+use rand;
+
+use systems::handlers::game::on_player_killed::KnownEventSources;
+use utils::{EventHandler, EventHandlerTypeProvider};
+use SystemInfo;
+
+/// Randomly drop an upgrade where a player was
+/// killed.
+///
+/// The drop chance comes from the game mode unless
+/// `upgrade_drop_chance` is set in the config.
+#[derive(Default)]
+pub struct DropUpgrade;
+
+#[derive(SystemData)]
+pub struct DropUpgradeData<'a> {
+	channel: Write<'a, OnUpgradeSpawn>,
+	config: Read<'a, Config>,
+	gamemode: GameModeWriter<'a, GameMode>,
+
+	entities: Entities<'a>,
+	pos: WriteStorage<'a, Position>,
+}
+
+impl EventHandlerTypeProvider for DropUpgrade {
+	type Event = PlayerKilled;
+}
+
+impl<'a> EventHandler<'a> for DropUpgrade {
+	type SystemData = DropUpgradeData<'a>;
+
+	fn on_event(&mut self, evt: &PlayerKilled, data: &mut Self::SystemData) {
+		let chance = data
+			.config
+			.upgrade_drop_chance
+			.unwrap_or_else(|| data.gamemode.get().upgrade_drop_chance());
+
+		if rand::random::<f32>() >= chance {
+			return;
+		}
+
+		let upgrade = data.entities.create();
+		data.pos.insert(upgrade, evt.pos).unwrap();
+
+		data.channel.single_write(UpgradeSpawnEvent {
+			upgrade,
+			pos: evt.pos,
+		});
+	}
+}
+
+impl SystemInfo for DropUpgrade {
+	type Dependencies = KnownEventSources;
+
+	fn name() -> &'static str {
+		concat!(module_path!(), "::", line!())
+	}
+
+	fn new() -> Self {
+		Self::default()
+	}
+}
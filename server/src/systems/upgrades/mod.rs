@@ -1,7 +1,17 @@
 mod register;
 
+mod despawn_expired;
+mod drop_upgrade;
 mod pickup_upgrade;
+mod send_on_join;
+mod send_score_update;
+mod spawn_upgrade;
 
 pub use self::register::register;
 
+pub use self::despawn_expired::DespawnExpired;
+pub use self::drop_upgrade::DropUpgrade;
 pub use self::pickup_upgrade::PickupUpgrade as Pickup;
+pub use self::send_on_join::SendUpgradesOnJoin;
+pub use self::send_score_update::SendScoreUpdate;
+pub use self::spawn_upgrade::SpawnUpgrade;
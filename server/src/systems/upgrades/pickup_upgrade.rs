@@ -2,14 +2,13 @@ use specs::*;
 
 use SystemInfo;
 
-use types::collision::*;
 use types::*;
 
 use component::channel::*;
 use component::event::*;
 use component::flag::*;
 
-use systems::collision::PlayerUpgradeCollisionSystem;
+use systems::collision::{pickups, PlayerUpgradeCollisionSystem};
 
 #[derive(Default)]
 pub struct PickupUpgrade {
@@ -20,6 +19,7 @@ pub struct PickupUpgrade {
 pub struct PickupUpgradeData<'a> {
 	channel: Read<'a, OnPlayerUpgradeCollision>,
 	upgrade_channel: Write<'a, OnUpgradePickup>,
+	despawn_channel: Write<'a, OnMobDespawn>,
 	entities: Entities<'a>,
 
 	upgrades: WriteStorage<'a, Upgrades>,
@@ -39,25 +39,23 @@ impl<'a> System<'a> for PickupUpgrade {
 	}
 
 	fn run(&mut self, mut data: Self::SystemData) {
-		for evt in data.channel.read(self.reader.as_mut().unwrap()) {
-			let Collision(c1, c2) = evt.0;
-
-			let (player, upgrade) = match data.is_player.get(c1.ent) {
-				Some(_) => (c1, c2),
-				None => (c2, c1),
-			};
-
-			if !data.entities.is_alive(upgrade.ent) {
-				continue;
-			}
+		let collisions = data
+			.channel
+			.read(self.reader.as_mut().unwrap())
+			.map(|evt| &evt.0);
 
+		for (player, upgrade) in pickups(collisions, &data.entities, &data.is_player) {
 			data.upgrades.get_mut(player.ent).unwrap().unused += 1;
 
 			data.upgrade_channel.single_write(UpgradePickupEvent {
 				pos: upgrade.pos,
 				upgrade: upgrade.ent,
 				player: player.ent,
-			})
+			});
+			data.despawn_channel.single_write(MobDespawnEvent {
+				mob: upgrade.ent,
+				ty: Mob::Upgrade,
+			});
 		}
 	}
 }
@@ -3,5 +3,11 @@ use dispatch::Builder;
 use super::*;
 
 pub fn register<'a, 'b>(builder: Builder<'a, 'b>) -> Builder<'a, 'b> {
-	builder.with::<Pickup>()
+	builder
+		.with::<Pickup>()
+		.with::<DespawnExpired>()
+		.with_handler::<DropUpgrade>()
+		.with_handler::<SpawnUpgrade>()
+		.with_handler::<SendScoreUpdate>()
+		.with_handler::<SendUpgradesOnJoin>()
 }
@@ -0,0 +1,61 @@
+use specs::*;
+use types::*;
+
+use component::event::PlayerJoin;
+use protocol::server::MobUpdateStationary;
+
+use systems::handlers::game::on_join::AllJoinHandlers;
+use utils::{EventHandler, EventHandlerTypeProvider};
+use SystemInfo;
+
+/// Let newly joined players know about all the
+/// upgrades that are already on the map.
+#[derive(Default)]
+pub struct SendUpgradesOnJoin;
+
+#[derive(SystemData)]
+pub struct SendUpgradesOnJoinData<'a> {
+	conns: Read<'a, Connections>,
+
+	entities: Entities<'a>,
+	pos: ReadStorage<'a, Position>,
+	mob: ReadStorage<'a, Mob>,
+}
+
+impl EventHandlerTypeProvider for SendUpgradesOnJoin {
+	type Event = PlayerJoin;
+}
+
+impl<'a> EventHandler<'a> for SendUpgradesOnJoin {
+	type SystemData = SendUpgradesOnJoinData<'a>;
+
+	fn on_event(&mut self, evt: &PlayerJoin, data: &mut Self::SystemData) {
+		let conns = &data.conns;
+
+		(&*data.entities, &data.pos, &data.mob)
+			.join()
+			.filter(|(_, _, &mob)| mob == Mob::Upgrade)
+			.for_each(|(ent, &pos, &mob)| {
+				conns.send_to_player(
+					evt.id,
+					MobUpdateStationary {
+						id: ent.into(),
+						ty: mob,
+						pos,
+					},
+				);
+			});
+	}
+}
+
+impl SystemInfo for SendUpgradesOnJoin {
+	type Dependencies = AllJoinHandlers;
+
+	fn name() -> &'static str {
+		concat!(module_path!(), "::", line!())
+	}
+
+	fn new() -> Self {
+		Self::default()
+	}
+}
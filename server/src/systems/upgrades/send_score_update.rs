@@ -0,0 +1,63 @@
+use specs::*;
+use types::*;
+
+use component::counter::*;
+use component::event::UpgradePickupEvent;
+use protocol::server::ScoreUpdate;
+
+use utils::{EventHandler, EventHandlerTypeProvider};
+use SystemInfo;
+
+use super::Pickup;
+
+/// Let the player know how many unused upgrades
+/// they have after picking one up.
+#[derive(Default)]
+pub struct SendScoreUpdate;
+
+#[derive(SystemData)]
+pub struct SendScoreUpdateData<'a> {
+	conns: Read<'a, Connections>,
+
+	score: ReadStorage<'a, Score>,
+	earnings: ReadStorage<'a, Earnings>,
+	upgrades: ReadStorage<'a, Upgrades>,
+	total_kills: ReadStorage<'a, TotalKills>,
+	total_deaths: ReadStorage<'a, TotalDeaths>,
+}
+
+impl EventHandlerTypeProvider for SendScoreUpdate {
+	type Event = UpgradePickupEvent;
+}
+
+impl<'a> EventHandler<'a> for SendScoreUpdate {
+	type SystemData = SendScoreUpdateData<'a>;
+
+	fn on_event(&mut self, evt: &UpgradePickupEvent, data: &mut Self::SystemData) {
+		let player = evt.player;
+
+		data.conns.send_to_player(
+			player,
+			ScoreUpdate {
+				id: player.into(),
+				score: *try_get!(player, data.score),
+				earnings: try_get!(player, data.earnings).0,
+				upgrades: try_get!(player, data.upgrades).unused,
+				total_kills: try_get!(player, data.total_kills).0,
+				total_deaths: try_get!(player, data.total_deaths).0,
+			},
+		);
+	}
+}
+
+impl SystemInfo for SendScoreUpdate {
+	type Dependencies = Pickup;
+
+	fn name() -> &'static str {
+		concat!(module_path!(), "::", line!())
+	}
+
+	fn new() -> Self {
+		Self::default()
+	}
+}
@@ -0,0 +1,66 @@
+use specs::*;
+use types::*;
+
+use component::event::UpgradeSpawnEvent;
+use component::time::{MobSpawnTime, ThisFrame};
+use consts::upgrade::UPGRADE_TEAM;
+use protocol::server::MobUpdateStationary;
+
+use systems::admin::SpawnUpgrade as AdminSpawnUpgrade;
+use utils::{EventHandler, EventHandlerTypeProvider};
+use SystemInfo;
+
+use super::DropUpgrade;
+
+/// Turn a newly spawned upgrade into a mob
+/// that players can collide with and let
+/// everyone nearby know about it.
+#[derive(Default)]
+pub struct SpawnUpgrade;
+
+#[derive(SystemData)]
+pub struct SpawnUpgradeData<'a> {
+	conns: Read<'a, Connections>,
+	this_frame: Read<'a, ThisFrame>,
+
+	mob: WriteStorage<'a, Mob>,
+	team: WriteStorage<'a, Team>,
+	spawn_time: WriteStorage<'a, MobSpawnTime>,
+}
+
+impl EventHandlerTypeProvider for SpawnUpgrade {
+	type Event = UpgradeSpawnEvent;
+}
+
+impl<'a> EventHandler<'a> for SpawnUpgrade {
+	type SystemData = SpawnUpgradeData<'a>;
+
+	fn on_event(&mut self, evt: &UpgradeSpawnEvent, data: &mut Self::SystemData) {
+		data.mob.insert(evt.upgrade, Mob::Upgrade).unwrap();
+		data.team.insert(evt.upgrade, UPGRADE_TEAM).unwrap();
+		data.spawn_time
+			.insert(evt.upgrade, MobSpawnTime(data.this_frame.0))
+			.unwrap();
+
+		data.conns.send_to_visible(
+			evt.pos,
+			MobUpdateStationary {
+				id: evt.upgrade.into(),
+				ty: Mob::Upgrade,
+				pos: evt.pos,
+			},
+		);
+	}
+}
+
+impl SystemInfo for SpawnUpgrade {
+	type Dependencies = (DropUpgrade, AdminSpawnUpgrade);
+
+	fn name() -> &'static str {
+		concat!(module_path!(), "::", line!())
+	}
+
+	fn new() -> Self {
+		Self::default()
+	}
+}
@@ -0,0 +1,51 @@
+use specs::*;
+
+use SystemInfo;
+
+use component::collection::VoteMutes;
+use component::event::PlayerLeave;
+use utils::{EventHandler, EventHandlerTypeProvider};
+
+use systems::handlers::packet::OnCloseHandler;
+
+/// Remove all votes cast by a player, as well
+/// as any votes against them, when they leave.
+#[derive(Default)]
+pub struct ClearVotes;
+
+#[derive(SystemData)]
+pub struct ClearVotesData<'a> {
+	votes: Write<'a, VoteMutes>,
+}
+
+impl EventHandlerTypeProvider for ClearVotes {
+	type Event = PlayerLeave;
+}
+
+impl<'a> EventHandler<'a> for ClearVotes {
+	type SystemData = ClearVotesData<'a>;
+
+	fn on_event(&mut self, evt: &PlayerLeave, data: &mut Self::SystemData) {
+		let votes = &mut data.votes.0;
+
+		votes.remove(&evt.0);
+
+		for voters in votes.values_mut() {
+			voters.remove(&evt.0);
+		}
+
+		votes.retain(|_, voters| !voters.is_empty());
+	}
+}
+
+impl SystemInfo for ClearVotes {
+	type Dependencies = OnCloseHandler;
+
+	fn name() -> &'static str {
+		concat!(module_path!(), "::", line!())
+	}
+
+	fn new() -> Self {
+		Self::default()
+	}
+}
@@ -0,0 +1,8 @@
+mod clear_votes;
+mod register;
+mod vote;
+
+pub use self::clear_votes::ClearVotes;
+pub use self::vote::VoteMuteHandler;
+
+pub use self::register::register;
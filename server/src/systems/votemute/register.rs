@@ -0,0 +1,9 @@
+use dispatch::Builder;
+
+use super::*;
+
+pub fn register<'a, 'b>(builder: Builder<'a, 'b>) -> Builder<'a, 'b> {
+	builder
+		.with_handler::<VoteMuteHandler>()
+		.with_handler::<ClearVotes>()
+}
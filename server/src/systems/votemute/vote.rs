@@ -0,0 +1,124 @@
+use specs::*;
+use types::*;
+
+use SystemInfo;
+
+use component::collection::VoteMutes;
+use component::counter::PlayersGame;
+use component::event::VotemuteEvent;
+use component::flag::*;
+use component::ratelimit::VoteMuteLimiter;
+use component::time::ThisFrame;
+use utils::{EventHandler, EventHandlerTypeProvider};
+
+use protocol::server::ChatVoteMutePassed;
+
+use systems::handlers::game::on_join::InitLimiters;
+
+/// Number of votes required to mute a player.
+///
+/// This is the formula used by the official
+/// server (see `notes/votemutes.md`).
+pub fn vote_threshold(player_count: u32) -> usize {
+	(player_count as f64).sqrt().floor() as usize + 1
+}
+
+/// Record votemutes and mute the target once
+/// enough players have voted against them.
+#[derive(Default)]
+pub struct VoteMuteHandler;
+
+#[derive(SystemData)]
+pub struct VoteMuteHandlerData<'a> {
+	conns: Read<'a, Connections>,
+	votes: Write<'a, VoteMutes>,
+	players_game: Read<'a, PlayersGame>,
+	this_frame: Read<'a, ThisFrame>,
+
+	entities: Entities<'a>,
+	is_player: ReadStorage<'a, IsPlayer>,
+	is_muted: WriteStorage<'a, IsChatMuted>,
+	limiter: WriteStorage<'a, VoteMuteLimiter>,
+}
+
+impl EventHandlerTypeProvider for VoteMuteHandler {
+	type Event = VotemuteEvent;
+}
+
+impl<'a> EventHandler<'a> for VoteMuteHandler {
+	type SystemData = VoteMuteHandlerData<'a>;
+
+	fn on_event(&mut self, evt: &VotemuteEvent, data: &mut Self::SystemData) {
+		let player = match data.conns.associated_player(evt.0) {
+			Some(p) => p,
+			None => return,
+		};
+
+		let target = data.entities.entity(evt.1.id.0 as u32);
+
+		if !data.entities.is_alive(target) || data.is_player.get(target).is_none() {
+			return;
+		}
+		// Players can't vote for themselves
+		if target == player {
+			return;
+		}
+		// Votes against players that are already muted
+		// don't do anything.
+		if data.is_muted.get(target).is_some() {
+			return;
+		}
+
+		let limiter = try_get!(player, mut data.limiter);
+		limiter.0.add_event(data.this_frame.0);
+		if limiter.0.limit_reached() {
+			return;
+		}
+
+		let threshold = vote_threshold(data.players_game.0);
+		let count = {
+			let votes = data.votes.0.entry(target).or_insert_with(Default::default);
+			votes.insert(player);
+			votes.len()
+		};
+
+		if count < threshold {
+			return;
+		}
+
+		data.votes.0.remove(&target);
+		data.is_muted.insert(target, IsChatMuted).unwrap();
+
+		info!("Player {:?} was votemuted with {} votes", target, count);
+
+		data.conns
+			.send_to_player(target, ChatVoteMutePassed { id: target.into() });
+	}
+}
+
+impl SystemInfo for VoteMuteHandler {
+	type Dependencies = InitLimiters;
+
+	fn name() -> &'static str {
+		concat!(module_path!(), "::", line!())
+	}
+
+	fn new() -> Self {
+		Self::default()
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	#[test]
+	fn votemute_threshold() {
+		assert_eq!(vote_threshold(0), 1);
+		assert_eq!(vote_threshold(1), 2);
+		assert_eq!(vote_threshold(3), 2);
+		assert_eq!(vote_threshold(4), 3);
+		assert_eq!(vote_threshold(24), 5);
+		assert_eq!(vote_threshold(25), 6);
+	}
+}
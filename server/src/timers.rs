@@ -41,4 +41,38 @@ pub fn start_timer_events(channel: Sender<TimerEvent>) {
 			Duration::from_secs(5),
 		)
 	});
+
+	// 30s timer for writing out accounts
+	tokio::spawn({
+		let channel = channel.clone();
+		timeloop(
+			move |instant| {
+				channel
+					.send(TimerEvent {
+						ty: *SAVE_ACCOUNTS,
+						instant: instant,
+						..Default::default()
+					})
+					.unwrap();
+			},
+			Duration::from_secs(30),
+		)
+	});
+
+	// 5s timer for checking for AFK players
+	tokio::spawn({
+		let channel = channel.clone();
+		timeloop(
+			move |instant| {
+				channel
+					.send(TimerEvent {
+						ty: *AFK_TIMER,
+						instant: instant,
+						..Default::default()
+					})
+					.unwrap();
+			},
+			Duration::from_secs(5),
+		)
+	});
 }
@@ -0,0 +1,352 @@
+use fnv::FnvHashMap;
+use serde_json;
+use specs::*;
+use uuid::Uuid;
+
+use std::fs::{self, File};
+use std::io::{self, BufReader, BufWriter};
+use std::path::{Path, PathBuf};
+
+/// Lifetime stats that are stored with an account.
+#[derive(Copy, Clone, Debug, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AccountStats {
+	pub level: u8,
+	pub score: u32,
+	pub earnings: u32,
+	pub total_kills: u32,
+	pub total_deaths: u32,
+	pub captures: u32,
+}
+
+impl AccountStats {
+	/// Add the score, kills and deaths from a single
+	/// game on top of the lifetime stats.
+	pub fn add_game(&mut self, score: u32, kills: u32, deaths: u32) {
+		self.score = self.score.saturating_add(score);
+		self.total_kills = self.total_kills.saturating_add(kills);
+		self.total_deaths = self.total_deaths.saturating_add(deaths);
+	}
+}
+
+/// Highest level that a player can reach.
+pub const MAX_LEVEL: u8 = 100;
+
+/// The level that a player with `earnings` total
+/// earnings has. Every level takes 1000 more
+/// earnings than the one before it.
+pub fn level_for_earnings(earnings: u32) -> u8 {
+	let mut level = 0;
+	let mut needed = 0u64;
+
+	while level < MAX_LEVEL {
+		needed += 1000 * (level as u64 + 1);
+		if (earnings as u64) < needed {
+			break;
+		}
+		level += 1;
+	}
+
+	level
+}
+
+/// The account that a player is logged in as.
+///
+/// Only players whose session token was accepted
+/// by the [`SessionValidator`] have this component.
+///
+/// `stats` are kept separate from the per-game
+/// counters (`Score`, `TotalKills`, etc.), which
+/// start from zero every time a player joins. The
+/// score, kills and deaths from the current game
+/// are added to `stats` when the account is saved.
+/// Earnings and level are lifetime stats in the
+/// game itself, and game modes can add stats that
+/// the server doesn't track (e.g. CTF captures) to
+/// `stats` directly.
+#[derive(Clone, Debug, Component)]
+pub struct Account {
+	pub id: Uuid,
+	pub stats: AccountStats,
+}
+
+/// Checks the session tokens that players send
+/// when they log in.
+pub trait SessionValidator: Send + Sync {
+	/// The id of the account that `token` belongs
+	/// to, `None` if the token isn't valid.
+	fn validate(&self, token: &str) -> Option<Uuid>;
+}
+
+/// Session validator that rejects every token.
+/// Players can still log in but nobody gets an
+/// account.
+#[derive(Default)]
+pub struct NoSessions;
+
+impl SessionValidator for NoSessions {
+	fn validate(&self, _: &str) -> Option<Uuid> {
+		None
+	}
+}
+
+/// Session validator backed by a JSON file that
+/// maps session tokens to account ids, e.g. one
+/// that is written by a login service.
+///
+/// The file is read again for every login so that
+/// new sessions are accepted without restarting
+/// the server.
+pub struct FileSessionValidator {
+	path: PathBuf,
+}
+
+impl FileSessionValidator {
+	pub fn new<P: AsRef<Path>>(path: P) -> Self {
+		Self {
+			path: path.as_ref().to_owned(),
+		}
+	}
+
+	fn read(&self) -> io::Result<FnvHashMap<String, Uuid>> {
+		let file = File::open(&self.path)?;
+
+		serde_json::from_reader(BufReader::new(file))
+			.map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+	}
+}
+
+impl SessionValidator for FileSessionValidator {
+	fn validate(&self, token: &str) -> Option<Uuid> {
+		if token.is_empty() {
+			return None;
+		}
+
+		match self.read() {
+			Ok(sessions) => sessions.get(token).cloned(),
+			Err(e) => {
+				error!(
+					"Failed to read sessions from {}: {}",
+					self.path.display(),
+					e
+				);
+				None
+			}
+		}
+	}
+}
+
+/// Resource containing the session validator in
+/// use by the server. Defaults to [`NoSessions`].
+pub struct Sessions(pub Box<SessionValidator>);
+
+impl Default for Sessions {
+	fn default() -> Self {
+		Sessions(Box::new(NoSessions))
+	}
+}
+
+/// Backing storage for player accounts.
+pub trait AccountStore: Send + Sync {
+	/// Look up an account, `None` if there isn't
+	/// one with that id yet.
+	fn load(&self, id: &Uuid) -> Option<AccountStats>;
+	/// Save the stats for an account, creating the
+	/// account if it doesn't already exist.
+	///
+	/// Stores may hold on to the stats until the next
+	/// call to [`flush`](AccountStore::flush).
+	fn store(&mut self, id: &Uuid, stats: &AccountStats) -> io::Result<()>;
+	/// Write any accounts that have been stored since
+	/// the last flush to permanent storage.
+	fn flush(&mut self) -> io::Result<()> {
+		Ok(())
+	}
+}
+
+/// Account store that only keeps accounts in
+/// memory. Accounts will be lost when the server
+/// restarts.
+#[derive(Default)]
+pub struct MemoryAccountStore {
+	accounts: FnvHashMap<Uuid, AccountStats>,
+}
+
+impl AccountStore for MemoryAccountStore {
+	fn load(&self, id: &Uuid) -> Option<AccountStats> {
+		self.accounts.get(id).cloned()
+	}
+
+	fn store(&mut self, id: &Uuid, stats: &AccountStats) -> io::Result<()> {
+		self.accounts.insert(*id, *stats);
+		Ok(())
+	}
+}
+
+/// Account store backed by a single JSON file.
+///
+/// All accounts are kept in memory. Stored accounts
+/// are only written out when the store is flushed,
+/// which rewrites the whole file.
+pub struct FileAccountStore {
+	path: PathBuf,
+	accounts: FnvHashMap<Uuid, AccountStats>,
+	dirty: bool,
+}
+
+impl FileAccountStore {
+	/// Open the account file at `path`. If the file
+	/// doesn't exist then it will be created the first
+	/// time that an account is stored.
+	pub fn open<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+		let path = path.as_ref().to_owned();
+
+		let accounts = match File::open(&path) {
+			Ok(file) => serde_json::from_reader(BufReader::new(file))
+				.map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?,
+			Err(ref e) if e.kind() == io::ErrorKind::NotFound => FnvHashMap::default(),
+			Err(e) => return Err(e),
+		};
+
+		Ok(Self {
+			path,
+			accounts,
+			dirty: false,
+		})
+	}
+
+	fn write(&self) -> io::Result<()> {
+		// Write to a temporary file first so that a crash
+		// halfway through doesn't corrupt the accounts.
+		let tmp = self.path.with_extension("tmp");
+
+		{
+			let file = File::create(&tmp)?;
+			serde_json::to_writer(BufWriter::new(file), &self.accounts)
+				.map_err(|e| io::Error::new(io::ErrorKind::Other, e))?;
+		}
+
+		fs::rename(&tmp, &self.path)
+	}
+}
+
+impl AccountStore for FileAccountStore {
+	fn load(&self, id: &Uuid) -> Option<AccountStats> {
+		self.accounts.get(id).cloned()
+	}
+
+	fn store(&mut self, id: &Uuid, stats: &AccountStats) -> io::Result<()> {
+		self.accounts.insert(*id, *stats);
+		self.dirty = true;
+		Ok(())
+	}
+
+	fn flush(&mut self) -> io::Result<()> {
+		if !self.dirty {
+			return Ok(());
+		}
+
+		self.write()?;
+		self.dirty = false;
+		Ok(())
+	}
+}
+
+impl Drop for FileAccountStore {
+	fn drop(&mut self) {
+		if let Err(e) = self.flush() {
+			error!("Failed to save accounts to {}: {}", self.path.display(), e);
+		}
+	}
+}
+
+/// Resource containing the account store in use
+/// by the server. Defaults to a [`MemoryAccountStore`].
+pub struct Accounts(pub Box<AccountStore>);
+
+impl Default for Accounts {
+	fn default() -> Self {
+		Accounts(Box::new(MemoryAccountStore::default()))
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	use std::env;
+	use std::process;
+
+	#[test]
+	fn file_store_roundtrip() {
+		let path = env::temp_dir().join(format!("airmash-accounts-{}.json", process::id()));
+		let id = Uuid::nil();
+		let stats = AccountStats {
+			level: 3,
+			score: 450,
+			earnings: 1200,
+			total_kills: 10,
+			total_deaths: 4,
+			captures: 2,
+		};
+
+		{
+			let mut store = FileAccountStore::open(&path).unwrap();
+			assert!(store.load(&id).is_none());
+			store.store(&id, &stats).unwrap();
+			store.flush().unwrap();
+		}
+
+		let store = FileAccountStore::open(&path).unwrap();
+		let loaded = store.load(&id).unwrap();
+		fs::remove_file(&path).unwrap();
+
+		assert_eq!(loaded.level, 3);
+		assert_eq!(loaded.score, 450);
+		assert_eq!(loaded.earnings, 1200);
+		assert_eq!(loaded.captures, 2);
+	}
+
+	#[test]
+	fn levels_from_earnings() {
+		assert_eq!(level_for_earnings(0), 0);
+		assert_eq!(level_for_earnings(999), 0);
+		assert_eq!(level_for_earnings(1000), 1);
+		assert_eq!(level_for_earnings(2999), 1);
+		assert_eq!(level_for_earnings(3000), 2);
+		assert_eq!(level_for_earnings(u32::max_value()), MAX_LEVEL);
+	}
+
+	#[test]
+	fn games_add_to_lifetime_stats() {
+		let mut stats = AccountStats {
+			score: 100,
+			total_kills: 5,
+			total_deaths: 3,
+			..Default::default()
+		};
+
+		stats.add_game(50, 2, 1);
+		assert_eq!(stats.score, 150);
+		assert_eq!(stats.total_kills, 7);
+		assert_eq!(stats.total_deaths, 4);
+	}
+
+	#[test]
+	fn sessions_come_from_file() {
+		let path = env::temp_dir().join(format!("airmash-sessions-{}.json", process::id()));
+		let id = Uuid::nil();
+
+		let validator = FileSessionValidator::new(&path);
+		assert_eq!(validator.validate("secret"), None);
+
+		fs::write(&path, format!("{{\"secret\": \"{}\"}}", id)).unwrap();
+		let valid = validator.validate("secret");
+		let invalid = validator.validate("guess");
+		fs::remove_file(&path).unwrap();
+
+		assert_eq!(valid, Some(id));
+		assert_eq!(invalid, None);
+		assert_eq!(NoSessions.validate("secret"), None);
+	}
+}
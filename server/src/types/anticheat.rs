@@ -0,0 +1,374 @@
+//! Detection of scripted clients based on the
+//! `Key` packets that they send.
+//!
+//! Every check that fails counts as an offense.
+//! Offenses add to a per-player score which
+//! decays over time, and once the score passes
+//! one of the thresholds in [`AntiCheatConfig`]
+//! the player is logged, kicked or banned.
+
+use specs::*;
+
+use std::mem;
+use std::time::{Duration, Instant};
+
+use protocol::client::Key;
+use protocol::KeyCode;
+use types::RateLimiter;
+
+/// Something a client did that a human player
+/// with an unmodified client shouldn't be able
+/// to do.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum Offense {
+	/// Changed key states faster than a human can.
+	KeyToggleRate,
+	/// Pressed fire faster than the plane can
+	/// actually fire.
+	FireRate,
+	/// Sent a key sequence number that repeats or
+	/// goes backwards, or that advances faster
+	/// than keys could have been pressed.
+	KeySequence,
+}
+
+/// What should be done about a player, based
+/// on their current offense score.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum AntiCheatAction {
+	Log,
+	Kick,
+	Ban,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AntiCheatConfig {
+	/// Whether the anti-cheat checks are run at all.
+	pub enabled: bool,
+
+	/// Maximum number of key state changes that
+	/// are allowed within `toggle_window`.
+	pub max_key_toggles: usize,
+	pub toggle_window: Duration,
+	/// Fire presses that are closer together than
+	/// the plane's `fire_delay` times this factor
+	/// count as an offense.
+	pub fire_delay_tolerance: f32,
+	/// Number of key presses per second that a
+	/// client can plausibly send, used to check
+	/// how fast the key sequence number advances.
+	pub max_keys_per_second: f32,
+	/// Slack given to the sequence number check to
+	/// account for packets that arrive in bursts.
+	pub max_seq_jump: u32,
+
+	/// Score that each kind of offense adds.
+	pub toggle_score: f32,
+	pub fire_rate_score: f32,
+	pub sequence_score: f32,
+	/// Amount of score that is forgiven every second.
+	pub score_decay: f32,
+
+	pub log_threshold: f32,
+	pub kick_threshold: f32,
+	pub ban_threshold: f32,
+	/// Whether players that pass `ban_threshold` are
+	/// banned. Otherwise they are only kicked.
+	pub auto_ban: bool,
+	/// How long bans last, `None` for permanent bans.
+	pub ban_duration: Option<Duration>,
+}
+
+impl Default for AntiCheatConfig {
+	fn default() -> Self {
+		Self {
+			enabled: true,
+
+			max_key_toggles: 40,
+			toggle_window: Duration::from_secs(1),
+			fire_delay_tolerance: 0.25,
+			max_keys_per_second: 40.0,
+			max_seq_jump: 10,
+
+			toggle_score: 1.0,
+			fire_rate_score: 0.5,
+			sequence_score: 2.0,
+			score_decay: 0.5,
+
+			log_threshold: 5.0,
+			kick_threshold: 20.0,
+			ban_threshold: 50.0,
+			auto_ban: false,
+			ban_duration: Some(Duration::from_secs(60 * 60)),
+		}
+	}
+}
+
+impl AntiCheatConfig {
+	pub fn score(&self, offense: Offense) -> f32 {
+		match offense {
+			Offense::KeyToggleRate => self.toggle_score,
+			Offense::FireRate => self.fire_rate_score,
+			Offense::KeySequence => self.sequence_score,
+		}
+	}
+
+	/// The most severe action for a given score.
+	pub fn action(&self, score: f32) -> Option<AntiCheatAction> {
+		if self.auto_ban && score >= self.ban_threshold {
+			Some(AntiCheatAction::Ban)
+		} else if score >= self.kick_threshold {
+			Some(AntiCheatAction::Kick)
+		} else if score >= self.log_threshold {
+			Some(AntiCheatAction::Log)
+		} else {
+			None
+		}
+	}
+}
+
+fn secs(dur: Duration) -> f32 {
+	dur.as_secs() as f32 + dur.subsec_nanos() as f32 * 1e-9
+}
+
+/// Per-player state used by the anti-cheat checks.
+#[derive(Clone, Debug, Component)]
+pub struct AntiCheatState {
+	toggles: RateLimiter,
+	last_seq: Option<(u32, Instant)>,
+	last_fire: Option<Instant>,
+	last_offense: Instant,
+	/// Current offense score of the player.
+	pub score: f32,
+}
+
+impl AntiCheatState {
+	pub fn new(config: &AntiCheatConfig, now: Instant) -> Self {
+		Self {
+			toggles: RateLimiter::new(config.max_key_toggles, config.toggle_window),
+			last_seq: None,
+			last_fire: None,
+			last_offense: now,
+			score: 0.0,
+		}
+	}
+
+	/// Run all the checks for a key packet and
+	/// return the offenses that were found.
+	/// `fire_delay` is the fire delay of the
+	/// player's plane, if they have one.
+	///
+	/// Clients send every key packet on both their
+	/// primary and backup connections and the copies
+	/// can arrive in any order. Keys that aren't newer
+	/// than the last key that was checked are ignored,
+	/// the same as [`KeyHandler`] doesn't apply them.
+	///
+	/// [`KeyHandler`]: ::handlers::KeyHandler
+	pub fn check_key(
+		&mut self,
+		config: &AntiCheatConfig,
+		key: &Key,
+		fire_delay: Option<Duration>,
+		now: Instant,
+	) -> Vec<Offense> {
+		match self.last_seq {
+			Some((prev, _)) if key.seq <= prev => return vec![],
+			_ => (),
+		}
+
+		let mut offenses = vec![];
+		offenses.extend(self.check_seq(config, key.seq, now));
+		offenses.extend(self.check_toggle(now));
+
+		match (key.key, fire_delay) {
+			(KeyCode::Fire, Some(fire_delay)) if key.state => {
+				offenses.extend(self.check_fire(config, fire_delay, now));
+			}
+			_ => (),
+		}
+
+		offenses
+	}
+
+	/// Record a key state change.
+	pub fn check_toggle(&mut self, now: Instant) -> Option<Offense> {
+		self.toggles.add_event(now);
+
+		if self.toggles.limit_reached() {
+			Some(Offense::KeyToggleRate)
+		} else {
+			None
+		}
+	}
+
+	/// Record the fire key being pressed by a plane
+	/// that fires once every `fire_delay`.
+	pub fn check_fire(
+		&mut self,
+		config: &AntiCheatConfig,
+		fire_delay: Duration,
+		now: Instant,
+	) -> Option<Offense> {
+		let prev = mem::replace(&mut self.last_fire, Some(now))?;
+
+		if secs(now - prev) < secs(fire_delay) * config.fire_delay_tolerance {
+			Some(Offense::FireRate)
+		} else {
+			None
+		}
+	}
+
+	/// Record the sequence number of a key packet.
+	pub fn check_seq(
+		&mut self,
+		config: &AntiCheatConfig,
+		seq: u32,
+		now: Instant,
+	) -> Option<Offense> {
+		let (prev, time) = match mem::replace(&mut self.last_seq, Some((seq, now))) {
+			Some(last) => last,
+			None => return None,
+		};
+
+		if seq <= prev {
+			return Some(Offense::KeySequence);
+		}
+
+		let allowed = config.max_seq_jump as f32 + secs(now - time) * config.max_keys_per_second;
+		if (seq - prev) as f32 > allowed {
+			return Some(Offense::KeySequence);
+		}
+
+		None
+	}
+
+	/// Add an offense to the player's score and
+	/// return the action that should be taken.
+	pub fn add_offense(
+		&mut self,
+		config: &AntiCheatConfig,
+		offense: Offense,
+		now: Instant,
+	) -> Option<AntiCheatAction> {
+		let decay = secs(now - self.last_offense) * config.score_decay;
+
+		self.score = (self.score - decay).max(0.0) + config.score(offense);
+		self.last_offense = now;
+
+		config.action(self.score)
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	#[test]
+	fn seq_must_increase() {
+		let config = AntiCheatConfig::default();
+		let now = Instant::now();
+		let mut state = AntiCheatState::new(&config, now);
+
+		assert_eq!(state.check_seq(&config, 5, now), None);
+		assert_eq!(state.check_seq(&config, 6, now), None);
+		assert_eq!(state.check_seq(&config, 6, now), Some(Offense::KeySequence));
+		assert_eq!(state.check_seq(&config, 2, now), Some(Offense::KeySequence));
+	}
+
+	#[test]
+	fn duplicate_keys_are_ignored() {
+		let config = AntiCheatConfig::default();
+		let now = Instant::now();
+		let mut state = AntiCheatState::new(&config, now);
+
+		// Every key arrives once on the primary and
+		// once on the backup connection.
+		for seq in 0..100 {
+			let key = Key {
+				seq,
+				key: KeyCode::Up,
+				state: seq % 2 == 0,
+			};
+			let time = now + Duration::from_millis(seq as u64 * 100);
+
+			assert_eq!(state.check_key(&config, &key, None, time), vec![]);
+			assert_eq!(state.check_key(&config, &key, None, time), vec![]);
+		}
+
+		// A copy that arrives after newer keys is also
+		// ignored.
+		let key = Key {
+			seq: 50,
+			key: KeyCode::Fire,
+			state: true,
+		};
+		assert_eq!(state.check_key(&config, &key, None, now), vec![]);
+	}
+
+	#[test]
+	fn seq_jump_scales_with_time() {
+		let config = AntiCheatConfig::default();
+		let now = Instant::now();
+		let mut state = AntiCheatState::new(&config, now);
+
+		state.check_seq(&config, 0, now);
+		assert_eq!(
+			state.check_seq(&config, 1000, now + Duration::from_millis(100)),
+			Some(Offense::KeySequence)
+		);
+		assert_eq!(
+			state.check_seq(&config, 1100, now + Duration::from_secs(10)),
+			None
+		);
+	}
+
+	#[test]
+	fn fast_fire_presses() {
+		let config = AntiCheatConfig::default();
+		let delay = Duration::from_millis(500);
+		let now = Instant::now();
+		let mut state = AntiCheatState::new(&config, now);
+
+		assert_eq!(state.check_fire(&config, delay, now), None);
+		assert_eq!(
+			state.check_fire(&config, delay, now + Duration::from_millis(400)),
+			None
+		);
+		assert_eq!(
+			state.check_fire(&config, delay, now + Duration::from_millis(420)),
+			Some(Offense::FireRate)
+		);
+	}
+
+	#[test]
+	fn score_decays() {
+		let config = AntiCheatConfig::default();
+		let now = Instant::now();
+		let mut state = AntiCheatState::new(&config, now);
+
+		for _ in 0..10 {
+			state.add_offense(&config, Offense::KeySequence, now);
+		}
+		assert_eq!(state.score, 20.0);
+		assert_eq!(config.action(state.score), Some(AntiCheatAction::Kick));
+
+		let later = now + Duration::from_secs(30);
+		assert_eq!(
+			state.add_offense(&config, Offense::FireRate, later),
+			Some(AntiCheatAction::Log)
+		);
+		assert_eq!(state.score, 5.5);
+	}
+
+	#[test]
+	fn bans_are_opt_in() {
+		let mut config = AntiCheatConfig::default();
+
+		assert_eq!(config.action(100.0), Some(AntiCheatAction::Kick));
+
+		config.auto_ban = true;
+		assert_eq!(config.action(100.0), Some(AntiCheatAction::Ban));
+	}
+}
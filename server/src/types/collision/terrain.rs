@@ -38,6 +38,10 @@ impl Terrain {
 		Self::new(TERRAIN.iter(), ents)
 	}
 
+	pub fn from_map(map: &Map, ents: &EntitiesRes) -> Self {
+		Self::new(map.terrain.iter(), ents)
+	}
+
 	pub fn collide<I>(&self, it: I) -> Vec<Collision>
 	where
 		I: Iterator<Item = HitCircle>,
@@ -1,3 +1,4 @@
+use rand;
 use uuid::Uuid;
 
 use specs::*;
@@ -15,9 +16,23 @@ pub use protocol::PlayerStatus as Status;
 pub struct Name(pub String);
 #[derive(Clone, Debug, Default, Component, Eq, PartialEq, Hash)]
 pub struct Session(pub Option<Uuid>);
+/// Random token given to a player when they log in.
+/// Clients present it when opening a backup connection.
+#[derive(Clone, Debug, Default, Component, Eq, PartialEq, Hash)]
+pub struct BackupToken(pub String);
 #[derive(Clone, Debug, Copy, Component, Default)]
 pub struct AssociatedConnection(pub ConnectionId);
 
+impl BackupToken {
+	pub fn generate() -> Self {
+		BackupToken(format!(
+			"{:016x}{:016x}",
+			rand::random::<u64>(),
+			rand::random::<u64>()
+		))
+	}
+}
+
 pub trait ToClock {
 	fn to_clock(&self) -> u32;
 }
@@ -27,3 +42,17 @@ impl ToClock for Duration {
 		(self.as_secs() * 1_000_000) as u32 + self.subsec_micros() / 10
 	}
 }
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	#[test]
+	fn backup_tokens_are_unique() {
+		let a = BackupToken::generate();
+		let b = BackupToken::generate();
+
+		assert_eq!(a.0.len(), 32);
+		assert_ne!(a, b);
+	}
+}
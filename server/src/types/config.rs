@@ -1,9 +1,14 @@
 use airmash_protocol::{MobType, PlaneType};
 use fnv::FnvHashMap;
+
+use std::error::Error;
+use std::fmt;
 use std::ops::Index;
+use std::path::{Path, PathBuf};
 use std::time::Duration;
 
 use types::*;
+use utils::{load_file, LoadError};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PlaneInfo {
@@ -73,16 +78,185 @@ pub struct UpgradeInfos {
 	pub defense: UpgradeInfo,
 }
 
+/// Settings for the in-process bots.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(default)]
+pub struct BotConfig {
+	/// Number of bots to keep in the game.
+	pub count: usize,
+	/// If set, `count` is the number of players that
+	/// the server is kept at and bots will leave as
+	/// real players join.
+	pub fill: bool,
+	/// Distance at which bots will go after enemies.
+	pub chase_range: Distance,
+	/// Distance at which bots will start firing.
+	pub fire_range: Distance,
+}
+
+impl Default for BotConfig {
+	fn default() -> Self {
+		Self {
+			count: 0,
+			fill: false,
+			chase_range: Distance::new(1500.0),
+			fire_range: Distance::new(600.0),
+		}
+	}
+}
+
+/// When idle players get moved to spectate or
+/// disconnected.
+///
+/// Players are idle while they aren't pressing
+/// any keys. Chatting also counts as activity
+/// for players that went into spectate on their
+/// own, so that they can keep commanding their
+/// team.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AfkConfig {
+	pub enabled: bool,
+	/// How long before being moved to spectate or
+	/// disconnected that players get a warning.
+	pub warning: Duration,
+	/// Idle time before a player that is flying
+	/// around is moved to spectate.
+	pub spectate_time: Duration,
+	/// Idle time before a player is disconnected.
+	pub disconnect_time: Duration,
+	/// Idle time before a player that went into
+	/// spectate on their own is disconnected.
+	pub spectator_disconnect_time: Duration,
+}
+
+impl Default for AfkConfig {
+	fn default() -> Self {
+		Self {
+			enabled: true,
+			warning: Duration::from_secs(30),
+			spectate_time: Duration::from_secs(5 * 60),
+			disconnect_time: Duration::from_secs(10 * 60),
+			spectator_disconnect_time: Duration::from_secs(20 * 60),
+		}
+	}
+}
+
+/// Whether missiles can hit players on the same
+/// team as the player that fired them.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum FriendlyFireMode {
+	Off,
+	/// Teammates take `reduced_factor` times the
+	/// normal damage.
+	Reduced,
+	Full,
+}
+
+impl Default for FriendlyFireMode {
+	fn default() -> Self {
+		FriendlyFireMode::Off
+	}
+}
+
+/// Settings for friendly fire and for dealing
+/// with players that kill their teammates.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(default)]
+pub struct FriendlyFireConfig {
+	pub mode: FriendlyFireMode,
+	pub reduced_factor: f32,
+	/// Score that a player loses for killing a
+	/// teammate.
+	pub team_kill_penalty: u32,
+	/// Number of team kills after which a player
+	/// gets kicked or banned, `None` to never do so.
+	pub kick_team_kills: Option<u32>,
+	pub ban_team_kills: Option<u32>,
+	/// Team kills older than this don't count
+	/// towards `kick_team_kills` and `ban_team_kills`.
+	pub team_kill_window: Duration,
+	/// How long bans last, `None` for permanent bans.
+	pub ban_duration: Option<Duration>,
+}
+
+impl FriendlyFireConfig {
+	/// Whether missiles can hit teammates at all.
+	pub fn enabled(&self) -> bool {
+		self.mode != FriendlyFireMode::Off
+	}
+}
+
+impl Default for FriendlyFireConfig {
+	fn default() -> Self {
+		Self {
+			mode: FriendlyFireMode::Off,
+			reduced_factor: 0.5,
+			team_kill_penalty: 50,
+			kick_team_kills: Some(3),
+			ban_team_kills: Some(5),
+			team_kill_window: Duration::from_secs(30 * 60),
+			ban_duration: Some(Duration::from_secs(60 * 60)),
+		}
+	}
+}
+
 #[derive(Clone, Serialize, Deserialize)]
+#[serde(default)]
 pub struct Config {
 	pub planes: PlaneInfos,
 	pub mobs: MobInfos,
 	pub upgrades: UpgradeInfos,
+	/// Whether admin commands are available at all.
+	/// Players still need the admin role to use them.
 	pub admin_enabled: bool,
+	/// Account ids and IP addresses that are
+	/// granted moderator or admin roles.
+	pub roles: RoleConfig,
 	pub spawn_shield_duration: Duration,
 	pub shield_duration: Duration,
 	pub inferno_duration: Duration,
+	/// Horizon used for players that have not
+	/// sent a `Horizon` packet yet.
 	pub view_radius: Distance,
+	/// Maximum horizon that a client can request,
+	/// larger values will be clamped to these.
+	pub max_horizon_x: Distance,
+	pub max_horizon_y: Distance,
+	/// Minimum horizon that a client can request,
+	/// smaller values will be raised to these.
+	pub min_horizon_x: Distance,
+	pub min_horizon_y: Distance,
+	/// How long a player is kept in the game after
+	/// their primary connection closes without a
+	/// backup connection to take over.
+	pub backup_grace_period: Duration,
+	/// Thresholds and penalties for detecting
+	/// scripted clients.
+	pub anticheat: AntiCheatConfig,
+	pub bots: BotConfig,
+	pub friendly_fire: FriendlyFireConfig,
+	/// Whether missiles from different teams can
+	/// shoot each other down.
+	pub missile_collisions: bool,
+	/// Rewinding of missile targets to make up for
+	/// the ping of the shooter.
+	pub lag_compensation: LagCompensationConfig,
+	/// How far the position that a client predicts for
+	/// a plane can be from the actual position before
+	/// the server sends a correction.
+	pub max_prediction_error: Distance,
+	/// Chance that a killed player drops an upgrade.
+	/// If this isn't set then the chance given by
+	/// the game mode is used instead.
+	pub upgrade_drop_chance: Option<f32>,
+	pub afk: AfkConfig,
+	/// Whether players can join as spectators without
+	/// a team, by connecting with `?spectate` in the
+	/// URL. They pick a team later with the `join`
+	/// command. Everyone else gets a team as usual.
+	pub spectator_join: bool,
 }
 
 impl Index<Plane> for PlaneInfos {
@@ -345,6 +519,14 @@ impl Default for MobInfos {
 			},
 		);
 
+		map.insert(
+			MobType::Upgrade,
+			MobInfo {
+				lifetime: Some(Duration::from_secs(60)),
+				missile: None,
+			},
+		);
+
 		// TODO: Determine actual powerup lifetime
 		map.insert(
 			MobType::Inferno,
@@ -399,10 +581,162 @@ impl Default for Config {
 			mobs: Default::default(),
 			upgrades: Default::default(),
 			admin_enabled: true,
+			roles: Default::default(),
 			spawn_shield_duration: Duration::from_secs(2),
 			shield_duration: Duration::from_secs(10),
 			inferno_duration: Duration::from_secs(10),
 			view_radius: Distance::new(2250.0),
+			max_horizon_x: Distance::new(3000.0),
+			max_horizon_y: Distance::new(2250.0),
+			min_horizon_x: Distance::new(1000.0),
+			min_horizon_y: Distance::new(500.0),
+			backup_grace_period: Duration::from_secs(10),
+			anticheat: Default::default(),
+			bots: Default::default(),
+			friendly_fire: Default::default(),
+			missile_collisions: false,
+			lag_compensation: Default::default(),
+			max_prediction_error: Distance::new(20.0),
+			upgrade_drop_chance: None,
+			afk: Default::default(),
+			spectator_join: false,
 		}
 	}
 }
+
+/// Path of the file that the [`Config`] was loaded
+/// from, if any. Used when reloading the config.
+#[derive(Clone, Debug, Default)]
+pub struct ConfigPath(pub Option<PathBuf>);
+
+#[derive(Debug)]
+pub enum ConfigError {
+	Load(LoadError),
+	MissingPlane(Plane),
+	MissingMob(Mob),
+	MissingMissileInfo(Mob),
+}
+
+impl fmt::Display for ConfigError {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		use self::ConfigError::*;
+
+		match self {
+			Load(e) => write!(f, "unable to load config file: {}", e),
+			MissingPlane(plane) => write!(f, "no entry for plane {:?} in planes", plane),
+			MissingMob(mob) => write!(f, "no entry for mob {:?} in mobs", mob),
+			MissingMissileInfo(mob) => write!(
+				f,
+				"mob {:?} is used as a missile but has no missile info",
+				mob
+			),
+		}
+	}
+}
+
+impl Error for ConfigError {}
+
+impl From<LoadError> for ConfigError {
+	fn from(e: LoadError) -> Self {
+		ConfigError::Load(e)
+	}
+}
+
+impl Config {
+	/// Load a config from a file.
+	///
+	/// The format is determined by the file extension,
+	/// either `.toml` or `.json`. Any fields that are
+	/// missing from the file will use their default
+	/// values. The loaded config is validated before
+	/// it is returned.
+	pub fn load<P: AsRef<Path>>(path: P) -> Result<Self, ConfigError> {
+		let config: Config = load_file(path)?;
+
+		config.validate()?;
+
+		Ok(config)
+	}
+
+	/// Check that the config has entries for all
+	/// planes and all mobs that the server uses.
+	pub fn validate(&self) -> Result<(), ConfigError> {
+		const PLANES: [PlaneType; 5] = [
+			PlaneType::Predator,
+			PlaneType::Goliath,
+			PlaneType::Mohawk,
+			PlaneType::Tornado,
+			PlaneType::Prowler,
+		];
+
+		for plane in PLANES.iter() {
+			let info = match self.planes.0.get(plane) {
+				Some(info) => info,
+				None => return Err(ConfigError::MissingPlane(*plane)),
+			};
+
+			self.validate_missile(info.missile_type)?;
+		}
+
+		// Fired by the tornado special
+		self.validate_missile(MobType::TornadoTripleMissile)?;
+
+		for mob in [MobType::Inferno, MobType::Shield].iter() {
+			if !self.mobs.0.contains_key(mob) {
+				return Err(ConfigError::MissingMob(*mob));
+			}
+		}
+
+		Ok(())
+	}
+
+	fn validate_missile(&self, mob: Mob) -> Result<(), ConfigError> {
+		match self.mobs.0.get(&mob) {
+			Some(MobInfo { missile: Some(_), .. }) => Ok(()),
+			Some(_) => Err(ConfigError::MissingMissileInfo(mob)),
+			None => Err(ConfigError::MissingMob(mob)),
+		}
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use toml;
+
+	#[test]
+	fn default_config_is_valid() {
+		Config::default().validate().expect("Default config is invalid");
+	}
+
+	#[test]
+	fn missing_plane() {
+		let mut config = Config::default();
+		config.planes.0.remove(&PlaneType::Mohawk);
+
+		match config.validate() {
+			Err(ConfigError::MissingPlane(PlaneType::Mohawk)) => (),
+			x => panic!("Unexpected result {:?}", x),
+		}
+	}
+
+	#[test]
+	fn missing_missile() {
+		let mut config = Config::default();
+		config.mobs.0.remove(&MobType::TornadoTripleMissile);
+
+		match config.validate() {
+			Err(ConfigError::MissingMob(MobType::TornadoTripleMissile)) => (),
+			x => panic!("Unexpected result {:?}", x),
+		}
+	}
+
+	#[test]
+	fn friendly_fire_mode() {
+		let config: Config = toml::from_str("[friendly_fire]\nmode = \"reduced\"\n").unwrap();
+
+		assert_eq!(config.friendly_fire.mode, FriendlyFireMode::Reduced);
+		assert!(config.friendly_fire.enabled());
+		assert!(!Config::default().friendly_fire.enabled());
+	}
+}
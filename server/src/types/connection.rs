@@ -24,6 +24,10 @@ pub struct ConnectionData {
 pub struct ConnectionInfo {
 	pub addr: IpAddr,
 	pub origin: Option<String>,
+	/// Whether the client asked to join as a
+	/// spectator, by connecting to a URL with a
+	/// `spectate` query parameter.
+	pub spectate: bool,
 }
 
 #[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
@@ -36,6 +40,11 @@ pub enum ConnectionType {
 #[derive(Debug)]
 pub enum MessageInfo {
 	ToConnection(ConnectionId),
+	/// A packet that is broadcast to all the given
+	/// connections. The recipients are picked when the
+	/// packet is sent, so players that join later in
+	/// the same frame don't receive it.
+	Broadcast(Vec<ConnectionId>),
 	ToTeam(Entity),
 	ToVisible(Position),
 }
@@ -71,13 +80,13 @@ impl Connections {
 		}
 	}
 
-	pub fn add(&mut self, id: ConnectionId, sink: WsSender, addr: IpAddr, origin: Option<String>) {
+	pub fn add(&mut self, id: ConnectionId, sink: WsSender, info: ConnectionInfo) {
 		let data = ConnectionData {
 			sink: sink,
 			ty: ConnectionType::Inactive,
 			player: None,
 			id: id,
-			info: ConnectionInfo { addr, origin },
+			info: info,
 		};
 
 		self.conns.insert(id, data);
@@ -172,54 +181,40 @@ impl Connections {
 	where
 		I: Into<ServerPacket>,
 	{
-		let msg = msg.into();
-		self.conns
-			.iter()
-			.filter_map(|(id, ref conn)| {
-				if conn.player.is_some() {
-					if conn.ty == ConnectionType::Primary {
-						return Some(id);
-					}
-				}
-				None
-			})
-			.for_each(|id| {
-				self.lock
-					.lock()
-					.unwrap()
-					.send(Message {
-						info: MessageInfo::ToConnection(*id),
-						msg: MessageBody::Packet(msg.clone()),
-					})
-					.unwrap();
-			});
+		let recipients = self
+			.conns
+			.values()
+			.filter(|conn| conn.player.is_some() && conn.ty == ConnectionType::Primary)
+			.map(|conn| conn.id)
+			.collect();
+
+		self.broadcast(recipients, msg.into());
 	}
 
 	pub fn send_to_others<I>(&self, player: Entity, msg: I)
 	where
 		I: Into<ServerPacket>,
 	{
-		let msg = msg.into();
-		self.conns
-			.iter()
-			.filter_map(|(id, ref conn)| {
-				if let Some(ent) = conn.player {
-					if conn.ty == ConnectionType::Primary && ent != player {
-						return Some(id);
-					}
-				}
-				None
+		let recipients = self
+			.conns
+			.values()
+			.filter(|conn| conn.ty == ConnectionType::Primary)
+			.filter(|conn| conn.player.is_some() && conn.player != Some(player))
+			.map(|conn| conn.id)
+			.collect();
+
+		self.broadcast(recipients, msg.into());
+	}
+
+	fn broadcast(&self, recipients: Vec<ConnectionId>, msg: ServerPacket) {
+		self.lock
+			.lock()
+			.unwrap()
+			.send(Message {
+				info: MessageInfo::Broadcast(recipients),
+				msg: MessageBody::Packet(msg),
 			})
-			.for_each(|id| {
-				self.lock
-					.lock()
-					.unwrap()
-					.send(Message {
-						info: MessageInfo::ToConnection(*id),
-						msg: MessageBody::Packet(msg.clone()),
-					})
-					.unwrap()
-			});
+			.unwrap();
 	}
 
 	pub fn send_to_team<I>(&self, player: Entity, msg: I)
@@ -8,6 +8,7 @@ pub struct ConnectionOpen {
 	pub sink: WsSender,
 	pub addr: IpAddr,
 	pub origin: Option<String>,
+	pub spectate: bool,
 }
 
 #[derive(Copy, Clone, Debug)]
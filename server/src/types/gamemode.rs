@@ -20,6 +20,24 @@ pub trait GameMode: Any + Sync + Send {
 	fn assign_plane(&mut self, _player: Entity, _team: Team) -> Plane {
 		Plane::Predator
 	}
+	/// Whether a dead or spectating player may
+	/// currently respawn. Game modes without
+	/// respawning can return `false` here.
+	fn allow_respawn(&self, _player: Entity) -> bool {
+		true
+	}
+	/// Chance, between 0 and 1, that a player drops
+	/// an upgrade when they are killed. This can be
+	/// overridden by `upgrade_drop_chance` in the
+	/// config.
+	fn upgrade_drop_chance(&self) -> f32 {
+		0.0
+	}
+	/// Powerup crates that the game mode places in
+	/// the world, on top of those from the map.
+	fn powerup_spawns(&self) -> Vec<PowerupSpawn> {
+		vec![]
+	}
 
 	fn gametype(&self) -> GameType;
 	fn room(&self) -> String;
@@ -0,0 +1,148 @@
+//! Recent positions of players, used to check
+//! missile hits against where a player was when
+//! the shooter saw them.
+
+use bounded_queue::BoundedQueue;
+use specs::*;
+
+use std::time::{Duration, Instant};
+
+use types::{Position, Rotation};
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(default)]
+pub struct LagCompensationConfig {
+	/// Whether missile hits are checked against
+	/// past positions of the target.
+	pub enabled: bool,
+	/// Maximum amount of time that targets can
+	/// be rewound by, no matter how high the ping
+	/// of the shooter is.
+	pub max_rewind: Duration,
+}
+
+impl Default for LagCompensationConfig {
+	fn default() -> Self {
+		Self {
+			enabled: false,
+			max_rewind: Duration::from_millis(200),
+		}
+	}
+}
+
+impl LagCompensationConfig {
+	/// How far back targets should be rewound for
+	/// a shooter with a ping of `ping` ms.
+	///
+	/// The ping is a round trip, but the shooter only
+	/// sees positions that are one way out of date.
+	pub fn rewind(&self, ping: u32) -> Duration {
+		Duration::from_millis(ping as u64 / 2).min(self.max_rewind)
+	}
+}
+
+/// A snapshot of a player's position from a
+/// previous frame.
+#[derive(Copy, Clone, Debug)]
+pub struct HistoryEntry {
+	pub time: Instant,
+	pub pos: Position,
+	pub rot: Rotation,
+}
+
+/// Ring buffer of the last few positions that a
+/// player was at, recorded once per frame.
+#[derive(Clone, Debug, Component)]
+pub struct PositionHistory {
+	entries: BoundedQueue<HistoryEntry>,
+}
+
+impl PositionHistory {
+	/// Create a history that can hold `frames`
+	/// positions.
+	pub fn new(frames: usize) -> Self {
+		Self {
+			entries: BoundedQueue::new(frames.max(1)),
+		}
+	}
+
+	/// Number of frames needed to rewind by `max_rewind`
+	/// at the given frame time.
+	pub fn frames_for(max_rewind: Duration, frame: Duration) -> usize {
+		let rewind = max_rewind.as_secs() * 1_000_000 + max_rewind.subsec_micros() as u64;
+		let frame = frame.as_secs() * 1_000_000 + frame.subsec_micros() as u64;
+
+		(rewind / frame.max(1)) as usize + 2
+	}
+
+	/// Number of positions that the history can hold.
+	pub fn capacity(&self) -> usize {
+		self.entries.capacity()
+	}
+
+	pub fn record(&mut self, time: Instant, pos: Position, rot: Rotation) {
+		self.entries.push(HistoryEntry { time, pos, rot });
+	}
+
+	/// Drop all recorded positions, for when a player
+	/// respawns or teleports.
+	pub fn clear(&mut self) {
+		while self.entries.pop().is_some() {}
+	}
+
+	/// The most recent recorded position at or before
+	/// `time`. If `time` is older than the whole history
+	/// then the oldest position is returned instead.
+	pub fn at(&self, time: Instant) -> Option<HistoryEntry> {
+		self.entries
+			.iter()
+			.rev()
+			.find(|x| x.time <= time)
+			.or_else(|| self.entries.peek())
+			.cloned()
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	#[test]
+	fn rewind_is_capped() {
+		let config = LagCompensationConfig::default();
+
+		assert_eq!(config.rewind(50), Duration::from_millis(25));
+		assert_eq!(config.rewind(1000), config.max_rewind);
+	}
+
+	#[test]
+	fn lookup_past_position() {
+		let start = Instant::now();
+		let frame = Duration::from_millis(16);
+		let mut history = PositionHistory::new(3);
+
+		assert!(history.at(start).is_none());
+
+		for i in 0..5 {
+			let pos = Position::new(i as f32, 0.0);
+			history.record(start + frame * i, pos, Rotation::new(0.0));
+		}
+
+		let at = |t| history.at(t).unwrap().pos.x.inner();
+
+		assert_eq!(at(start + frame * 4), 4.0);
+		assert_eq!(at(start + frame * 3 + frame / 2), 3.0);
+		// Older than anything in the history
+		assert_eq!(at(start), 2.0);
+	}
+
+	#[test]
+	fn frames_needed() {
+		let frame = Duration::from_micros(16667);
+
+		assert_eq!(
+			PositionHistory::frames_for(Duration::from_millis(200), frame),
+			13
+		);
+	}
+}
@@ -3,7 +3,7 @@ use specs::*;
 use airmash_protocol::{PlaneType, ServerKeyState};
 use types::Plane;
 
-#[derive(Default, Clone, Debug)]
+#[derive(Default, Clone, Debug, PartialEq)]
 pub struct KeyState {
 	pub up: bool,
 	pub down: bool,
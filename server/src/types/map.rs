@@ -0,0 +1,380 @@
+use rand::{self, Closed01};
+
+use std::error::Error;
+use std::fmt;
+use std::path::Path;
+
+use consts::TERRAIN;
+use protocol::PowerupType;
+use types::*;
+use utils::{load_file, LoadError};
+
+use std::time::Duration;
+
+/// Largest map that the server supports. Maps can
+/// be smaller than this but not any larger.
+pub const MAX_BOUND_X: f32 = 16384.0;
+pub const MAX_BOUND_Y: f32 = 8192.0;
+
+/// Half the size of the map, the map extends from
+/// `-x` to `x` and `-y` to `y`.
+#[derive(Copy, Clone, Debug, Serialize, Deserialize)]
+pub struct MapBounds {
+	pub x: Distance,
+	pub y: Distance,
+}
+
+/// An area where players can spawn.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct SpawnZone {
+	pub name: String,
+	/// The team that spawns here, `None` if anyone
+	/// can spawn in this zone.
+	#[serde(default)]
+	pub team: Option<u16>,
+	/// Top left corner of the zone
+	pub pos: Position,
+	/// Size of the zone, a size of zero means that
+	/// players spawn exactly at `pos`.
+	#[serde(default)]
+	pub size: Position,
+}
+
+/// Where a team's flag is placed in CTF.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Base {
+	pub team: u16,
+	pub flag: Position,
+}
+
+/// A place where powerup crates appear.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct PowerupSpawn {
+	pub pos: Position,
+	pub ty: PowerupType,
+	/// Time between a crate being picked up and
+	/// the next one appearing.
+	pub respawn: Duration,
+	/// Up to this much time is randomly added to
+	/// `respawn` so that crates are harder to time.
+	#[serde(default)]
+	pub jitter: Duration,
+}
+
+impl PowerupSpawn {
+	/// Time until the next crate appears after the
+	/// last one was picked up, including jitter.
+	pub fn next_delay(&self) -> Duration {
+		let Closed01(frac) = rand::random::<Closed01<f64>>();
+		let jitter = self.jitter.as_secs() as f64 + self.jitter.subsec_nanos() as f64 * 1.0e-9;
+		let extra = jitter * frac;
+
+		self.respawn + Duration::new(extra as u64, ((extra - extra.floor()) * 1.0e9) as u32)
+	}
+}
+
+/// Description of the map that the game is
+/// played on.
+///
+/// The map is stored as a resource. The default map
+/// is the standard airmash map. Other maps can be
+/// loaded from a TOML or JSON file using [`Map::load`].
+///
+/// Note that clients always render the standard
+/// map, so custom terrain will only be visible to
+/// clients that know about it.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Map {
+	pub name: String,
+	pub bounds: MapBounds,
+	/// Terrain hit circles as `[x, y, radius]`
+	pub terrain: Vec<[i16; 3]>,
+	pub spawns: Vec<SpawnZone>,
+	pub bases: Vec<Base>,
+	/// Powerup crates placed on the map, these are
+	/// in addition to any that the game mode adds.
+	pub powerups: Vec<PowerupSpawn>,
+}
+
+impl Default for Map {
+	fn default() -> Self {
+		Self {
+			name: "default".to_owned(),
+			bounds: MapBounds {
+				x: Distance::new(MAX_BOUND_X),
+				y: Distance::new(MAX_BOUND_Y),
+			},
+			terrain: TERRAIN.to_vec(),
+			spawns: vec![
+				SpawnZone {
+					name: "ffa".to_owned(),
+					team: None,
+					pos: Position::new(Distance::new(-1325.0), Distance::new(-4330.0)),
+					size: Position::new(Distance::new(3500.0), Distance::new(3500.0)),
+				},
+				SpawnZone {
+					name: "blue".to_owned(),
+					team: Some(1),
+					pos: Position::new(Distance::new(-8878.0), Distance::new(-2971.0)),
+					size: Position::default(),
+				},
+				SpawnZone {
+					name: "red".to_owned(),
+					team: Some(2),
+					pos: Position::new(Distance::new(7818.0), Distance::new(-2930.0)),
+					size: Position::default(),
+				},
+			],
+			bases: vec![
+				Base {
+					team: 1,
+					flag: Position::new(Distance::new(-9670.0), Distance::new(-1470.0)),
+				},
+				Base {
+					team: 2,
+					flag: Position::new(Distance::new(8600.0), Distance::new(-940.0)),
+				},
+			],
+			powerups: vec![],
+		}
+	}
+}
+
+#[derive(Debug)]
+pub enum MapError {
+	Load(LoadError),
+	/// The map bounds are larger than the server supports
+	BoundsTooLarge(MapBounds),
+	/// A terrain circle, spawn zone or base is
+	/// outside of the map bounds.
+	OutOfBounds(String),
+	/// There is no spawn zone for the given team, or
+	/// no zone that anyone can spawn in if `None`.
+	NoSpawnZone(Option<u16>),
+}
+
+impl fmt::Display for MapError {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		use self::MapError::*;
+
+		match self {
+			Load(e) => write!(f, "unable to load map file: {}", e),
+			BoundsTooLarge(bounds) => write!(
+				f,
+				"map bounds ({}, {}) are larger than the maximum of ({}, {})",
+				bounds.x.inner(),
+				bounds.y.inner(),
+				MAX_BOUND_X,
+				MAX_BOUND_Y
+			),
+			OutOfBounds(what) => write!(f, "{} is outside of the map", what),
+			NoSpawnZone(Some(team)) => write!(f, "there is no spawn zone for team {}", team),
+			NoSpawnZone(None) => write!(f, "there is no spawn zone without a team"),
+		}
+	}
+}
+
+impl Error for MapError {}
+
+impl From<LoadError> for MapError {
+	fn from(e: LoadError) -> Self {
+		MapError::Load(e)
+	}
+}
+
+impl Map {
+	/// Load a map from a `.toml` or `.json` file.
+	/// The loaded map is validated before it is
+	/// returned.
+	pub fn load<P: AsRef<Path>>(path: P) -> Result<Self, MapError> {
+		let map: Map = load_file(path)?;
+
+		map.validate()?;
+
+		Ok(map)
+	}
+
+	/// Check that everything on the map is within
+	/// the map bounds.
+	pub fn validate(&self) -> Result<(), MapError> {
+		if self.bounds.x.inner() > MAX_BOUND_X || self.bounds.y.inner() > MAX_BOUND_Y {
+			return Err(MapError::BoundsTooLarge(self.bounds));
+		}
+
+		for circle in self.terrain.iter() {
+			let pos = Position::new(circle[0] as f32, circle[1] as f32);
+
+			if !self.contains(pos) {
+				return Err(MapError::OutOfBounds(format!(
+					"terrain circle at ({}, {})",
+					circle[0], circle[1]
+				)));
+			}
+		}
+
+		for zone in self.spawns.iter() {
+			if !self.contains(zone.pos) || !self.contains(zone.pos + zone.size) {
+				return Err(MapError::OutOfBounds(format!(
+					"spawn zone {:?}",
+					zone.name
+				)));
+			}
+		}
+
+		for base in self.bases.iter() {
+			if !self.contains(base.flag) {
+				return Err(MapError::OutOfBounds(format!(
+					"base for team {}",
+					base.team
+				)));
+			}
+		}
+
+		for spawn in self.powerups.iter() {
+			if !self.contains(spawn.pos) {
+				return Err(MapError::OutOfBounds(format!(
+					"{:?} powerup at ({}, {})",
+					spawn.ty,
+					spawn.pos.x.inner(),
+					spawn.pos.y.inner()
+				)));
+			}
+		}
+
+		Ok(())
+	}
+
+	/// Check that players on each of `teams` have a
+	/// spawn zone. Game modes that put every player
+	/// on their own team should pass no teams, which
+	/// requires a zone that anyone can spawn in.
+	///
+	/// This depends on the game mode, so it isn't
+	/// part of [`validate`][0] and needs to be called
+	/// before starting the server.
+	///
+	/// [0]: #method.validate
+	pub fn check_spawns(&self, teams: &[Team]) -> Result<(), MapError> {
+		if teams.is_empty() && !self.spawns.iter().any(|zone| zone.team.is_none()) {
+			return Err(MapError::NoSpawnZone(None));
+		}
+
+		for &team in teams {
+			if self.spawn_zones(team).is_empty() {
+				return Err(MapError::NoSpawnZone(Some(team.0)));
+			}
+		}
+
+		Ok(())
+	}
+
+	/// Whether a position is within the map bounds.
+	pub fn contains(&self, pos: Position) -> bool {
+		pos.x.abs() <= self.bounds.x && pos.y.abs() <= self.bounds.y
+	}
+
+	/// All spawn zones that players on `team` can
+	/// spawn in. If there are no zones specifically
+	/// for the team then the zones without a team
+	/// are used.
+	pub fn spawn_zones(&self, team: Team) -> Vec<&SpawnZone> {
+		let zones = self
+			.spawns
+			.iter()
+			.filter(|zone| zone.team == Some(team.0))
+			.collect::<Vec<_>>();
+
+		if !zones.is_empty() {
+			return zones;
+		}
+
+		self.spawns.iter().filter(|zone| zone.team.is_none()).collect()
+	}
+
+	/// Pick a random spawn position for a player on
+	/// `team`. Returns `None` if the map has no spawn
+	/// zones that the team can use.
+	pub fn random_spawn(&self, team: Team) -> Option<Position> {
+		let zones = self.spawn_zones(team);
+
+		if zones.is_empty() {
+			return None;
+		}
+
+		let zone = zones[rand::random::<usize>() % zones.len()];
+		let Closed01(x) = rand::random::<Closed01<f32>>();
+		let Closed01(y) = rand::random::<Closed01<f32>>();
+
+		Some(zone.pos + zone.size * Vector2::new(x, y))
+	}
+
+	/// Get the base for a team, if there is one.
+	pub fn base(&self, team: Team) -> Option<&Base> {
+		self.bases.iter().find(|base| base.team == team.0)
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	#[test]
+	fn default_map_is_valid() {
+		Map::default().validate().expect("Default map is invalid");
+	}
+
+	#[test]
+	fn team_spawns_fall_back_to_shared_zones() {
+		let map = Map::default();
+
+		assert_eq!(map.spawn_zones(Team(1))[0].name, "blue");
+		assert_eq!(map.spawn_zones(Team(2))[0].name, "red");
+		assert_eq!(map.spawn_zones(Team(57))[0].name, "ffa");
+	}
+
+	#[test]
+	fn missing_spawn_zones() {
+		let mut map = Map::default();
+		map.spawns.retain(|zone| zone.team == Some(1));
+
+		assert!(map.check_spawns(&[Team(1)]).is_ok());
+
+		match map.check_spawns(&[Team(1), Team(2)]) {
+			Err(MapError::NoSpawnZone(Some(2))) => (),
+			x => panic!("Unexpected result {:?}", x),
+		}
+		match map.check_spawns(&[]) {
+			Err(MapError::NoSpawnZone(None)) => (),
+			x => panic!("Unexpected result {:?}", x),
+		}
+	}
+
+	#[test]
+	fn powerup_jitter_is_bounded() {
+		let spawn = PowerupSpawn {
+			pos: Position::default(),
+			ty: PowerupType::Shield,
+			respawn: Duration::from_secs(60),
+			jitter: Duration::from_secs(10),
+		};
+
+		for _ in 0..100 {
+			let delay = spawn.next_delay();
+
+			assert!(delay >= Duration::from_secs(60));
+			assert!(delay <= Duration::from_secs(70));
+		}
+	}
+
+	#[test]
+	fn terrain_out_of_bounds() {
+		let mut map = Map::default();
+		map.bounds.x = Distance::new(1000.0);
+
+		match map.validate() {
+			Err(MapError::OutOfBounds(_)) => (),
+			x => panic!("Unexpected result {:?}", x),
+		}
+	}
+}
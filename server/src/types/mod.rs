@@ -1,6 +1,7 @@
 mod components;
 mod flags;
 mod future;
+mod history;
 mod keystate;
 mod pingdata;
 mod powerups;
@@ -10,8 +11,12 @@ mod upgrades;
 
 mod connection_events;
 
+pub mod account;
+pub mod anticheat;
 pub mod collision;
 pub mod config;
+pub mod map;
+pub mod moderation;
 pub mod systemdata;
 
 pub(crate) mod connection;
@@ -19,10 +24,20 @@ pub(crate) mod gamemode;
 
 pub use protocol::Vector2;
 
+pub use self::account::{
+	Account, AccountStats, AccountStore, Accounts, FileAccountStore, FileSessionValidator,
+	MemoryAccountStore, NoSessions, SessionValidator, Sessions,
+};
+pub use self::anticheat::{AntiCheatAction, AntiCheatConfig, AntiCheatState, Offense};
 pub use self::components::*;
-pub use self::config::Config;
+pub use self::config::{
+	AfkConfig, BotConfig, Config, ConfigError, ConfigPath, FriendlyFireConfig, FriendlyFireMode,
+};
 pub use self::future::FutureDispatcher;
+pub use self::history::{HistoryEntry, LagCompensationConfig, PositionHistory};
 pub use self::keystate::*;
+pub use self::map::{Base, Map, MapBounds, MapError, PowerupSpawn, SpawnZone};
+pub use self::moderation::{Ban, BanList, Role, RoleConfig, TeamKillRecord};
 pub use self::pingdata::*;
 pub use self::powerups::*;
 pub use self::ratelimit::RateLimiter;
@@ -33,6 +48,6 @@ pub mod event {
 	pub use types::connection_events::*;
 }
 
-pub use self::connection::{ConnectionType, Connections};
+pub use self::connection::{ConnectionInfo, ConnectionType, Connections};
 pub use self::gamemode::{GameMode, GameModeWriter};
 pub use self::systemdata::fire_missiles::MissileFireInfo;
@@ -0,0 +1,238 @@
+use fnv::FnvHashMap;
+use serde_json;
+use specs::*;
+use uuid::Uuid;
+
+use std::fs::{self, File};
+use std::io::{self, BufReader, BufWriter};
+use std::net::IpAddr;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant, SystemTime};
+
+/// Permission level of a player.
+///
+/// Roles are ordered so that a higher role
+/// has all the permissions of the lower ones.
+#[derive(
+	Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize, Component,
+)]
+#[serde(rename_all = "lowercase")]
+pub enum Role {
+	Player,
+	Moderator,
+	Admin,
+}
+
+impl Default for Role {
+	fn default() -> Self {
+		Role::Player
+	}
+}
+
+/// Identities that are granted elevated roles.
+///
+/// A player gets the highest role that either their
+/// account or their IP address is granted. Roles are
+/// given by account id rather than session token, so
+/// only players with a validated session can get a
+/// role through `accounts`.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RoleConfig {
+	pub accounts: FnvHashMap<Uuid, Role>,
+	pub addrs: FnvHashMap<IpAddr, Role>,
+}
+
+impl RoleConfig {
+	pub fn role_for(&self, account: Option<Uuid>, addr: IpAddr) -> Role {
+		let by_account = account
+			.and_then(|id| self.accounts.get(&id))
+			.cloned()
+			.unwrap_or_default();
+		let by_addr = self.addrs.get(&addr).cloned().unwrap_or_default();
+
+		by_account.max(by_addr)
+	}
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Ban {
+	pub addr: IpAddr,
+	/// When the ban expires, `None` for
+	/// permanent bans.
+	pub expiry: Option<SystemTime>,
+	pub reason: String,
+}
+
+impl Ban {
+	pub fn is_active(&self, now: SystemTime) -> bool {
+		self.expiry.map(|expiry| expiry > now).unwrap_or(true)
+	}
+}
+
+/// IP bans that are currently in effect.
+///
+/// If the ban list was opened from a file then
+/// it will be rewritten every time that it is
+/// modified so that bans persist across restarts.
+#[derive(Default)]
+pub struct BanList {
+	path: Option<PathBuf>,
+	bans: FnvHashMap<IpAddr, Ban>,
+}
+
+impl BanList {
+	/// Open the ban list at `path`. If the file
+	/// doesn't exist then it will be created the
+	/// first time that someone is banned.
+	pub fn open<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+		let path = path.as_ref().to_owned();
+
+		let bans: Vec<Ban> = match File::open(&path) {
+			Ok(file) => serde_json::from_reader(BufReader::new(file))
+				.map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?,
+			Err(ref e) if e.kind() == io::ErrorKind::NotFound => vec![],
+			Err(e) => return Err(e),
+		};
+
+		Ok(Self {
+			path: Some(path),
+			bans: bans.into_iter().map(|ban| (ban.addr, ban)).collect(),
+		})
+	}
+
+	/// Get the active ban for an address, if any.
+	pub fn get(&self, addr: IpAddr, now: SystemTime) -> Option<&Ban> {
+		self.bans.get(&addr).filter(|ban| ban.is_active(now))
+	}
+
+	pub fn is_banned(&self, addr: IpAddr, now: SystemTime) -> bool {
+		self.get(addr, now).is_some()
+	}
+
+	pub fn ban(&mut self, ban: Ban) -> io::Result<()> {
+		self.bans.insert(ban.addr, ban);
+		self.flush()
+	}
+
+	/// Remove the ban for an address. Returns whether
+	/// there was a ban to remove.
+	pub fn unban(&mut self, addr: IpAddr) -> io::Result<bool> {
+		if self.bans.remove(&addr).is_none() {
+			return Ok(false);
+		}
+
+		self.flush().map(|_| true)
+	}
+
+	fn flush(&mut self) -> io::Result<()> {
+		let path = match self.path {
+			Some(ref path) => path,
+			None => return Ok(()),
+		};
+
+		// Don't bother saving bans that have already run out
+		let now = SystemTime::now();
+		self.bans.retain(|_, ban| ban.is_active(now));
+
+		let tmp = path.with_extension("tmp");
+
+		{
+			let file = File::create(&tmp)?;
+			let bans = self.bans.values().collect::<Vec<_>>();
+			serde_json::to_writer_pretty(BufWriter::new(file), &bans)
+				.map_err(|e| io::Error::new(io::ErrorKind::Other, e))?;
+		}
+
+		fs::rename(&tmp, path)
+	}
+}
+
+/// Recent team kills by players from each IP
+/// address.
+///
+/// This is kept separately from the players so
+/// that leaving and joining again doesn't reset
+/// the count. Team kills are forgotten after a
+/// while so that unrelated players sharing an
+/// address (e.g. behind a NAT) don't slowly add
+/// up to a ban.
+#[derive(Default, Debug)]
+pub struct TeamKillRecord(FnvHashMap<IpAddr, Vec<Instant>>);
+
+impl TeamKillRecord {
+	/// Record a team kill from `addr` and return the
+	/// number of team kills from it within the last
+	/// `window`.
+	pub fn add(&mut self, addr: IpAddr, now: Instant, window: Duration) -> u32 {
+		// Drop old team kills from every address so that
+		// the record doesn't keep growing.
+		self.0.retain(|_, kills| {
+			kills.retain(|&time| now - time < window);
+			!kills.is_empty()
+		});
+
+		let kills = self.0.entry(addr).or_insert_with(Vec::new);
+		kills.push(now);
+		kills.len() as u32
+	}
+
+	/// Forget the team kills from `addr`, e.g. once
+	/// it has been banned for them.
+	pub fn clear(&mut self, addr: IpAddr) {
+		self.0.remove(&addr);
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	#[test]
+	fn highest_role_wins() {
+		let account = Uuid::nil();
+		let addr: IpAddr = "127.0.0.1".parse().unwrap();
+
+		let mut config = RoleConfig::default();
+		assert_eq!(config.role_for(Some(account), addr), Role::Player);
+
+		config.addrs.insert(addr, Role::Moderator);
+		assert_eq!(config.role_for(None, addr), Role::Moderator);
+
+		config.accounts.insert(account, Role::Admin);
+		assert_eq!(config.role_for(Some(account), addr), Role::Admin);
+	}
+
+	#[test]
+	fn ban_expiry() {
+		let addr: IpAddr = "10.0.0.1".parse().unwrap();
+		let now = SystemTime::now();
+
+		let mut bans = BanList::default();
+		bans.ban(Ban {
+			addr,
+			expiry: Some(now + Duration::from_secs(60)),
+			reason: String::new(),
+		})
+		.unwrap();
+
+		assert!(bans.is_banned(addr, now));
+		assert!(!bans.is_banned(addr, now + Duration::from_secs(120)));
+
+		assert!(bans.unban(addr).unwrap());
+		assert!(!bans.is_banned(addr, now));
+	}
+
+	#[test]
+	fn team_kills_decay() {
+		let addr: IpAddr = "10.0.0.1".parse().unwrap();
+		let window = Duration::from_secs(60);
+		let now = Instant::now();
+
+		let mut record = TeamKillRecord::default();
+		assert_eq!(record.add(addr, now, window), 1);
+		assert_eq!(record.add(addr, now + Duration::from_secs(30), window), 2);
+		assert_eq!(record.add(addr, now + Duration::from_secs(70), window), 2);
+		assert_eq!(record.add(addr, now + Duration::from_secs(200), window), 1);
+	}
+}
@@ -0,0 +1,75 @@
+use serde::de::DeserializeOwned;
+use serde_json;
+use toml;
+
+use std::error::Error;
+use std::fmt;
+use std::fs::File;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+
+/// Errors that can happen while reading a config
+/// or map file with [`load_file`].
+#[derive(Debug)]
+pub enum LoadError {
+	Io(io::Error),
+	Parse(String),
+	UnknownFormat(PathBuf),
+}
+
+impl fmt::Display for LoadError {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		use self::LoadError::*;
+
+		match self {
+			Io(e) => write!(f, "unable to read file: {}", e),
+			Parse(e) => write!(f, "unable to parse file: {}", e),
+			UnknownFormat(path) => write!(
+				f,
+				"unknown format for {}, expected a .toml or .json file",
+				path.display()
+			),
+		}
+	}
+}
+
+impl Error for LoadError {}
+
+impl From<io::Error> for LoadError {
+	fn from(e: io::Error) -> Self {
+		LoadError::Io(e)
+	}
+}
+
+/// Read a TOML or JSON file, depending on the
+/// file extension.
+pub fn load_file<T, P>(path: P) -> Result<T, LoadError>
+where
+	T: DeserializeOwned,
+	P: AsRef<Path>,
+{
+	let path = path.as_ref();
+	let mut contents = String::new();
+	File::open(path)?.read_to_string(&mut contents)?;
+
+	match path.extension().and_then(|x| x.to_str()) {
+		Some("toml") => toml::from_str(&contents).map_err(|e| LoadError::Parse(e.to_string())),
+		Some("json") => {
+			serde_json::from_str(&contents).map_err(|e| LoadError::Parse(e.to_string()))
+		}
+		_ => Err(LoadError::UnknownFormat(path.to_owned())),
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	#[test]
+	fn missing_file() {
+		match load_file::<u32, _>("this-file-does-not-exist.toml") {
+			Err(LoadError::Io(_)) => (),
+			x => panic!("Unexpected result {:?}", x),
+		}
+	}
+}
@@ -7,7 +7,9 @@ mod try_get;
 mod tuple_array;
 
 pub mod event_handler;
+pub mod load;
 pub mod maybe_init;
 pub mod timer;
 
 pub use self::event_handler::{EventHandler, EventHandlerTypeProvider};
+pub use self::load::{load_file, LoadError};
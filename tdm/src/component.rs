@@ -0,0 +1,110 @@
+use shrev::*;
+
+use server::Team;
+
+/// Kills scored by each team during the current
+/// round. Team `n` is at index `n - 1`.
+#[derive(Clone, Debug, Default)]
+pub struct TeamScores(pub Vec<u32>);
+
+impl TeamScores {
+	pub fn new(teams: u16) -> Self {
+		TeamScores(vec![0; teams as usize])
+	}
+
+	pub fn get(&self, team: Team) -> u32 {
+		let idx = (team.0 as usize).wrapping_sub(1);
+
+		self.0.get(idx).cloned().unwrap_or(0)
+	}
+
+	/// Record a kill for `team`, returning the new
+	/// total for that team.
+	pub fn add_kill(&mut self, team: Team) -> u32 {
+		let idx = (team.0 as usize).wrapping_sub(1);
+
+		match self.0.get_mut(idx) {
+			Some(kills) => {
+				*kills += 1;
+				*kills
+			}
+			None => 0,
+		}
+	}
+
+	/// The team with the most kills, or `None` if
+	/// there is a tie for first place.
+	pub fn leader(&self) -> Option<Team> {
+		let max = *self.0.iter().max()?;
+
+		let mut leaders = self
+			.0
+			.iter()
+			.enumerate()
+			.filter(|&(_, &kills)| kills == max);
+
+		match (leaders.next(), leaders.next()) {
+			(Some((idx, _)), None) => Some(Team(idx as u16 + 1)),
+			_ => None,
+		}
+	}
+
+	/// All teams along with their kills
+	pub fn iter<'a>(&'a self) -> impl Iterator<Item = (Team, u32)> + 'a {
+		self.0
+			.iter()
+			.enumerate()
+			.map(|(idx, &kills)| (Team(idx as u16 + 1), kills))
+	}
+}
+
+/// Incremented every time a round starts, used
+/// to ignore time limits from earlier rounds.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct Round(pub u32);
+
+#[derive(Copy, Clone, Debug)]
+pub struct GameStartEvent;
+
+#[derive(Copy, Clone, Debug)]
+pub struct GameWinEvent {
+	/// `None` if the round ended in a draw.
+	pub winning_team: Option<Team>,
+}
+
+#[derive(Copy, Clone, Debug)]
+pub struct GameActive(pub bool);
+
+pub type OnGameWin = EventChannel<GameWinEvent>;
+pub type OnGameWinReader = ReaderId<GameWinEvent>;
+
+pub type OnGameStart = EventChannel<GameStartEvent>;
+pub type OnGameStartReader = ReaderId<GameStartEvent>;
+
+impl Default for GameActive {
+	fn default() -> Self {
+		GameActive(true)
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	#[test]
+	fn leader_with_tie() {
+		let mut kills = TeamScores::new(3);
+
+		assert_eq!(kills.leader(), None);
+
+		kills.add_kill(Team(2));
+		assert_eq!(kills.leader(), Some(Team(2)));
+
+		kills.add_kill(Team(3));
+		assert_eq!(kills.leader(), None);
+
+		// Kills for teams that don't exist are ignored
+		assert_eq!(kills.add_kill(Team(0)), 0);
+		assert_eq!(kills.add_kill(Team(4)), 0);
+	}
+}
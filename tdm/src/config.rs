@@ -0,0 +1,98 @@
+use server::utils::{load_file, LoadError};
+use server::*;
+
+use std::error::Error;
+use std::fmt;
+use std::path::Path;
+use std::time::Duration;
+
+/// Tunable parameters for team deathmatch games.
+///
+/// This is stored as a resource and can be loaded
+/// from a TOML or JSON file using [`TdmConfig::load`].
+/// Any fields that are missing from the file will
+/// use their default values.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TdmConfig {
+	/// Number of teams that players are split
+	/// into. Teams are numbered starting at 1.
+	pub teams: u16,
+	/// Number of kills a team needs to win a round
+	pub kills_to_win: u32,
+	/// Maximum length of a round. If no team has
+	/// reached `kills_to_win` by then, the team with
+	/// the most kills wins.
+	pub time_limit: Duration,
+	/// Time between winning a round and a new round
+	/// starting.
+	pub game_reset_time: Duration,
+	/// The base score that a winning player would get
+	/// if they were the only ones on the server. This
+	/// value will be multiplied by the number of players
+	/// in the server (up to a max of 10 times).
+	pub game_win_bounty_base: Score,
+}
+
+impl TdmConfig {
+	/// Load the config from a TOML or JSON file,
+	/// depending on the file extension.
+	pub fn load<P: AsRef<Path>>(path: P) -> Result<Self, TdmConfigError> {
+		let config: TdmConfig = load_file(path)?;
+
+		config.validate()?;
+
+		Ok(config)
+	}
+
+	pub fn validate(&self) -> Result<(), TdmConfigError> {
+		if self.teams < 2 {
+			return Err(TdmConfigError::TooFewTeams);
+		}
+
+		if self.kills_to_win == 0 {
+			return Err(TdmConfigError::NoKillsToWin);
+		}
+
+		Ok(())
+	}
+}
+
+impl Default for TdmConfig {
+	fn default() -> Self {
+		Self {
+			teams: 2,
+			kills_to_win: 30,
+			time_limit: Duration::from_secs(10 * 60),
+			game_reset_time: Duration::from_secs(30),
+			game_win_bounty_base: Score(100),
+		}
+	}
+}
+
+#[derive(Debug)]
+pub enum TdmConfigError {
+	Load(LoadError),
+	TooFewTeams,
+	NoKillsToWin,
+}
+
+impl fmt::Display for TdmConfigError {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		use self::TdmConfigError::*;
+
+		match self {
+			Load(e) => write!(f, "unable to load TDM config file: {}", e),
+			TooFewTeams => write!(f, "teams must be at least 2"),
+			NoKillsToWin => write!(f, "kills_to_win must be at least 1"),
+		}
+	}
+}
+
+impl Error for TdmConfigError {}
+
+impl From<LoadError> for TdmConfigError {
+	fn from(e: LoadError) -> Self {
+		TdmConfigError::Load(e)
+	}
+}
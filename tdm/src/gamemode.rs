@@ -0,0 +1,96 @@
+use server::protocol::GameType;
+use server::*;
+
+use rand;
+use specs::Entity;
+
+#[derive(Default, Debug)]
+pub struct TDMGameMode {
+	/// Number of players on each team, team `n`
+	/// is at index `n - 1`.
+	pub teams: Vec<u16>,
+	/// Map that the game is being played on,
+	/// used to find the team respawn points.
+	pub map: Map,
+}
+
+impl TDMGameMode {
+	pub fn new(map: Map, teams: u16) -> Self {
+		Self {
+			teams: vec![0; teams as usize],
+			map,
+		}
+	}
+
+	/// Remove a player from the count for `team`.
+	pub fn remove_player(&mut self, team: Team) {
+		let idx = (team.0 as usize).wrapping_sub(1);
+
+		if let Some(count) = self.teams.get_mut(idx) {
+			*count -= (*count).min(1);
+		}
+	}
+}
+
+impl GameMode for TDMGameMode {
+	fn assign_team(&mut self, _: Entity) -> Team {
+		let smallest = *self.teams.iter().min().unwrap();
+
+		// Pick randomly between all the teams that are
+		// tied for the least players.
+		let candidates = self
+			.teams
+			.iter()
+			.enumerate()
+			.filter(|&(_, &count)| count == smallest)
+			.map(|(idx, _)| idx)
+			.collect::<Vec<_>>();
+		let idx = candidates[rand::random::<usize>() % candidates.len()];
+
+		self.teams[idx] += 1;
+
+		info!("Added to team {}, teams are now {:?}", idx + 1, self.teams);
+
+		Team(idx as u16 + 1)
+	}
+
+	fn spawn_pos(&mut self, _: Entity, team: Team) -> Position {
+		match self.map.random_spawn(team) {
+			Some(pos) => pos,
+			None => panic!("Map {:?} has no spawn zone for team {}", self.map.name, team.0),
+		}
+	}
+
+	fn gametype(&self) -> GameType {
+		// The client has no team deathmatch mode, but
+		// FFA works with any team numbers.
+		GameType::FFA
+	}
+
+	fn room(&self) -> String {
+		"matrix".to_owned()
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use specs::{Builder, World};
+
+	#[test]
+	fn teams_stay_balanced() {
+		let mut world = World::new();
+		let mut gamemode = TDMGameMode::new(Map::default(), 3);
+
+		for _ in 0..7 {
+			let ent = world.create_entity().build();
+			gamemode.assign_team(ent);
+		}
+
+		let max = *gamemode.teams.iter().max().unwrap();
+		let min = *gamemode.teams.iter().min().unwrap();
+
+		assert_eq!(gamemode.teams.iter().sum::<u16>(), 7);
+		assert!(max - min <= 1);
+	}
+}
@@ -0,0 +1,83 @@
+use specs::*;
+
+use server::component::channel::*;
+use server::systems::handlers::game::on_player_killed::KnownEventSources;
+use server::*;
+
+use component::*;
+use config::TdmConfig;
+
+/// Add up the kills of each team and end the
+/// round once one of them reaches the kill
+/// target.
+#[derive(Default)]
+pub struct CountKills {
+	reader: Option<OnPlayerKilledReader>,
+}
+
+#[derive(SystemData)]
+pub struct CountKillsData<'a> {
+	channel: Read<'a, OnPlayerKilled>,
+	win_channel: Write<'a, OnGameWin>,
+	scores: Write<'a, TeamScores>,
+	game_active: Read<'a, GameActive>,
+	config: Read<'a, TdmConfig>,
+
+	team: ReadStorage<'a, Team>,
+}
+
+impl<'a> System<'a> for CountKills {
+	type SystemData = CountKillsData<'a>;
+
+	fn setup(&mut self, res: &mut Resources) {
+		Self::SystemData::setup(res);
+
+		self.reader = Some(res.fetch_mut::<OnPlayerKilled>().register_reader());
+	}
+
+	fn run(&mut self, mut data: Self::SystemData) {
+		let mut winning_team = None;
+
+		for evt in data.channel.read(self.reader.as_mut().unwrap()) {
+			// Kills between rounds don't count
+			if !data.game_active.0 {
+				continue;
+			}
+
+			let team = match data.team.get(evt.killer) {
+				Some(&team) => team,
+				None => continue,
+			};
+
+			// Neither team kills nor deaths without a
+			// killer count towards the team's total.
+			if data.team.get(evt.player) == Some(&team) {
+				continue;
+			}
+
+			let kills = data.scores.add_kill(team);
+
+			if kills >= data.config.kills_to_win && winning_team.is_none() {
+				winning_team = Some(team);
+			}
+		}
+
+		if let Some(team) = winning_team {
+			data.win_channel.single_write(GameWinEvent {
+				winning_team: Some(team),
+			});
+		}
+	}
+}
+
+impl SystemInfo for CountKills {
+	type Dependencies = KnownEventSources;
+
+	fn name() -> &'static str {
+		concat!(module_path!(), "::", line!())
+	}
+
+	fn new() -> Self {
+		Self::default()
+	}
+}
@@ -0,0 +1,16 @@
+mod count_kills;
+mod register;
+mod score_detailed;
+mod start_first_round;
+mod update_gamemode;
+
+pub mod on_game_start;
+pub mod on_game_win;
+pub mod timer;
+
+pub use self::register::register;
+
+pub use self::count_kills::CountKills;
+pub use self::score_detailed::ScoreDetailed;
+pub use self::start_first_round::StartFirstRound;
+pub use self::update_gamemode::UpdateGameModeOnPlayerLeave;
@@ -0,0 +1,5 @@
+mod reset_scores;
+mod respawn_all;
+
+pub use self::reset_scores::ResetScores;
+pub use self::respawn_all::RespawnAll;
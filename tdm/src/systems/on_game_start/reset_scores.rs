@@ -0,0 +1,67 @@
+use specs::*;
+
+use server::component::event::TimerEvent;
+use server::types::FutureDispatcher;
+use server::*;
+
+use component::*;
+use config::TdmConfig;
+use consts::TIME_LIMIT_TIMER;
+use systems::timer::GameStart;
+use systems::StartFirstRound;
+
+/// Reset all team kills to 0 when a round
+/// starts and set up the time limit for it.
+#[derive(Default)]
+pub struct ResetScores {
+	reader: Option<OnGameStartReader>,
+}
+
+#[derive(SystemData)]
+pub struct ResetScoresData<'a> {
+	channel: Read<'a, OnGameStart>,
+	scores: Write<'a, TeamScores>,
+	game_active: Write<'a, GameActive>,
+	round: Write<'a, Round>,
+	future: ReadExpect<'a, FutureDispatcher>,
+	config: Read<'a, TdmConfig>,
+}
+
+impl<'a> System<'a> for ResetScores {
+	type SystemData = ResetScoresData<'a>;
+
+	fn setup(&mut self, res: &mut Resources) {
+		Self::SystemData::setup(res);
+
+		self.reader = Some(res.fetch_mut::<OnGameStart>().register_reader());
+	}
+
+	fn run(&mut self, mut data: Self::SystemData) {
+		for _ in data.channel.read(self.reader.as_mut().unwrap()) {
+			*data.scores = TeamScores::new(data.config.teams);
+			data.game_active.0 = true;
+			data.round.0 += 1;
+
+			let round = *data.round;
+
+			data.future
+				.run_delayed(data.config.time_limit, move |inst| TimerEvent {
+					ty: *TIME_LIMIT_TIMER,
+					instant: inst,
+					data: Some(Box::new(round)),
+				});
+		}
+	}
+}
+
+impl SystemInfo for ResetScores {
+	type Dependencies = (GameStart, StartFirstRound);
+
+	fn name() -> &'static str {
+		concat!(module_path!(), "::", line!())
+	}
+
+	fn new() -> Self {
+		Self::default()
+	}
+}
@@ -0,0 +1,85 @@
+use specs::*;
+
+use server::component::channel::*;
+use server::component::event::*;
+use server::component::flag::*;
+use server::consts::spectate::SPECTATOR_TEAM;
+use server::systems::handlers::game::on_join::AllJoinHandlers;
+use server::*;
+
+use component::*;
+use systems::timer::GameStart;
+use systems::StartFirstRound;
+
+/// Respawn all players, including those that
+/// are spectating, at the start of a round.
+#[derive(Default)]
+pub struct RespawnAll {
+	reader: Option<OnGameStartReader>,
+}
+
+#[derive(SystemData)]
+pub struct RespawnAllData<'a> {
+	channel: Read<'a, OnGameStart>,
+	respawn_channel: Write<'a, OnPlayerRespawn>,
+
+	entities: Entities<'a>,
+	is_player: ReadStorage<'a, IsPlayer>,
+	team: ReadStorage<'a, Team>,
+	is_spec: WriteStorage<'a, IsSpectating>,
+	is_dead: ReadStorage<'a, IsDead>,
+}
+
+impl<'a> System<'a> for RespawnAll {
+	type SystemData = RespawnAllData<'a>;
+
+	fn setup(&mut self, res: &mut Resources) {
+		Self::SystemData::setup(res);
+
+		self.reader = Some(res.fetch_mut::<OnGameStart>().register_reader());
+	}
+
+	fn run(&mut self, mut data: Self::SystemData) {
+		use self::PlayerRespawnPrevStatus::*;
+
+		for _ in data.channel.read(self.reader.as_mut().unwrap()) {
+			// Spectators without a team stay in spec
+			let players = (&*data.entities, &data.team, data.is_player.mask())
+				.join()
+				.filter(|(_, team, ..)| **team != SPECTATOR_TEAM)
+				.map(|(ent, ..)| ent)
+				.collect::<Vec<_>>();
+
+			for player in players {
+				// The spectating flag has to be removed first,
+				// otherwise the respawn packet won't be sent.
+				let was_spec = data.is_spec.remove(player).is_some();
+				let was_dead = data.is_dead.get(player).is_some();
+
+				data.respawn_channel.single_write(PlayerRespawn {
+					player,
+					prev_status: if was_spec || was_dead { Dead } else { Alive },
+				});
+			}
+		}
+	}
+}
+
+impl SystemInfo for RespawnAll {
+	type Dependencies = (
+		// PlayerRespawn accesses position by entity,
+		// there's a race condition in ordering here
+		// if a player joins exactly as a game is starting.
+		AllJoinHandlers,
+		GameStart,
+		StartFirstRound,
+	);
+
+	fn name() -> &'static str {
+		concat!(module_path!(), "::", line!())
+	}
+
+	fn new() -> Self {
+		Self::default()
+	}
+}
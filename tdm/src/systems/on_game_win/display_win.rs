@@ -0,0 +1,78 @@
+use server::*;
+use specs::*;
+
+use component::*;
+use config::TdmConfig;
+use systems::timer::TimeLimit;
+use systems::CountKills;
+
+use server::component::counter::PlayersGame;
+use server::protocol::server::ServerMessage;
+use server::protocol::ServerMessageType;
+
+/// Announce the winning team along with the final
+/// kills of every team. The client has no win
+/// screen for team deathmatch so this uses a
+/// banner instead.
+#[derive(Default)]
+pub struct DisplayWin {
+	reader: Option<OnGameWinReader>,
+}
+
+#[derive(SystemData)]
+pub struct DisplayWinData<'a> {
+	channel: Read<'a, OnGameWin>,
+	conns: Read<'a, Connections>,
+	players_game: Read<'a, PlayersGame>,
+	scores: Read<'a, TeamScores>,
+	config: Read<'a, TdmConfig>,
+}
+
+impl<'a> System<'a> for DisplayWin {
+	type SystemData = DisplayWinData<'a>;
+
+	fn setup(&mut self, res: &mut Resources) {
+		Self::SystemData::setup(res);
+
+		self.reader = Some(res.fetch_mut::<OnGameWin>().register_reader());
+	}
+
+	fn run(&mut self, data: Self::SystemData) {
+		for evt in data.channel.read(self.reader.as_mut().unwrap()) {
+			let totals = data
+				.scores
+				.iter()
+				.map(|(team, kills)| format!("Team {}: {}", team.0, kills))
+				.collect::<Vec<_>>()
+				.join(" | ");
+
+			let text = match evt.winning_team {
+				Some(team) => format!(
+					"Team {} wins the round! +{} bounty - {}",
+					team.0,
+					data.players_game.0.min(10) * data.config.game_win_bounty_base.0,
+					totals
+				),
+				None => format!("The round ended in a draw - {}", totals),
+			};
+
+			data.conns.send_to_all(ServerMessage {
+				ty: ServerMessageType::Banner,
+				duration: 8000,
+				text,
+			});
+		}
+	}
+}
+
+impl SystemInfo for DisplayWin {
+	type Dependencies = (CountKills, TimeLimit);
+
+	fn name() -> &'static str {
+		concat!(module_path!(), "::", line!())
+	}
+
+	fn new() -> Self {
+		Self::default()
+	}
+}
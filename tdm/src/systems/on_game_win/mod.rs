@@ -0,0 +1,11 @@
+mod award_bounty;
+mod display_win;
+mod set_game_active;
+mod setup_game_start;
+mod setup_messages;
+
+pub use self::award_bounty::AwardBounty;
+pub use self::display_win::DisplayWin;
+pub use self::set_game_active::SetGameActive;
+pub use self::setup_game_start::SetupGameStart;
+pub use self::setup_messages::SetupMessages;
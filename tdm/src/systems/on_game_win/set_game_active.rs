@@ -0,0 +1,51 @@
+use specs::*;
+
+use server::*;
+
+use component::*;
+use systems::timer::TimeLimit;
+use systems::CountKills;
+
+/// Change GameActive state to false.
+///
+/// Kills made between the end of a round
+/// and the start of the next one don't
+/// count towards either team.
+#[derive(Default)]
+pub struct SetGameActive {
+	reader: Option<OnGameWinReader>,
+}
+
+#[derive(SystemData)]
+pub struct SetGameActiveData<'a> {
+	channel: Read<'a, OnGameWin>,
+	game_active: Write<'a, GameActive>,
+}
+
+impl<'a> System<'a> for SetGameActive {
+	type SystemData = SetGameActiveData<'a>;
+
+	fn setup(&mut self, res: &mut Resources) {
+		Self::SystemData::setup(res);
+
+		self.reader = Some(res.fetch_mut::<OnGameWin>().register_reader());
+	}
+
+	fn run(&mut self, mut data: Self::SystemData) {
+		for _ in data.channel.read(self.reader.as_mut().unwrap()) {
+			data.game_active.0 = false;
+		}
+	}
+}
+
+impl SystemInfo for SetGameActive {
+	type Dependencies = (CountKills, TimeLimit);
+
+	fn name() -> &'static str {
+		concat!(module_path!(), "::", line!())
+	}
+
+	fn new() -> Self {
+		Self::default()
+	}
+}
@@ -0,0 +1,31 @@
+use server::Builder;
+use specs::*;
+
+use super::*;
+use component::*;
+use config::TdmConfig;
+
+pub fn register<'a, 'b>(world: &mut World, disp: Builder<'a, 'b>) -> Builder<'a, 'b> {
+	let teams = world.read_resource::<TdmConfig>().teams;
+
+	world.add_resource(TeamScores::new(teams));
+	world.add_resource(GameActive(true));
+	world.add_resource(Round::default());
+
+	disp.with::<CountKills>()
+		.with::<ScoreDetailed>()
+		.with::<UpdateGameModeOnPlayerLeave>()
+		.with::<StartFirstRound>()
+		// On Game Win events
+		.with::<on_game_win::SetupMessages>()
+		.with::<on_game_win::SetupGameStart>()
+		.with::<on_game_win::SetGameActive>()
+		.with::<on_game_win::DisplayWin>()
+		.with::<on_game_win::AwardBounty>()
+		// Timer events
+		.with::<timer::GameStart>()
+		.with::<timer::TimeLimit>()
+		// Game Start events
+		.with::<on_game_start::ResetScores>()
+		.with::<on_game_start::RespawnAll>()
+}
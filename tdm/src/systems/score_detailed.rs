@@ -0,0 +1,100 @@
+use specs::*;
+
+use server::component::channel::*;
+use server::component::counter::*;
+use server::component::flag::*;
+use server::protocol::server::{ScoreDetailedFFA, ScoreDetailedFFAEntry, ServerMessage};
+use server::protocol::ServerMessageType;
+use server::*;
+
+use component::TeamScores;
+
+/// Reply to scoreboard requests with the usual
+/// per-player scores, and show the kill totals
+/// of every team alongside them.
+#[derive(Default)]
+pub struct ScoreDetailed {
+	reader: Option<OnScoreDetailedReader>,
+}
+
+#[derive(SystemData)]
+pub struct ScoreDetailedData<'a> {
+	channel: Read<'a, OnScoreDetailed>,
+	conns: Read<'a, Connections>,
+	scores: Read<'a, TeamScores>,
+
+	entities: Entities<'a>,
+	level: ReadStorage<'a, Level>,
+	score: ReadStorage<'a, Score>,
+	kills: ReadStorage<'a, TotalKills>,
+	deaths: ReadStorage<'a, TotalDeaths>,
+	ping: ReadStorage<'a, PlayerPing>,
+	is_player: ReadStorage<'a, IsPlayer>,
+}
+
+impl<'a> System<'a> for ScoreDetailed {
+	type SystemData = ScoreDetailedData<'a>;
+
+	fn setup(&mut self, res: &mut Resources) {
+		Self::SystemData::setup(res);
+
+		self.reader = Some(res.fetch_mut::<OnScoreDetailed>().register_reader());
+	}
+
+	fn run(&mut self, data: Self::SystemData) {
+		for evt in data.channel.read(self.reader.as_mut().unwrap()) {
+			let scores = (
+				&*data.entities,
+				&data.level,
+				&data.score,
+				&data.kills,
+				&data.deaths,
+				&data.ping,
+				data.is_player.mask(),
+			)
+				.join()
+				.map(
+					|(ent, level, score, kills, deaths, ping, ..)| ScoreDetailedFFAEntry {
+						id: ent.into(),
+						level: *level,
+						score: *score,
+						kills: kills.0 as u16,
+						deaths: deaths.0 as u16,
+						// TODO: Track this
+						damage: 0.0,
+						ping: ping.0 as u16,
+					},
+				)
+				.collect();
+
+			data.conns.send_to(evt.0, ScoreDetailedFFA { scores });
+
+			let totals = data
+				.scores
+				.iter()
+				.map(|(team, kills)| format!("Team {}: {} kills", team.0, kills))
+				.collect::<Vec<_>>();
+
+			data.conns.send_to(
+				evt.0,
+				ServerMessage {
+					ty: ServerMessageType::Banner,
+					duration: 5000,
+					text: totals.join(" | "),
+				},
+			);
+		}
+	}
+}
+
+impl SystemInfo for ScoreDetailed {
+	type Dependencies = ();
+
+	fn name() -> &'static str {
+		concat!(module_path!(), "::", line!())
+	}
+
+	fn new() -> Self {
+		Self::default()
+	}
+}
@@ -0,0 +1,43 @@
+use specs::*;
+
+use server::*;
+
+use component::*;
+
+/// Start the first round as soon as the server
+/// is up so that it gets a time limit like every
+/// round after it.
+#[derive(Default)]
+pub struct StartFirstRound {
+	started: bool,
+}
+
+#[derive(SystemData)]
+pub struct StartFirstRoundData<'a> {
+	channel: Write<'a, OnGameStart>,
+}
+
+impl<'a> System<'a> for StartFirstRound {
+	type SystemData = StartFirstRoundData<'a>;
+
+	fn run(&mut self, mut data: Self::SystemData) {
+		if self.started {
+			return;
+		}
+
+		self.started = true;
+		data.channel.single_write(GameStartEvent);
+	}
+}
+
+impl SystemInfo for StartFirstRound {
+	type Dependencies = ();
+
+	fn name() -> &'static str {
+		concat!(module_path!(), "::", line!())
+	}
+
+	fn new() -> Self {
+		Self::default()
+	}
+}
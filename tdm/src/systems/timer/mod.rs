@@ -0,0 +1,5 @@
+mod game_start;
+mod time_limit;
+
+pub use self::game_start::GameStart;
+pub use self::time_limit::TimeLimit;
@@ -0,0 +1,75 @@
+use specs::*;
+
+use server::component::channel::*;
+use server::*;
+
+use component::*;
+use consts::*;
+
+/// End the round once the [`TIME_LIMIT_TIMER`]
+/// for it fires. The team with the most kills
+/// wins, or nobody if there is a tie.
+#[derive(Default)]
+pub struct TimeLimit {
+	reader: Option<OnTimerEventReader>,
+}
+
+#[derive(SystemData)]
+pub struct TimeLimitData<'a> {
+	channel: Read<'a, OnTimerEvent>,
+	win_channel: Write<'a, OnGameWin>,
+	game_active: Read<'a, GameActive>,
+	scores: Read<'a, TeamScores>,
+	round: Read<'a, Round>,
+}
+
+impl<'a> System<'a> for TimeLimit {
+	type SystemData = TimeLimitData<'a>;
+
+	fn setup(&mut self, res: &mut Resources) {
+		Self::SystemData::setup(res);
+
+		self.reader = Some(res.fetch_mut::<OnTimerEvent>().register_reader());
+	}
+
+	fn run(&mut self, mut data: Self::SystemData) {
+		for evt in data.channel.read(self.reader.as_mut().unwrap()) {
+			if evt.ty != *TIME_LIMIT_TIMER {
+				continue;
+			}
+
+			let round = match evt.data {
+				Some(ref dat) => match (*dat).downcast_ref::<Round>() {
+					Some(val) => *val,
+					None => {
+						error!("Unable to downcast TimerEvent data to Round!");
+						continue;
+					}
+				},
+				None => continue,
+			};
+
+			// The round this timer was for already
+			// ended by reaching the kill target.
+			if round != *data.round || !data.game_active.0 {
+				continue;
+			}
+
+			data.win_channel.single_write(GameWinEvent {
+				winning_team: data.scores.leader(),
+			});
+		}
+	}
+}
+
+impl SystemInfo for TimeLimit {
+	type Dependencies = ();
+
+	fn name() -> &'static str {
+		concat!(module_path!(), "::", line!())
+	}
+
+	fn new() -> Self {
+		Self::default()
+	}
+}
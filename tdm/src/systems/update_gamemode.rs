@@ -0,0 +1,53 @@
+use specs::*;
+
+use server::component::channel::*;
+use server::component::event::PlayerLeave;
+use server::systems::handlers::packet::OnCloseHandler;
+use server::*;
+
+use gamemode::TDMGameMode;
+
+/// Keep the team sizes in the game mode up to
+/// date when players leave, so that new players
+/// are put on the smallest team.
+pub struct UpdateGameModeOnPlayerLeave {
+	reader: Option<OnPlayerLeaveReader>,
+}
+
+#[derive(SystemData)]
+pub struct UpdateGameModeOnPlayerLeaveData<'a> {
+	pub gamemode: GameModeWriter<'a, TDMGameMode>,
+	pub channel: Read<'a, OnPlayerLeave>,
+
+	pub teams: ReadStorage<'a, Team>,
+}
+
+impl<'a> System<'a> for UpdateGameModeOnPlayerLeave {
+	type SystemData = UpdateGameModeOnPlayerLeaveData<'a>;
+
+	fn setup(&mut self, res: &mut Resources) {
+		Self::SystemData::setup(res);
+
+		self.reader = Some(res.fetch_mut::<OnPlayerLeave>().register_reader());
+	}
+
+	fn run(&mut self, mut data: Self::SystemData) {
+		for PlayerLeave(ent) in data.channel.read(self.reader.as_mut().unwrap()) {
+			if let Some(&team) = data.teams.get(*ent) {
+				data.gamemode.remove_player(team);
+			}
+		}
+	}
+}
+
+impl SystemInfo for UpdateGameModeOnPlayerLeave {
+	type Dependencies = OnCloseHandler;
+
+	fn name() -> &'static str {
+		concat!(module_path!(), "::", line!())
+	}
+
+	fn new() -> Self {
+		Self { reader: None }
+	}
+}